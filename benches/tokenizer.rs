@@ -0,0 +1,107 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use english_to_cron::{str_cron_syntax, Tokenizer};
+use std::hint::black_box;
+
+/// Short descriptions: a single clause.
+const SHORT: [&str; 17] = [
+    "every minute",
+    "every 15 seconds",
+    "every hour",
+    "every 3 hours",
+    "at noon",
+    "at midnight",
+    "at 10:00 am",
+    "weekly",
+    "fortnightly",
+    "every day",
+    "every weekday",
+    "on weekends",
+    "every month",
+    "every 2 years",
+    "hourly",
+    "every 5th minute",
+    "in Q1",
+];
+
+/// Medium descriptions: a time combined with days or months.
+const MEDIUM: [&str; 17] = [
+    "every day at 4:00 pm",
+    "7pm every Thursday",
+    "on Sunday at 12:00",
+    "midnight on Tuesdays",
+    "at 9am and 5pm every day",
+    "every 15 minutes on weekdays",
+    "on the last day of the month",
+    "the first Friday of the month at 9am",
+    "at 10:15 in January and July",
+    "every 2 hours starting at 9am",
+    "every 10 minutes ending at 5:00 pm",
+    "at 7 p.m. every Thursday",
+    "every 6 hours starting at 1pm",
+    "at 0, 6, 12 and 18 hours",
+    "every 10 minutes during business hours",
+    "every day except Saturday and Sunday",
+    "at 15 past the hour between 9am and 5pm",
+];
+
+/// Long descriptions: several clauses in one sentence.
+const LONG: [&str; 16] = [
+    "Run at midnight on the 1st and 15th of the month",
+    "Run every 6 hours, starting at 1:00 pm on day Monday",
+    "Run at 6:00 pm every Monday through Friday",
+    "every 10 minutes between 9am and 5pm on weekdays",
+    "on weekdays every 10 minutes from 9am to 5pm",
+    "every 5 minutes starting at 9am ending at 5pm on Monday and Friday",
+    "at 6:30 and 18:30 every 2 days in January through March",
+    "every 15 minutes from 8am to 6pm on the 1st and 15th of the month",
+    "every half hour between 9am and 9pm every weekday",
+    "at 9:30 in the morning on the second Monday of the month in 2030",
+    "every 20 minutes during business hours except on Fridays",
+    "at 7 in the evening on Monday, Wednesday and Friday every month",
+    "every 2 weeks on Monday at 9am starting in 2030",
+    "at 5 minutes past the hour from 8am to 8pm on weekends",
+    "every 30 seconds between 9am and 5pm on Monday through Friday in December",
+    "at noon on the last Friday of the month from January to June",
+];
+
+fn inputs() -> [(&'static str, &'static [&'static str]); 3] {
+    [("short", &SHORT), ("medium", &MEDIUM), ("long", &LONG)]
+}
+
+fn bytes(inputs: &[&str]) -> u64 {
+    inputs.iter().map(|input| input.len() as u64).sum()
+}
+
+fn tokenizer(c: &mut Criterion) {
+    let tokenizer = Tokenizer::new();
+    let mut group = c.benchmark_group("Tokenizer::run");
+    for (name, inputs) in inputs() {
+        group.throughput(Throughput::Bytes(bytes(inputs)));
+        group.bench_with_input(BenchmarkId::from_parameter(name), inputs, |b, inputs| {
+            b.iter(|| {
+                for input in inputs {
+                    black_box(tokenizer.run(black_box(input)));
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+fn end_to_end(c: &mut Criterion) {
+    let mut group = c.benchmark_group("str_cron_syntax");
+    for (name, inputs) in inputs() {
+        group.throughput(Throughput::Bytes(bytes(inputs)));
+        group.bench_with_input(BenchmarkId::from_parameter(name), inputs, |b, inputs| {
+            b.iter(|| {
+                for input in inputs {
+                    let _ = black_box(str_cron_syntax(black_box(input)));
+                }
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, tokenizer, end_to_end);
+criterion_main!(benches);
@@ -0,0 +1,14 @@
+#![no_main]
+
+use english_to_cron::{str_cron_syntax, Cron};
+use libfuzzer_sys::fuzz_target;
+
+// Any input should come back as `Ok` or `Err`, and a schedule that parses should be
+// describable and readable as a cron expression again.
+fuzz_target!(|input: &str| {
+    let Ok(expression) = str_cron_syntax(input) else {
+        return;
+    };
+    let cron = Cron::parse_cron(&expression).unwrap();
+    let _ = cron.describe();
+});
@@ -2,7 +2,11 @@
 #[allow(clippy::doc_markdown)]
 #[doc = include_str!("../README.md")]
 mod str_to_cron;
-pub use str_to_cron::{Cron, Error, Result};
+pub use str_to_cron::{
+    Clock, Cron, CronBuilder, CronDialect, CronField, CronVerbose, DayOfWeekFormat, Error,
+    FieldItem, FieldPosition, FieldSpec, Meridiem, Month, MonthFormat, MultiCron, Options, Result,
+    ScheduleSpec, Syntax, Tokenizer, Warning, WeekStart, Weekday,
+};
 
 /// Converts an English description of a schedule into cronjob syntax.
 ///
@@ -41,3 +45,131 @@ pub fn str_cron_syntax(input: &str) -> str_to_cron::Result<String> {
     let cron = str_to_cron::Cron::new(input)?;
     Ok(format!("{cron}"))
 }
+
+/// Converts an English description of a schedule into cronjob syntax, along with any
+/// [`Warning`]s about how the input was read.
+///
+/// The expression is the same one [`str_cron_syntax`] returns. The warnings point out
+/// input that was approximated, dropped, or not understood, so a user interface can
+/// show how the description was interpreted. If the input cannot be parsed, there are
+/// no warnings.
+///
+/// # Examples
+///
+/// ```rust
+/// use english_to_cron::{str_cron_syntax_verbose, Warning};
+///
+/// let (cron, warnings) = str_cron_syntax_verbose("every 2 days on Monday");
+/// assert_eq!(cron.unwrap(), "0 0 0 ? * MON *");
+/// assert_eq!(
+///     warnings,
+///     vec![Warning::AmbiguousDayFields {
+///         day_of_month: "*/2".to_string(),
+///         day_of_week: "MON".to_string(),
+///     }]
+/// );
+///
+/// let (_, warnings) = str_cron_syntax_verbose("every hour please");
+/// assert_eq!(warnings, vec![Warning::IgnoredToken { token: "please".to_string() }]);
+/// ```
+pub fn str_cron_syntax_verbose(input: &str) -> (str_to_cron::Result<String>, Vec<Warning>) {
+    match str_to_cron::Cron::new(input) {
+        Ok(cron) => (Ok(cron.to_string()), cron.warnings),
+        Err(error) => (Err(error), Vec::new()),
+    }
+}
+
+/// Converts an English description of a schedule into cronjob syntax, skipping whatever
+/// cannot be understood instead of failing.
+///
+/// Returns the best-effort expression along with the words and tokens that were left
+/// out, so a user interface can point them out. Tokens that keep the rest of the input
+/// from parsing are dropped one at a time. If nothing is understood, the expression
+/// runs every second (`* * * * * ? *`).
+///
+/// Input that is understood but cannot be expressed as written, such as "every other
+/// Monday", is an error rather than a different schedule. This is why the expression is a
+/// [`Result`] rather than a plain `String`: dropping "other" would run every Monday while
+/// only listing the word as ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use english_to_cron::str_cron_syntax_lenient;
+///
+/// let (cron, ignored) = str_cron_syntax_lenient("every 15 minutes please");
+/// assert_eq!(cron.unwrap(), "0 0/15 * * * ? *");
+/// assert_eq!(ignored, vec!["please"]);
+///
+/// let (cron, ignored) = str_cron_syntax_lenient("whenever you like");
+/// assert_eq!(cron.unwrap(), "* * * * * ? *");
+/// assert_eq!(ignored, vec!["whenever", "you", "like"]);
+///
+/// let (cron, _) = str_cron_syntax_lenient("every other Monday at 9am");
+/// assert!(cron.is_err());
+/// ```
+pub fn str_cron_syntax_lenient(input: &str) -> (str_to_cron::Result<String>, Vec<String>) {
+    let (cron, ignored) = str_to_cron::Cron::new_lenient(input);
+    let cron =
+        cron.map(|cron| cron.map_or_else(|| "* * * * * ? *".to_string(), |cron| cron.to_string()));
+    (cron, ignored)
+}
+
+/// Converts an English description of a schedule into one or more cron expressions.
+///
+/// A cron expression has a single minute field, so listed times with different
+/// minutes (e.g., "every day at 9:30am and 5pm") cannot share one line. Such
+/// phrases are split into one expression per minute; everything else yields
+/// exactly one expression, the same one [`str_cron_syntax`] returns.
+///
+/// # Examples
+///
+/// ```rust
+/// use english_to_cron::str_cron_syntax_multi;
+///
+/// let multi = str_cron_syntax_multi("every day at 9:30am and 5pm").unwrap();
+/// assert_eq!(multi.len(), 2);
+/// assert_eq!(multi.to_string(), "0 30 9 */1 * ? *\n0 0 17 */1 * ? *");
+///
+/// let single = str_cron_syntax_multi("every day at 9am and 9pm").unwrap();
+/// assert_eq!(single.to_string(), "0 0 9,21 */1 * ? *");
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`Error`] if the phrase cannot be parsed, even after splitting its
+/// listed times.
+///
+/// [`Error`]: str_to_cron::Error
+pub fn str_cron_syntax_multi(input: &str) -> str_to_cron::Result<MultiCron> {
+    MultiCron::new(input)
+}
+
+/// Converts an ISO 8601 duration (e.g., `PT15M`) into a cron expression that repeats at
+/// that interval.
+///
+/// # Examples
+///
+/// ```rust
+/// use english_to_cron::str_cron_syntax_from_iso8601;
+///
+/// assert_eq!(str_cron_syntax_from_iso8601("PT30S").unwrap(), "0/30 * * * * ? *");
+/// assert_eq!(str_cron_syntax_from_iso8601("PT15M").unwrap(), "0 0/15 * * * ? *");
+/// assert_eq!(str_cron_syntax_from_iso8601("PT1H").unwrap(), "0 0 0/1 * * ? *");
+/// assert_eq!(str_cron_syntax_from_iso8601("P1D").unwrap(), "0 0 0 */1 * ? *");
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidInput`] if the duration is malformed or cannot be expressed
+/// in cron, such as `P1Y6M`, which mixes two units, and [`Error::UnsupportedSchedule`]
+/// for an interval such as `PT90M` that is not a whole number of the next unit. See
+/// [`Cron::from_iso8601`].
+///
+/// [`Error::InvalidInput`]: str_to_cron::Error::InvalidInput
+/// [`Error::UnsupportedSchedule`]: str_to_cron::Error::UnsupportedSchedule
+/// [`Cron::from_iso8601`]: str_to_cron::Cron::from_iso8601
+pub fn str_cron_syntax_from_iso8601(duration: &str) -> str_to_cron::Result<String> {
+    let cron = str_to_cron::Cron::from_iso8601(duration)?;
+    Ok(format!("{cron}"))
+}
@@ -1,6 +1,6 @@
 //! This file provides functionality for processing clock time tokens, converting them into the
 //! appropriate format for cron syntax. It recognizes various time formats, including 12-hour
-//! format with AM/PM and 24-hour format, as well as specific keywords like "noon" and "midnight".
+//! format with AM/PM and 24-hour format, as well as specific keywords like "noon", "midday" and "midnight".
 //!
 //! The regex patterns defined here help to match and extract hours and minutes from the tokens.
 //!
@@ -8,31 +8,42 @@
 
 use super::super::{
     action::Kind,
-    cron::Cron,
+    cron::{Cron, FieldPosition},
     stack::{Stack, StartEnd},
     Error, Result,
 };
 
-use regex::Regex;
+use super::super::re::Regex;
+use super::{range_end, range_start};
 use std::sync::LazyLock;
 
 /// A regex pattern that matches various clock time formats, including:
 /// - 12-hour format with AM/PM (e.g., "5 PM", "7 AM")
-/// - 24-hour format (e.g., "13:00")
-/// - Special cases for "noon" and "midnight"
+/// - 24-hour format, optionally with seconds (e.g., "13:00", "13:45:30")
+/// - "o'clock" format (e.g., "9 o'clock")
+/// - Any of the above followed by a time-of-day qualifier (e.g., "8 in the evening")
+/// - Special cases for "noon", "midday" and "midnight"
 static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"(?i)^([0-9]+:)?[0-9]+ *(AM|PM)$|^([0-9]+:[0-9]+)$|(noon|midnight)").unwrap()
+    Regex::new(r"(?i)^([0-9]+:){0,2}[0-9]+ *(AM|PM)( (in the (morning|afternoon|evening)|at night))?$|^([0-9]+:[0-9]+(:[0-9]+)?)$|^[0-9]+(:[0-9]+){0,2} (in the (morning|afternoon|evening)|at night)$|^[0-9]+ ?o['’]clock|^(noon|midday|midnight)$")
+        .unwrap()
 });
 
+/// A regex pattern that matches a trailing time-of-day qualifier (e.g., "in the morning", "at night").
+static RE_QUALIFIER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(in the (morning|afternoon|evening)|at night)$").unwrap());
+
 /// A regex pattern to extract the hour from a time token.
 static RE_HOUR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]+").unwrap());
 
 /// A regex pattern to extract the minute from a time token.
 static RE_MINUTE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r":[0-9]+").unwrap());
 
-/// A regex pattern that matches the keywords "noon" and "midnight".
+/// A regex pattern to extract the second from a time token with seconds (e.g., "13:45:30").
+static RE_SECOND: LazyLock<Regex> = LazyLock::new(|| Regex::new(r":[0-9]+:([0-9]+)").unwrap());
+
+/// A regex pattern that matches the keywords "noon", "midday" and "midnight" as whole words.
 static RE_NOON_MIDNIGHT: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"(noon|midnight)").unwrap());
+    LazyLock::new(|| Regex::new(r"(?i)(?-u:\b)(noon|midday|midnight)(?-u:\b)").unwrap());
 
 /// Checks if a given string token matches the expected clock time format.
 pub fn try_from_token(str: &str) -> bool {
@@ -43,7 +54,7 @@ pub fn try_from_token(str: &str) -> bool {
 /// Processes a clock time token and updates the corresponding fields in the cron syntax structure.
 ///
 /// This function extracts hours and minutes from the token, handles conversions from 12-hour to 24-hour format,
-/// and sets the appropriate values in the `Cron` struct. It also handles specific cases for "noon" and "midnight".
+/// and sets the appropriate values in the `Cron` struct. It also handles specific cases for "noon", "midday" and "midnight".
 ///
 /// # Errors
 ///
@@ -81,8 +92,41 @@ pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
         }
     }
 
-    match token.to_lowercase().as_str() {
-        _ if token.to_lowercase().contains("pm") => {
+    let second = match RE_SECOND.captures(token) {
+        Some(captures) => {
+            let second_str = &captures[1];
+            let second = second_str
+                .parse::<i32>()
+                .map_err(|_| Error::ParseToNumber {
+                    state: "clock_time".to_string(),
+                    value: second_str.to_string(),
+                })?;
+            if second >= 60 {
+                return Err(Error::IncorrectValue {
+                    state: "clock_time".to_string(),
+                    error: format!("second {second} should be between 0 and 59"),
+                });
+            }
+            Some(second)
+        }
+        None => None,
+    };
+
+    let lowercase = token.to_lowercase();
+    let qualifier = RE_QUALIFIER.find(&lowercase).map(|m| m.as_str());
+    let time = qualifier.map_or(lowercase.as_str(), |q| {
+        &lowercase[..lowercase.len() - q.len()]
+    });
+    let meridiem = if time.contains("pm") {
+        Some("pm")
+    } else if time.contains("am") {
+        Some("am")
+    } else {
+        None
+    };
+
+    match meridiem {
+        Some("pm") => {
             match hour.cmp(&12) {
                 std::cmp::Ordering::Less => hour += 12,
                 std::cmp::Ordering::Greater => {
@@ -94,7 +138,7 @@ pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
                 std::cmp::Ordering::Equal => {} // Do nothing, hour remains 12
             }
         }
-        _ if token.to_lowercase().contains("am") => {
+        Some(_) => {
             match hour.cmp(&12) {
                 std::cmp::Ordering::Equal => hour = 0,
                 std::cmp::Ordering::Greater => {
@@ -106,18 +150,77 @@ pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
                 std::cmp::Ordering::Less => {} // Do nothing, hour remains unchanged
             }
         }
-        _ => {} // Handle other cases if necessary
+        None => {} // Handle other cases if necessary
     }
 
-    if RE_NOON_MIDNIGHT.is_match(token) {
-        if token == "noon" {
-            hour = 12;
-        } else {
+    if let Some(qualifier) = qualifier {
+        hour = apply_qualifier(hour, meridiem, qualifier)?;
+    } else if let Some(element) = cron.stack.last() {
+        if element.owner == Kind::DayPeriod {
+            // A time following a period of the day ("every evening at 7:30") is read in that period.
+            if meridiem.is_none() {
+                let period = element.qualifier.clone().unwrap_or_default();
+                hour = apply_qualifier(hour, None, &period)?;
+            }
+            cron.stack.pop();
+        }
+    }
+
+    if let Some(keyword) = RE_NOON_MIDNIGHT.find(time) {
+        if keyword.as_str().eq_ignore_ascii_case("midnight") {
             hour = 0;
+        } else {
+            hour = 12;
         }
         minute = 0;
     }
 
+    if hour > 23 {
+        return Err(Error::IncorrectValue {
+            state: "clock_time".to_string(),
+            error: format!("hour {hour} should be between 0 and 23"),
+        });
+    }
+
+    if range_start::is_starting(cron)
+        && range_start::apply_step_offset(cron, FieldPosition::Hour, hour)
+    {
+        cron.syntax.min = minute.to_string();
+        cron.syntax.mark_explicit(FieldPosition::Minutes);
+        cron.stack.pop();
+        return Ok(());
+    }
+
+    if range_start::is_starting(cron) {
+        // "every 5 minutes starting at 8am" runs from that time until the end of the day,
+        // unless a following "to"/"ending" closes the range earlier
+        cron.syntax.hour = if hour < 23 {
+            format!("{hour}-23")
+        } else {
+            hour.to_string()
+        };
+        cron.syntax.mark_explicit(FieldPosition::Hour);
+        set_range_minute(cron, minute);
+    }
+
+    if range_end::is_ending(cron) {
+        // "every 10 minutes ending at 5pm" runs from the start of the day until that hour
+        cron.syntax.hour = match cron.syntax.hour.split_once('/') {
+            // An end already given is replaced rather than extended
+            Some((start, step)) => {
+                let start = start.split_once('-').map_or(start, |(start, _)| start);
+                format!("{start}-{hour}/{step}")
+            }
+            None if hour > 0 => format!("0-{hour}"),
+            None => hour.to_string(),
+        };
+        cron.syntax.mark_explicit(FieldPosition::Hour);
+        cron.stack.pop();
+        return Ok(());
+    }
+
+    let mut is_range = false;
+    let mut is_list = false;
     if let Some(element) = cron.stack.last_mut() {
         if element.owner == Kind::RangeStart {
             element.hour = Some(StartEnd {
@@ -136,6 +239,17 @@ pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
                 } else {
                     element_hour.end = Some(hour);
                     if element.is_and_connector && !element.is_between_range {
+                        // Listed times share the minute field, so they must agree on it
+                        if let Some(listed) = element.min.as_ref().and_then(|min| min.start) {
+                            if listed != minute {
+                                return Err(Error::IncorrectValue {
+                                    state: "clock_time".to_string(),
+                                    error: format!(
+                                        "listed times must share the same minute, found {listed} and {minute}"
+                                    ),
+                                });
+                            }
+                        }
                         // Use comma for "and" connector but not in a "between X and Y" context
                         // Check if the syntax hour already has values
                         if cron.syntax.hour.contains(',') {
@@ -145,20 +259,38 @@ pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
                             cron.syntax.hour =
                                 format!("{},{}", element_hour.start.unwrap_or_default(), hour);
                         }
+                        is_list = true;
                     } else {
                         // Use hyphen for other range connectors or for "between X and Y"
                         cron.syntax.hour =
                             format!("{}-{}", element_hour.start.unwrap_or_default(), hour);
+                        is_range = true;
                     }
                 }
+                cron.syntax.mark_explicit(FieldPosition::Hour);
             }
 
+            if is_list {
+                // The list is complete, so a following number ("at 9am and 5pm every 2
+                // days") starts a new clause rather than ending a range
+                element.owner = Kind::ClockTime;
+                element.is_and_connector = false;
+            }
+            if is_range {
+                repeat_within_range(cron);
+            }
             return Ok(());
         }
     }
 
+    if let Some(second) = second {
+        cron.syntax.seconds = second.to_string();
+        cron.syntax.mark_explicit(FieldPosition::Seconds);
+    }
     cron.syntax.min = minute.to_string();
     cron.syntax.hour = hour.to_string();
+    cron.syntax.mark_explicit(FieldPosition::Minutes);
+    cron.syntax.mark_explicit(FieldPosition::Hour);
 
     cron.stack.push(
         Stack::builder(Kind::ClockTime)
@@ -175,3 +307,90 @@ pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
 
     Ok(())
 }
+
+/// Applies a repetition given before a time range ("every 5 minutes from 9am to 5pm") within
+/// that range, since the start time has replaced the minute field with its own minute.
+fn repeat_within_range(cron: &mut Cron) {
+    let Some(repetition) = cron
+        .stack
+        .len()
+        .checked_sub(2)
+        .and_then(|index| cron.stack.get(index))
+    else {
+        return;
+    };
+
+    match repetition.owner {
+        Kind::Secund => cron.syntax.min = "*".to_string(),
+        Kind::Minute => match (repetition.frequency, &repetition.min, &repetition.hour) {
+            (Some(step), Some(_), _) => cron.syntax.min = format!("0/{step}"),
+            (Some(step), None, Some(_)) => {
+                cron.syntax.hour = format!("{}/{step}", cron.syntax.hour);
+            }
+            (None, None, None) => cron.syntax.min = "*".to_string(),
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+/// Sets the minute a "starting at" range begins on. A stepped minute field keeps its step
+/// and is aligned on that minute ("every 5 minutes starting at 8:10" keeps `0/5`).
+fn set_range_minute(cron: &mut Cron, minute: i32) {
+    let step = cron
+        .syntax
+        .min
+        .split_once('/')
+        .and_then(|(_, step)| step.parse::<i32>().ok())
+        .filter(|step| *step > 0);
+    match step {
+        Some(step) => {
+            range_start::apply_step_offset(cron, FieldPosition::Minutes, minute % step);
+        }
+        // Repeating seconds ("every 10 seconds starting at 9am") run through every minute
+        None if minute == 0 && cron.syntax.seconds.contains('/') => {}
+        None => {
+            cron.syntax.min = minute.to_string();
+            cron.syntax.mark_explicit(FieldPosition::Minutes);
+        }
+    }
+}
+
+/// Applies a time-of-day qualifier ("in the morning", "in the afternoon", "in the evening",
+/// "at night") to an hour.
+///
+/// When the time already carries an explicit AM/PM suffix, the qualifier is only checked for
+/// consistency. Otherwise it shifts the hour the same way PM would: morning hours are kept,
+/// afternoon and evening hours move to the second half of the day, and night hours move to
+/// the evening unless they are in the small hours (before 5) or exactly 12 (midnight).
+pub fn apply_qualifier(hour: i32, meridiem: Option<&str>, qualifier: &str) -> Result<i32> {
+    let contradiction = || Error::IncorrectValue {
+        state: "clock_time".to_string(),
+        error: format!(
+            "{} contradicts \"{qualifier}\"",
+            meridiem.unwrap_or_default().to_uppercase()
+        ),
+    };
+
+    match (qualifier, meridiem) {
+        ("in the morning", Some("pm")) | ("in the afternoon" | "in the evening", Some("am")) => {
+            Err(contradiction())
+        }
+        (_, Some(_)) => Ok(hour),
+        (_, None) if hour > 12 => {
+            if qualifier == "in the morning" {
+                Err(Error::IncorrectValue {
+                    state: "clock_time".to_string(),
+                    error: format!("hour {hour} contradicts \"{qualifier}\""),
+                })
+            } else {
+                Ok(hour)
+            }
+        }
+        ("in the morning", None) => Ok(if hour == 12 { 0 } else { hour }),
+        ("at night", None) if hour == 12 => Ok(0),
+        ("at night", None) if hour < 5 => Ok(hour),
+        (_, None) if hour == 12 => Ok(hour),
+        (_, None) => Ok(hour + 12),
+    }
+}
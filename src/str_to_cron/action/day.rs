@@ -6,164 +6,846 @@
 //! to determine whether a given token is valid as a day input, as well as to
 //! process that token into a `Cron` structure.
 
+use super::super::re::{Captures, Regex};
 use super::super::{
     action::Kind,
-    cron::Cron,
+    cron::{Cron, FieldPosition},
     stack::{Stack, StartEndString},
-    Error, Result,
+    DayOfWeekFormat, Error, Result, Warning, WeekStart,
 };
-use regex::Regex;
-use std::fmt::Write;
-use std::sync::LazyLock;
+use super::except;
+use std::{borrow::Cow, sync::LazyLock};
 
 /// Matches various formats for days, including full names and abbreviations.
 static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"(?i)^((days|day)|(((monday|tuesday|wednesday|thursday|friday|saturday|sunday|WEEKEND|MON|TUE|WED|THU|FRI|SAT|SUN)( ?and)?,? ?)+))$")
+    Regex::new(r"(?i)^((days|day|weeks|week|weekly|fortnight|fortnightly|bi-?weekly|two weeks)|((even|odd)(-numbered)? days?)|((MON|TUE|WED|THU|FRI|SAT|SUN)[a-z]* ?[-–] ?(MON|TUE|WED|THU|FRI|SAT|SUN)[a-z]*)|(last (monday|tuesday|wednesday|thursday|friday|saturday|sunday|MON|TUE|WED|THU|FRI|SAT|SUN)( of (the|each|every) month)?)|(([0-9]+(st|nd|rd|th)|first|second|third|fourth|fifth)( (and|or) ([0-9]+(st|nd|rd|th)|first|second|third|fourth|fifth))* (monday|tuesday|wednesday|thursday|friday|saturday|sunday|MON|TUE|WED|THU|FRI|SAT|SUN)( of (the|each|every) month)?)|(((monday|tuesday|wednesday|thursday|friday|saturday|sunday|WEEKDAYS?|BUSINESS ?DAYS?|WORK ?DAYS?|WEEKEND|MON|TUE|WED|THU|FRI|SAT|SUN)( ?and)?,? ?)+))$")
         .unwrap()
 });
 
+/// Matches a day name in a rendered day-of-week field.
+static RE_DAY_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("MON|TUE|WED|THU|FRI|SAT|SUN").unwrap());
+
+/// Matches a day number in a day-of-week field written with numbers, along with the
+/// start of the field or the separator before it.
+static RE_DAY_NUMBER: LazyLock<Regex> = LazyLock::new(|| Regex::new("(^|[,-])([0-7])").unwrap());
+
 /// Matches the tokens "day" or "days".
 static RE_DAY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^(day|days)$").unwrap());
 
-/// Matches the abbreviations for weekdays and the term "WEEKEND".
-static RE_WEEKDAYS: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"(?i)(MON|TUE|WED|THU|FRI|SAT|SUN|WEEKEND)").unwrap());
+/// Matches the tokens "week", "weeks" or "weekly".
+static RE_WEEK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(week|weeks|weekly)$").unwrap());
+
+/// The day a weekly schedule runs on when no day is given, matching cron's `@weekly`.
+const DEFAULT_WEEKLY_DAY: &str = "SUN";
+
+/// Matches the last occurrence of a weekday in the month (e.g., "last Friday of the month").
+static RE_LAST: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^last (MON|TUE|WED|THU|FRI|SAT|SUN)[a-z]*( of (the|each|every) month)?$")
+        .unwrap()
+});
+
+/// Matches numbered occurrences of a weekday in the month (e.g., "the 1st and 3rd Monday").
+static RE_NTH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(([0-9]+(st|nd|rd|th)|first|second|third|fourth|fifth)( (and|or) ([0-9]+(st|nd|rd|th)|first|second|third|fourth|fifth))*) (MON|TUE|WED|THU|FRI|SAT|SUN)[a-z]*( of (the|each|every) month)?$")
+        .unwrap()
+});
+
+/// Matches one occurrence number within [`RE_NTH`], as digits or a word.
+static RE_NTH_OCCURRENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)[0-9]+|first|second|third|fourth|fifth").unwrap());
+
+/// Occurrence words in order, starting with "first".
+const OCCURRENCES: [&str; 5] = ["first", "second", "third", "fourth", "fifth"];
+
+/// Matches a compact range of weekdays (e.g., "Mon-Fri", "Monday–Friday").
+static RE_DAY_RANGE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^(MON|TUE|WED|THU|FRI|SAT|SUN)[a-z]* ?[-–] ?(MON|TUE|WED|THU|FRI|SAT|SUN)[a-z]*$",
+    )
+    .unwrap()
+});
+
+/// Matches even or odd days of the month (e.g., "even days", "odd-numbered days").
+static RE_PARITY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(even|odd)(-numbered)? days?$").unwrap());
+
+/// The day-of-month field for even days.
+const EVEN_DAYS: &str = "2-30/2";
+
+/// The day-of-month field for odd days.
+const ODD_DAYS: &str = "1-31/2";
+
+/// Matches the phrasings of "every two weeks".
+static RE_FORTNIGHT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(fortnight|fortnightly|bi-?weekly|two weeks)$").unwrap());
+
+/// Matches the abbreviations for weekdays and the terms "WEEKDAY" and "WEEKEND", along with
+/// "business day" and "workday", which stand for weekdays.
+static RE_WEEKDAYS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(MON|TUE|WED|THU|FRI|SAT|SUN|WEEKDAY|WEEKEND|BUSINESS ?DAY|WORK ?DAY)")
+        .unwrap()
+});
 
 // Constant array representing the days of the week in uppercase.
-const WEEK_DAYS: [&str; 7] = ["MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"];
+pub const WEEK_DAYS: [&str; 7] = ["MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"];
 
 /// Checks if the provided string matches the expected day token formats.
 pub fn try_from_token(str: &str) -> bool {
     RE_MATCH.is_match(str)
 }
 
-/// Processes the given token to update the `cron` object with the specified day of the week information.
+/// Validates that a calendar day-of-month lies between 1 and 31 and renders it.
 ///
-/// This function determines whether the input token specifies days in a "day" or "days" format, or specific weekdays.
-/// It then updates the `day_of_week` and `day_of_month` fields in the provided `cron` object based on the matched days.
+/// Days such as the 31st are accepted even though some months are shorter, since
+/// schedulers simply skip months without that day. Steps (e.g., `*/2`) are not
+/// calendar days and must not be passed here.
 ///
-/// # Returns
+/// # Errors
 ///
-/// * [`Result<()>`] - Returns `Ok(())` if the processing is successful, or an `Error` if the token does not match expected formats.
-pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
-    if RE_DAY.is_match(token) {
-        cron.syntax.day_of_week = "?".to_string();
-        if cron.syntax.min == "*" {
-            cron.syntax.min = "0".to_string();
+/// Returns [`Error::IncorrectValue`] if the day is outside 1-31.
+pub fn validate_day_of_month(day: Option<i32>) -> Result<String> {
+    match day {
+        Some(value) if !(1..=31).contains(&value) => Err(Error::IncorrectValue {
+            state: "day".to_string(),
+            error: format!("day of month {value} should be between 1 and 31"),
+        }),
+        Some(value) => Ok(value.to_string()),
+        None => Ok("*".to_string()),
+    }
+}
+
+/// Renders the day-of-month boundaries collected on a range element.
+///
+/// Boundaries joined by "and" form a list (`1,15`), while "to"/"through" and
+/// "between ... and ..." form a range (`1-15`).
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if either boundary is outside 1-31, or if a range
+/// ends before it starts.
+pub fn day_of_month_range(element: &Stack) -> Result<String> {
+    let separator = element.range_separator();
+    if separator == '-' {
+        if let (Some(start), Some(end)) = (element.frequency_start, element.frequency_end) {
+            validate_day_of_month_order(start, end)?;
         }
-        if cron.syntax.hour == "*" {
-            cron.syntax.hour = "0".to_string();
+    }
+    let start = validate_day_of_month(element.frequency_start)?;
+    let end = validate_day_of_month(element.frequency_end)?;
+    Ok(format!("{start}{separator}{end}"))
+}
+
+/// Checks that a day-of-month range does not end before it starts.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if `end` is before `start`.
+pub fn validate_day_of_month_order(start: i32, end: i32) -> Result<()> {
+    if start > end {
+        return Err(Error::IncorrectValue {
+            state: "day".to_string(),
+            error: format!("day of month range {start}-{end} ends before it starts"),
+        });
+    }
+    Ok(())
+}
+
+/// Returns whether a day field restricts the schedule to specific days. Wildcards and
+/// repetitions of every few days (`*/2`) do not, and give way to the other day field.
+pub fn is_specific_day(value: &str) -> bool {
+    let value = value.trim();
+    !(value.is_empty() || value == "*" || value == "?" || value.starts_with("*/"))
+}
+
+/// Gives the day-of-month up to a day of the week just set, unless it holds specific
+/// days, which are left in place for [`Cron`] to report as a conflict.
+///
+/// A repetition of days ("every 2 days") that is given up this way is reported as a
+/// [`Warning::AmbiguousDayFields`].
+pub fn clear_day_of_month(cron: &mut Cron) {
+    let day_of_month = cron.syntax.day_of_month.trim();
+    if is_specific_day(day_of_month) {
+        return;
+    }
+    if day_of_month.starts_with("*/") && day_of_month != "*/1" {
+        let day_of_week = cron.syntax.day_of_week.trim().to_string();
+        // A repetition of weeks ("every 2 weeks on Monday") was already reported as an
+        // approximation, which now runs every week instead
+        let weeks = day_of_month[2..]
+            .parse::<i32>()
+            .ok()
+            .filter(|days| days % 7 == 0)
+            .map(|days| days / 7);
+        match (weeks, cron.warnings.last_mut()) {
+            (Some(weeks), Some(Warning::ApproximatedPeriod { approximation, .. })) => {
+                *approximation = unenforced_weeks(weeks, &day_of_week);
+            }
+            _ => cron.warnings.push(Warning::AmbiguousDayFields {
+                day_of_month: day_of_month.to_string(),
+                day_of_week,
+            }),
         }
+    }
+    cron.syntax.day_of_month = "?".to_string();
+}
 
-        if let Some(element) = cron.stack.last() {
-            if element.owner == Kind::FrequencyOnly {
-                cron.syntax.day_of_month = format!("*/{}", element.frequency_to_string());
-                cron.stack.pop();
-            } else if element.owner == Kind::FrequencyWith {
-                cron.syntax.day_of_month = element.frequency_to_string();
+/// Gives the day-of-week up to a day of the month just set, unless it holds specific
+/// days, which are left in place for [`Cron`] to report as a conflict.
+pub fn clear_day_of_week(cron: &mut Cron) {
+    if !is_specific_day(&cron.syntax.day_of_week) {
+        cron.syntax.day_of_week = "?".to_string();
+    }
+}
+
+/// Returns whether the input has given a time of day or a repetition within the day.
+pub fn is_time_set(cron: &Cron) -> bool {
+    [
+        FieldPosition::Seconds,
+        FieldPosition::Minutes,
+        FieldPosition::Hour,
+    ]
+    .into_iter()
+    .any(|field| cron.syntax.is_explicit(field))
+}
+
+/// Reads an ordinal that no unit took up ("on the 15th", "every month on the 15th at
+/// 3pm") as a day of the month, which runs at midnight unless a time is given.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if the day is outside 1-31.
+pub fn process_leftover_ordinal(cron: &mut Cron) -> Result<()> {
+    let Some(index) = cron
+        .stack
+        .iter()
+        .rposition(|element| element.owner == Kind::FrequencyWith)
+    else {
+        return Ok(());
+    };
+    if cron.syntax.is_explicit(FieldPosition::DayOfMonth) {
+        return Ok(());
+    }
+
+    let element = cron.stack.remove(index);
+    cron.syntax.day_of_month = validate_day_of_month(element.frequency)?;
+    cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+    clear_day_of_week(cron);
+    if !is_time_set(cron) {
+        set_default_time(cron);
+    }
+    Ok(())
+}
+
+/// Fills in midnight for a schedule that repeats on whole days, unless a time is already set.
+pub fn set_default_time(cron: &mut Cron) {
+    if cron.syntax.min == "*" {
+        cron.syntax.min = "0".to_string();
+    }
+    if cron.syntax.hour == "*" {
+        cron.syntax.hour = "0".to_string();
+    }
+}
+
+/// Rejects a specific day of the month combined with even or odd days
+/// ("on even days on the 5th"), whichever of the two comes first.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if the day-of-month field was already set to the
+/// other clause.
+pub fn reject_parity_conflict(cron: &Cron, token: &str) -> Result<()> {
+    let day_of_month = cron.syntax.day_of_month.as_str();
+    let is_explicit = cron.syntax.is_explicit(FieldPosition::DayOfMonth);
+    let conflicts = if RE_PARITY.is_match(token) {
+        // A repetition such as "every day" is replaced, but specific days are not
+        is_explicit && !day_of_month.contains('/')
+            || cron
+                .stack
+                .last()
+                .is_some_and(|element| element.owner == Kind::FrequencyWith)
+    } else {
+        is_explicit && (day_of_month == EVEN_DAYS || day_of_month == ODD_DAYS)
+    };
+    if conflicts {
+        return Err(Error::IncorrectValue {
+            state: "day".to_string(),
+            error: format!("{token} contradicts the day of month already given"),
+        });
+    }
+    Ok(())
+}
+
+/// Processes even or odd days of the month into a stepped range (`2-30/2` or `1-31/2`).
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if a specific day of the month was already given.
+fn process_parity(token: &str, cron: &mut Cron) -> Result<()> {
+    reject_parity_conflict(cron, token)?;
+
+    cron.syntax.day_of_month = if token.to_lowercase().starts_with("even") {
+        EVEN_DAYS.to_string()
+    } else {
+        ODD_DAYS.to_string()
+    };
+    clear_day_of_week(cron);
+    cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+    if !is_time_set(cron) {
+        set_default_time(cron);
+    }
+    cron.stack.push(
+        Stack::builder(Kind::Day)
+            .qualifier(token.to_lowercase())
+            .build(),
+    );
+    Ok(())
+}
+
+/// Processes a "week" token.
+///
+/// A single week ("every week", "weekly") runs once a week on [`DEFAULT_WEEKLY_DAY`] unless a
+/// following day replaces it ("every week on Tuesday"). A week adds nothing to a schedule
+/// that already names its days ("on Monday every week"), nor when it does not repeat
+/// ("every day this week"), which is reported as an ignored word. Cron cannot count weeks
+/// across month boundaries, so repetitions of more than one week are approximated in the
+/// day-of-month field (`*/14` for "every other week") and reported with a warning. Next to
+/// days of the week ("every 2 weeks on Monday") they run every week on those days instead,
+/// which the warning points out.
+fn process_week(token: &str, cron: &mut Cron) {
+    let weeks = if RE_FORTNIGHT.is_match(token) {
+        2
+    } else {
+        match cron.stack.last() {
+            Some(element) if element.owner == Kind::FrequencyOnly => {
+                let weeks = element.frequency.unwrap_or(1);
                 cron.stack.pop();
-            } else {
-                cron.syntax.day_of_month = "*".to_string();
+                weeks
             }
-        } else {
-            cron.syntax.day_of_month = "*/1".to_string();
+            _ if token.eq_ignore_ascii_case("weekly") => 1,
+            _ => {
+                // "this week" does not repeat, so it adds nothing to the schedule
+                cron.warnings.push(Warning::IgnoredToken {
+                    token: token.to_string(),
+                });
+                return;
+            }
+        }
+    };
+
+    if cron.syntax.is_explicit(FieldPosition::DayOfWeek)
+        || (weeks == 1 && cron.syntax.is_explicit(FieldPosition::DayOfMonth))
+    {
+        if weeks > 1 {
+            let day_of_week = cron.syntax.day_of_week.trim().to_string();
+            cron.warnings.push(Warning::ApproximatedPeriod {
+                phrase: week_phrase(token, weeks),
+                approximation: unenforced_weeks(weeks, &day_of_week),
+            });
         }
+        return;
+    }
+
+    let week = Stack::builder(Kind::Day)
+        .qualifier("week".to_string())
+        .build();
+
+    if weeks == 1 {
+        cron.syntax.day_of_week = DEFAULT_WEEKLY_DAY.to_string();
+        clear_day_of_month(cron);
+        cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+        set_default_time(cron);
+        cron.stack.push(week);
+        return;
+    }
+
+    cron.warnings.push(Warning::ApproximatedPeriod {
+        phrase: week_phrase(token, weeks),
+        approximation: days_of_weeks(weeks),
+    });
+
+    cron.syntax.day_of_month = format!("*/{}", weeks * 7);
+    clear_day_of_week(cron);
+    cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+    set_default_time(cron);
+    cron.stack.push(week);
+}
+
+/// Describes how a repetition of several weeks is approximated in the days of the month.
+pub fn days_of_weeks(weeks: i32) -> String {
+    format!(
+        "every {} days (*/{} in day_of_month), restarting each month",
+        weeks * 7,
+        weeks * 7
+    )
+}
+
+/// Returns the phrase a repetition of weeks was read from, for its warning.
+fn week_phrase(token: &str, weeks: i32) -> String {
+    if token.to_lowercase().starts_with("bi") {
+        // "biweekly" can also mean twice a week in American English
+        format!(
+            "{} (read as every two weeks, not twice a week)",
+            token.to_lowercase()
+        )
+    } else if RE_FORTNIGHT.is_match(token) {
+        token.to_lowercase()
     } else {
-        let matches: Vec<_> = RE_WEEKDAYS.find_iter(token).collect();
-        if matches.is_empty() {
+        format!("every {weeks} weeks")
+    }
+}
+
+/// Describes a repetition of weeks that gave way to days of the week, which then run
+/// every week.
+fn unenforced_weeks(weeks: i32, day_of_week: &str) -> String {
+    format!("every week on {day_of_week}, since the {weeks}-week cadence cannot be enforced")
+}
+
+/// Renders a numbered occurrence of a weekday in Quartz `#` syntax ("2nd Monday" becomes
+/// `MON#2`).
+///
+/// Quartz takes a single `#` day as the whole field, so more than one occurrence ("1st
+/// and 3rd Monday") cannot be expressed.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if an occurrence is outside 1-5, and
+/// [`Error::UnsupportedSchedule`] if more than one occurrence is given.
+fn nth_days_of_week(token: &str, occurrences: &str, day: &str) -> Result<String> {
+    let mut items = Vec::new();
+    for occurrence in RE_NTH_OCCURRENCE.find_iter(occurrences) {
+        let occurrence = occurrence.as_str().to_lowercase();
+        let number = OCCURRENCES
+            .iter()
+            .position(|word| *word == occurrence)
+            .map_or_else(
+                || occurrence.parse::<usize>().unwrap_or(0),
+                |index| index + 1,
+            );
+        if !(1..=OCCURRENCES.len()).contains(&number) {
             return Err(Error::IncorrectValue {
                 state: "day".to_string(),
-                error: format!("value {token} is not a weekend format"),
+                error: format!("occurrence {number} of {day} should be between 1 and 5"),
             });
         }
+        let item = format!("{day}#{number}");
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
+    if items.len() > 1 {
+        return Err(Error::UnsupportedSchedule {
+            schedule: format!("the {token}"),
+            suggestion: format!("every {}", day_word(token)),
+        });
+    }
+    Ok(items.join(","))
+}
 
-        // Set the day of week
-        cron.syntax.day_of_week = String::new();
+/// Returns the first word naming a day of the week in a token ("Monday" in "every other
+/// Monday at 9am").
+fn day_word(token: &str) -> &str {
+    token
+        .split_whitespace()
+        .find(|word| try_from_token(word))
+        .map_or(token, |word| {
+            word.trim_end_matches(|c: char| !c.is_ascii_alphabetic())
+        })
+}
 
-        let days: Vec<String> = matches
-            .iter()
-            .map(|day| day.as_str().to_uppercase())
-            .collect::<Vec<_>>();
-
-        if let Some(element) = cron.stack.last_mut() {
-            if element.owner == Kind::RangeStart {
-                element.day = Some(StartEndString {
-                    start: days.first().cloned(),
-                    end: element.day.clone().and_then(|a| a.end),
-                });
-                return Ok(());
-            } else if element.owner == Kind::RangeEnd {
-                let data = StartEndString {
-                    start: element.day.clone().and_then(|a| a.start),
-                    end: days.first().cloned(),
-                };
-                element.day = Some(data.clone());
-
-                if let (Some(start), Some(end)) = (data.start, data.end) {
-                    write!(cron.syntax.day_of_week, "{start}-{end}").map_err(|_| {
-                        Error::IncorrectValue {
-                            state: "day".to_string(),
-                            error: "Failed to format day of week range".to_string(),
-                        }
-                    })?;
-                }
+/// Lists days of the week from Monday, each once, expanding "WEEKDAY" and "WEEKEND" into
+/// the days they stand for ("FRI", "MON", "FRI" is `MON,FRI`).
+fn list_days(days: &[String]) -> String {
+    let mut indices: Vec<usize> = Vec::new();
+    for day in days {
+        match day.as_str() {
+            "WEEKDAY" => indices.extend(0..5),
+            "WEEKEND" => indices.extend(5..7),
+            day => indices.extend(WEEK_DAYS.iter().position(|name| *name == day)),
+        }
+    }
+    indices.sort_unstable();
+    indices.dedup();
+    indices
+        .into_iter()
+        .map(|index| WEEK_DAYS[index])
+        .collect::<Vec<_>>()
+        .join(",")
+}
 
-                cron.syntax.day_of_month = "?".to_string();
-                cron.stack.pop();
-                return Ok(());
-            } else if element.owner == Kind::OnlyOn {
-                // Special case for "only on" syntax
-                let day = days.first().cloned().ok_or_else(|| Error::IncorrectValue {
-                    state: "day".to_string(),
-                    error: "Expected at least one day in 'only on' syntax but found none"
-                        .to_string(),
-                })?;
-                cron.syntax.day_of_week = day;
-                cron.syntax.day_of_month = "?".to_string();
-
-                // Remove the "only on" entry from the stack
-                cron.stack.pop();
+/// Reorders a list of days of the week for weeks that start on Sunday. Lists are built
+/// from Monday, so Sunday moves from the end to the front (`MON,SUN` becomes `SUN,MON`).
+pub fn start_week(day_of_week: &mut String, week_start: WeekStart) {
+    if week_start == WeekStart::Sunday {
+        if let Some(rest) = day_of_week.strip_suffix(",SUN") {
+            *day_of_week = format!("SUN,{rest}");
+        }
+    }
+}
 
-                return Ok(());
-            }
+/// Writes the days of the week in a day-of-week field in the given format (`MON-FRI`
+/// becomes `1-5` with Sunday as 0, and `FRI-SUN` becomes `5,6,0`).
+pub fn format_days(day_of_week: &mut String, format: DayOfWeekFormat) {
+    if format == DayOfWeekFormat::Names {
+        return;
+    }
+    *day_of_week = day_of_week
+        .split(',')
+        .map(|item| format_day_item(item, format))
+        .collect::<Vec<_>>()
+        .join(",");
+}
 
-            // For other cases, clear the stack to start fresh
-            cron.stack.clear();
+/// Numbers the days of one item of a day-of-week field. A range whose numbers would run
+/// backwards ("Friday through Sunday" from Sunday as 0) is written as a list.
+fn format_day_item(item: &str, format: DayOfWeekFormat) -> String {
+    let position = |day: &str| WEEK_DAYS.iter().position(|name| *name == day);
+    if let Some((Some(start), Some(end))) = item
+        .split_once('-')
+        .map(|(start, end)| (position(start), position(end)))
+    {
+        if day_number(start, format) > day_number(end, format) {
+            let length = (end + 7 - start) % 7 + 1;
+            return (start..start + length)
+                .map(|index| day_number(index % 7, format).to_string())
+                .collect::<Vec<_>>()
+                .join(",");
         }
+    }
+    RE_DAY_NAME
+        .replace_all(item, |captures: &Captures<'_>| {
+            position(&captures[0]).map_or_else(
+                || captures[0].to_string(),
+                |index| day_number(index, format).to_string(),
+            )
+        })
+        .into_owned()
+}
 
-        // Normal processing for days
-        for &day in &WEEK_DAYS {
-            if days.contains(&day.to_string()) && !cron.syntax.day_of_week.contains(day) {
-                write!(cron.syntax.day_of_week, "{day},").map_err(|_| Error::IncorrectValue {
-                    state: "day".to_string(),
-                    error: "Failed to format day of week".to_string(),
-                })?;
-            }
-        }
+/// Returns the number of a day, given by its index in [`WEEK_DAYS`], in the given format.
+const fn day_number(index: usize, format: DayOfWeekFormat) -> usize {
+    match format {
+        DayOfWeekFormat::ZeroBasedSunday0 => (index + 1) % 7,
+        DayOfWeekFormat::ZeroBasedSunday7 => index + 1,
+        DayOfWeekFormat::Names | DayOfWeekFormat::SixBasedMon0 => index,
+    }
+}
 
-        // Handle the WEEKEND case
-        if days.contains(&"WEEKEND".to_string()) {
-            for &day in &["SAT", "SUN"] {
-                if !cron.syntax.day_of_week.contains(day) {
-                    write!(cron.syntax.day_of_week, "{day},").map_err(|_| {
-                        Error::IncorrectValue {
-                            state: "day".to_string(),
-                            error: "Failed to format weekend days".to_string(),
-                        }
-                    })?;
-                }
-            }
+/// Reads a day-of-week field written by [`format_days`] back with day names, so that it
+/// can be read with Quartz numbering. Numbers after `/` or `#` are counts and are kept.
+pub fn name_days(day_of_week: &str, format: DayOfWeekFormat) -> Cow<'_, str> {
+    if format == DayOfWeekFormat::Names {
+        return Cow::Borrowed(day_of_week);
+    }
+    RE_DAY_NUMBER.replace_all(day_of_week, |captures: &Captures<'_>| {
+        let number = captures[2].parse::<usize>().unwrap_or_default();
+        (0..WEEK_DAYS.len())
+            .find(|index| day_number(*index, format) == number)
+            .map_or_else(
+                || captures[0].to_string(),
+                |index| format!("{}{}", &captures[1], WEEK_DAYS[index]),
+            )
+    })
+}
+
+/// Renders a range of days of the week ("Monday through Friday" is `MON-FRI`).
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if either end stands for several days ("Monday
+/// through weekends").
+fn range_of_days(start: &str, end: &str) -> Result<String> {
+    if let Some(group) = [start, end]
+        .into_iter()
+        .find(|day| !WEEK_DAYS.contains(day))
+    {
+        return Err(Error::IncorrectValue {
+            state: "day".to_string(),
+            error: format!("a range of days cannot start or end with {group}"),
+        });
+    }
+    Ok(format!("{start}-{end}"))
+}
+
+/// Splits a rendered day-of-week field back into the day names it lists.
+fn previous_days(day_of_week: &str) -> Vec<String> {
+    if day_of_week == "MON-FRI" {
+        return vec!["WEEKDAY".to_string()];
+    }
+    day_of_week
+        .split(',')
+        .filter(|day| WEEK_DAYS.contains(day))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Processes the given token to update the `cron` object with the specified day of the week information.
+///
+/// This function determines whether the input token specifies days in a "day" or "days" format, or specific weekdays.
+/// It then updates the `day_of_week` and `day_of_month` fields in the provided `cron` object based on the matched days.
+///
+/// # Returns
+///
+/// * [`Result<()>`] - Returns `Ok(())` if the processing is successful, or an `Error` if the token does not match expected formats.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    // "except Saturday and Sunday" runs on every other day of the week
+    let is_except = except::take(cron);
+    let closes_except = is_except
+        .is_none()
+        .then(|| except::take_before_range(cron))
+        .flatten();
+    if is_except.is_some()
+        && cron
+            .stack
+            .last()
+            .is_some_and(|element| element.owner == Kind::Day)
+    {
+        // The excluded days start a list of their own
+        cron.stack.pop();
+    }
+    process_days(token, cron)?;
+    if let Some(marker) = is_except.as_ref().or(closes_except.as_ref()) {
+        if cron.syntax.is_explicit(FieldPosition::DayOfWeek) {
+            except::complement(cron, FieldPosition::DayOfWeek, marker)?;
         }
+    }
+    if let Some(marker) = is_except {
+        except::keep_for_range(cron, marker);
+    }
+    Ok(())
+}
 
-        cron.syntax.day_of_week = cron.syntax.day_of_week.trim_end_matches(',').to_string();
-        cron.syntax.day_of_month = "?".to_string();
+/// Processes a day token without regard to a preceding exclusion.
+fn process_days(token: &str, cron: &mut Cron) -> Result<()> {
+    let is_frequency = cron.stack.last().is_some_and(|element| {
+        element.owner == Kind::FrequencyOnly || element.owner == Kind::FrequencyWith
+    });
+    let is_time_set = is_time_set(cron);
+    // A time of day ("at 9am every day") runs once a day, the same as when it follows
+    let is_time_of_day = cron.syntax.is_explicit(FieldPosition::Hour)
+        && cron
+            .syntax
+            .hour
+            .split(',')
+            .all(|hour| hour.trim().parse::<u32>().is_ok());
+
+    if let Some(captures) = RE_LAST.captures(token) {
+        // Quartz writes the last Friday of the month as `FRIL`
+        cron.syntax.day_of_week = format!("{}L", captures[1].to_uppercase());
+        clear_day_of_month(cron);
+        cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+        cron.stack.clear();
+    } else if let Some(captures) = RE_NTH.captures(token) {
+        cron.syntax.day_of_week =
+            nth_days_of_week(token, &captures[1], &captures[8].to_uppercase())?;
+        clear_day_of_month(cron);
+        cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+        cron.stack.clear();
+    } else if let Some(captures) = RE_DAY_RANGE.captures(token) {
+        cron.syntax.day_of_week = format!(
+            "{}-{}",
+            captures[1].to_uppercase(),
+            captures[2].to_uppercase()
+        );
+        clear_day_of_month(cron);
+        cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+    } else if RE_PARITY.is_match(token) {
+        return process_parity(token, cron);
+    } else if RE_WEEK.is_match(token) || RE_FORTNIGHT.is_match(token) {
+        process_week(token, cron);
+        return Ok(());
+    } else if RE_DAY.is_match(token)
+        && !is_frequency
+        && ((is_time_set && !is_time_of_day) || cron.syntax.is_explicit(FieldPosition::DayOfMonth))
+    {
+        // A bare "every day" adds no restriction to a finer-grained schedule, and "day"
+        // after a list of days ("the 1st and 15th day") only names what they are.
+    } else if RE_DAY.is_match(token) {
+        process_every_day(cron, is_time_of_day)?;
+    } else {
+        return process_weekdays(token, cron);
     }
 
+    push_days(cron);
+    Ok(())
+}
+
+/// Pushes the days of the week that were just set, for a following day or range to
+/// continue.
+fn push_days(cron: &mut Cron) {
     cron.stack.push(
         Stack::builder(Kind::Day)
             .day_of_week(cron.syntax.day_of_week.clone())
             .build(),
     );
+}
+
+/// Processes a "day" token, which repeats every day or, after a frequency, every few
+/// days or on a day of the month.
+fn process_every_day(cron: &mut Cron, is_time_of_day: bool) -> Result<()> {
+    clear_day_of_week(cron);
+    set_default_time(cron);
+
+    if let Some(element) = cron.stack.last() {
+        if element.owner == Kind::FrequencyOnly {
+            cron.syntax.day_of_month = format!("*/{}", element.frequency_to_string());
+            cron.stack.pop();
+        } else if element.owner == Kind::FrequencyWith {
+            cron.syntax.day_of_month = validate_day_of_month(element.frequency)?;
+            cron.stack.pop();
+        } else if is_time_of_day {
+            cron.syntax.day_of_month = "*/1".to_string();
+        } else {
+            cron.syntax.day_of_month = "*".to_string();
+        }
+    } else {
+        cron.syntax.day_of_month = "*/1".to_string();
+    }
+    cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+    Ok(())
+}
+
+/// Reads the days of the week a token names, adding those of a list it continues.
+///
+/// # Errors
+///
+/// Returns an error if the token names no days, or an ordinal joined to them is not a
+/// day of the month.
+fn weekdays_of(token: &str, cron: &mut Cron) -> Result<Vec<String>> {
+    let matches: Vec<_> = RE_WEEKDAYS.find_iter(token).collect();
+    if matches.is_empty() {
+        return Err(Error::IncorrectValue {
+            state: "day".to_string(),
+            error: format!("value {token} is not a weekend format"),
+        });
+    }
+
+    let mut days: Vec<String> = matches
+        .iter()
+        .map(|day| match day.as_str().to_uppercase() {
+            day if day.starts_with("BUSINESS") || day.starts_with("WORK") => "WEEKDAY".to_string(),
+            day => day,
+        })
+        .collect::<Vec<_>>();
+
+    // Consecutive day tokens ("monday  wednesday friday") add to the same list
+    if cron
+        .stack
+        .last()
+        .is_some_and(|element| element.owner == Kind::Day && element.qualifier.is_none())
+    {
+        days.extend(previous_days(&cron.syntax.day_of_week));
+    }
+
+    // An ordinal joined to the weekdays ("on the 15th and on Monday") is a day of the
+    // month, which conflicts with them
+    let joined_ordinal = cron
+        .stack
+        .last()
+        .filter(|element| element.owner == Kind::RangeEnd && element.day.is_none())
+        .and_then(|element| element.frequency_start);
+    if let Some(ordinal) = joined_ordinal {
+        cron.syntax.day_of_month = validate_day_of_month(Some(ordinal))?;
+        cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+        cron.stack.pop();
+    }
+    Ok(days)
+}
+
+/// Processes a token naming days of the week, which may start, end or continue a range
+/// or list.
+fn process_weekdays(token: &str, cron: &mut Cron) -> Result<()> {
+    let days = weekdays_of(token, cron)?;
+    let mut pending = None;
+    if let Some(element) = cron.stack.last_mut() {
+        if element.owner == Kind::RangeStart {
+            if let (true, [start, end]) = (element.is_between_range, days.as_slice()) {
+                // "between Monday and Wednesday" arrives as a single list token
+                cron.syntax.day_of_week = range_of_days(start, end)?;
+                clear_day_of_month(cron);
+                cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+                cron.stack.pop();
+                cron.stack.push(
+                    Stack::builder(Kind::Day)
+                        .day_of_week(cron.syntax.day_of_week.clone())
+                        .build(),
+                );
+                return Ok(());
+            }
+            element.day = Some(StartEndString {
+                start: days.first().cloned(),
+                end: element.day.clone().and_then(|a| a.end),
+            });
+            return Ok(());
+        } else if element.owner == Kind::RangeEnd && element.day.is_some() {
+            // A range closed by a time ("from 9am to 5pm on Monday") leaves no day to
+            // pair this one with, so only a range opened by a day continues here
+            let data = StartEndString {
+                start: element.day.clone().and_then(|a| a.start),
+                end: days.first().cloned(),
+            };
+            element.day = Some(data.clone());
+
+            cron.syntax.day_of_week = match (data.start, data.end) {
+                (Some(start), Some(_)) if element.range_separator() == ',' => {
+                    let mut listed = previous_days(&start);
+                    listed.extend(days);
+                    list_days(&listed)
+                }
+                (Some(start), Some(end)) => range_of_days(&start, &end)?,
+                _ => list_days(&days),
+            };
+
+            clear_day_of_month(cron);
+            cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+            cron.stack.pop();
+            return Ok(());
+        } else if element.owner == Kind::OnlyOn {
+            // Special case for "only on" syntax
+            let day = days.first().cloned().ok_or_else(|| Error::IncorrectValue {
+                state: "day".to_string(),
+                error: "Expected at least one day in 'only on' syntax but found none".to_string(),
+            })?;
+            cron.syntax.day_of_week = day;
+            clear_day_of_month(cron);
+            cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+
+            // Remove the "only on" entry from the stack
+            cron.stack.pop();
+
+            return Ok(());
+        } else if element.owner == Kind::FrequencyOnly {
+            if element.qualifier.is_some() {
+                // Alternate weeks drift across month boundaries, which cron cannot count
+                let day = token.trim_end_matches(|c: char| !c.is_ascii_alphabetic());
+                let day = day.strip_suffix(" and").unwrap_or(day);
+                return Err(Error::UnsupportedSchedule {
+                    schedule: format!("every other {day}"),
+                    suggestion: format!("every {day}"),
+                });
+            }
+            // Keep a bare number ("at 9 every weekday morning") for a following
+            // period of the day to read as the hour
+            pending = cron.stack.pop();
+        }
+
+        // For other cases, clear the stack to start fresh
+        cron.stack.clear();
+    }
+
+    // "weekdays" on its own keeps the compact range; otherwise the days are listed
+    // from Monday, each once
+    let is_weekday = days.contains(&"WEEKDAY".to_string());
+    if is_weekday && days.iter().all(|day| day == "WEEKDAY") {
+        cron.syntax.day_of_week = "MON-FRI".to_string();
+    } else {
+        cron.syntax.day_of_week = list_days(&days);
+    }
+    clear_day_of_month(cron);
+    cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+    push_days(cron);
+    cron.stack.extend(pending);
 
     Ok(())
 }
@@ -0,0 +1,144 @@
+//! Module for processing periods of the day ("morning", "afternoon", "evening", "night")
+//! in cron expressions.
+//!
+//! "every morning" and "nightly" are daily schedules. A time given alongside the period
+//! is read in that part of the day ("every evening at 7" is 19:00, "every night at 11"
+//! is 23:00). When no time is given, the
+//! period falls back to a documented default hour:
+//!
+//! | Period    | Default |
+//! |-----------|---------|
+//! | morning   | 09:00   |
+//! | afternoon | 15:00   |
+//! | evening   | 18:00   |
+//! | night     | 00:00   |
+
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    stack::{Stack, StartEnd},
+    Error, Result,
+};
+use super::clock_time;
+use std::sync::LazyLock;
+
+/// Regular expression to match a period of the day (e.g., "morning", "evenings", "nightly").
+static RE_MATCH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^((morning|afternoon|evening|night)s?|nightly)$").unwrap());
+
+/// Checks if the provided token is a period of the day.
+pub fn try_from_token(str: &str) -> bool {
+    RE_MATCH.is_match(str)
+}
+
+/// Returns the time-of-day qualifier and default hour for a period token.
+fn period(token: &str) -> (&'static str, i32) {
+    let lowercase = token.to_lowercase();
+    if lowercase.starts_with("morning") {
+        ("in the morning", 9)
+    } else if lowercase.starts_with("afternoon") {
+        ("in the afternoon", 15)
+    } else if lowercase.starts_with("evening") {
+        ("in the evening", 18)
+    } else {
+        ("at night", 0)
+    }
+}
+
+/// Processes a period-of-day token.
+///
+/// The schedule becomes daily unless a day restriction is already present. A clock time
+/// already given is shifted into the period; otherwise the period's default hour is used
+/// and the period is kept on the stack so that a following bare hour ("at 8") can be read
+/// in that part of the day.
+///
+/// # Errors
+///
+/// Returns an error if a time given alongside the period contradicts it, or if a bare
+/// hour before it is outside 0-23.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    let (qualifier, default_hour) = period(token);
+
+    if !cron.syntax.is_explicit(FieldPosition::DayOfMonth)
+        && !cron.syntax.is_explicit(FieldPosition::DayOfWeek)
+    {
+        cron.syntax.day_of_month = "*/1".to_string();
+        cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+    }
+
+    if let Some(element) = cron.stack.last() {
+        if element.owner == Kind::FrequencyOnly {
+            // A bare number before the period ("at 9 every weekday morning") is the hour
+            let hour = element.frequency.unwrap_or_default();
+            cron.stack.pop();
+            return set_hour(hour, qualifier, cron);
+        } else if element.owner == Kind::ClockTime {
+            if let Some(hour) = element.hour.as_ref().and_then(|hour| hour.start) {
+                let hour = clock_time::apply_qualifier(hour, None, qualifier)?;
+                cron.syntax.hour = hour.to_string();
+                return Ok(());
+            }
+        }
+    }
+
+    if !cron.syntax.is_explicit(FieldPosition::Hour) {
+        cron.syntax.hour = default_hour.to_string();
+        if !cron.syntax.is_explicit(FieldPosition::Minutes) {
+            cron.syntax.min = "0".to_string();
+        }
+    }
+
+    cron.stack.push(
+        Stack::builder(Kind::DayPeriod)
+            .qualifier(qualifier.to_string())
+            .build(),
+    );
+
+    Ok(())
+}
+
+/// Reads a bare hour following a period of the day ("every morning at 8") as a clock time.
+///
+/// # Errors
+///
+/// Returns an error if the hour is outside 0-23 or contradicts the period.
+pub fn process_hour(hour: i32, cron: &mut Cron) -> Result<()> {
+    let qualifier = cron
+        .stack
+        .pop()
+        .and_then(|element| element.qualifier)
+        .unwrap_or_default();
+    set_hour(hour, &qualifier, cron)
+}
+
+/// Sets a bare hour read in a period of the day as the clock time.
+fn set_hour(hour: i32, qualifier: &str, cron: &mut Cron) -> Result<()> {
+    if !(0..24).contains(&hour) {
+        return Err(Error::IncorrectValue {
+            state: "day_period".to_string(),
+            error: format!("hour {hour} should be between 0 and 23"),
+        });
+    }
+    let hour = clock_time::apply_qualifier(hour, None, qualifier)?;
+
+    cron.syntax.hour = hour.to_string();
+    cron.syntax.min = "0".to_string();
+    cron.syntax.mark_explicit(FieldPosition::Hour);
+    cron.syntax.mark_explicit(FieldPosition::Minutes);
+
+    cron.stack.push(
+        Stack::builder(Kind::ClockTime)
+            .hour(StartEnd {
+                start: Some(hour),
+                end: None,
+            })
+            .min(StartEnd {
+                start: Some(0),
+                end: None,
+            })
+            .build(),
+    );
+
+    Ok(())
+}
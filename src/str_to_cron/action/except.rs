@@ -0,0 +1,161 @@
+//! Module for processing exclusions in cron expressions.
+//!
+//! "except", "excluding" and their forms with "on" or "for" leave a marker on the stack.
+//! The day or month token that follows it is then replaced by its complement, so "every
+//! day except Saturday and Sunday" runs on `MON,TUE,WED,THU,FRI`. Days or months given
+//! before the exclusion are what it excludes from, so "weekdays except Friday" runs on
+//! `MON,TUE,WED,THU`. The marker stays below that token in case it opens a range
+//! ("except Monday through Wednesday"), whose end is then replaced by its complement as
+//! well.
+
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    spec::{self, FieldItem, FieldSpec},
+    stack::{Stack, StartEndString},
+    Error, Result,
+};
+use super::day;
+use std::sync::LazyLock;
+
+/// Regular expression to match an exclusion ("except", "except on", "excluding").
+static RE_MATCH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(except|excluding)( on| for)?$").unwrap());
+
+/// Checks if the provided token starts an exclusion.
+pub fn try_from_token(str: &str) -> bool {
+    RE_MATCH.is_match(str)
+}
+
+/// Processes an exclusion token by leaving a marker for the following days or months.
+///
+/// The marker keeps the days of the week and the months already given, which the
+/// exclusion then applies to.
+pub fn process(cron: &mut Cron) {
+    let given = |value: &str| day::is_specific_day(value).then(|| value.trim().to_string());
+    let mut marker = Stack::builder(Kind::Except)
+        .month(StartEndString {
+            start: given(&cron.syntax.month),
+            end: None,
+        })
+        .build();
+    marker.day_of_week = given(&cron.syntax.day_of_week);
+    cron.stack.push(marker);
+}
+
+/// Returns an error if a token that cannot be excluded follows an exclusion ("every
+/// Monday except the 1st"), since only days of the week and months have a complement.
+pub fn check_excludable(kind: Kind, token: &str, cron: &Cron) -> Result<()> {
+    let is_pending = cron
+        .stack
+        .last()
+        .is_some_and(|element| element.owner == Kind::Except);
+    if is_pending && !matches!(kind, Kind::Day | Kind::Month) {
+        return Err(Error::IncorrectValue {
+            state: "except".to_string(),
+            error: format!("cannot exclude {token}"),
+        });
+    }
+    Ok(())
+}
+
+/// Removes the exclusion marker from the top of the stack, returning it if there was one.
+pub fn take(cron: &mut Cron) -> Option<Stack> {
+    if cron
+        .stack
+        .last()
+        .is_some_and(|element| element.owner == Kind::Except)
+    {
+        cron.stack.pop()
+    } else {
+        None
+    }
+}
+
+/// Removes the exclusion marker below an open range, returning it if there was one.
+pub fn take_before_range(cron: &mut Cron) -> Option<Stack> {
+    let index = match cron.stack.as_slice() {
+        [.., below, last] if below.owner == Kind::Except && last.owner == Kind::RangeEnd => {
+            cron.stack.len() - 2
+        }
+        _ => return None,
+    };
+    Some(cron.stack.remove(index))
+}
+
+/// Puts the exclusion marker back below the element just processed, in case that element
+/// opens a range.
+pub fn keep_for_range(cron: &mut Cron, marker: Stack) {
+    let index = cron.stack.len().saturating_sub(1);
+    cron.stack.insert(index, marker);
+}
+
+/// Replaces the values of a day-of-week or month field with the values it leaves out of
+/// those kept by the exclusion marker, or of the whole field.
+///
+/// Days of the week are listed from Monday to Sunday, matching the order of the other
+/// day lists.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if the field uses a step or a Quartz-only specifier
+/// such as `L`, or if nothing is left once the values are excluded.
+pub fn complement(cron: &mut Cron, field: FieldPosition, marker: &Stack) -> Result<()> {
+    let value = cron.syntax.field(field).trim().to_string();
+    let excluded = values(field, &value)?;
+
+    let within = match field {
+        FieldPosition::DayOfWeek => marker.day_of_week.clone(),
+        _ => marker.month.as_ref().and_then(|month| month.start.clone()),
+    };
+    let mut values = values(field, within.as_deref().unwrap_or("*"))?;
+    if field == FieldPosition::DayOfWeek {
+        // Quartz counts from Sunday, so move it to the end of the week
+        values.sort_unstable_by_key(|day| (day + 5) % 7);
+    }
+    let included: Vec<_> = values
+        .into_iter()
+        .filter(|value| !excluded.contains(value))
+        .map(FieldItem::Value)
+        .collect();
+    if included.is_empty() {
+        return Err(incorrect(format!(
+            "excluding {value} leaves nothing to run on"
+        )));
+    }
+
+    *cron.syntax.field_mut(field) = spec::render_field(field, &FieldSpec::List(included));
+    Ok(())
+}
+
+/// Returns the values a day-of-week or month field runs on.
+fn values(field: FieldPosition, value: &str) -> Result<Vec<u32>> {
+    let (min, max) = spec::bounds(field);
+    let mut values = Vec::new();
+    match spec::parse_field(field, value)? {
+        FieldSpec::List(items) => {
+            for item in items {
+                match item {
+                    FieldItem::Value(value) => values.push(value),
+                    FieldItem::Range { start, end } if start <= end => values.extend(start..=end),
+                    FieldItem::Range { start, end } => {
+                        values.extend((start..=max).chain(min..=end));
+                    }
+                    FieldItem::Step { .. } | FieldItem::Special(_) => {
+                        return Err(incorrect(format!("cannot exclude {value}")));
+                    }
+                }
+            }
+        }
+        FieldSpec::Any | FieldSpec::NoSpecificValue => values.extend(min..=max),
+    }
+    Ok(values)
+}
+
+fn incorrect(error: String) -> Error {
+    Error::IncorrectValue {
+        state: "except".to_string(),
+        error,
+    }
+}
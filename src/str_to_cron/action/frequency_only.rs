@@ -1,41 +1,99 @@
-/// This module provides functionality for processing frequency-related tokens
-/// within cron expressions. It defines a function to validate frequency inputs
-/// and another to process these inputs, updating the associated `Cron` structure.
-///
-use super::super::{action::Kind, cron::Cron, stack::Stack};
-use regex::Regex;
+//! This module provides functionality for processing frequency-related tokens
+//! within cron expressions. It defines a function to validate frequency inputs
+//! and another to process these inputs, updating the associated `Cron` structure.
+
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    stack::Stack,
+    Error, Result,
+};
+use super::{day_period, range_start};
 use std::sync::LazyLock;
 
-static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]+$").unwrap());
+/// Matches numbers of up to three digits, leaving four-digit numbers to be read as years
+/// unless a unit of time follows them ("every 1440 minutes").
+static RE_MATCH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^([0-9]{1,3}|other)$").unwrap());
 
 /// Checks if the given string is a valid frequency token.
 pub fn try_from_token(str: &str) -> bool {
     RE_MATCH.is_match(str)
 }
 
+/// Parses a frequency token, reading "other" ("every other day") as 2.
+fn parse(token: &str) -> Result<i32> {
+    if is_other(token) {
+        return Ok(2);
+    }
+    token.parse::<i32>().map_err(|_| Error::ParseToNumber {
+        state: "frequency_only".to_string(),
+        value: token.to_string(),
+    })
+}
+
+/// Returns whether the token is the "other" of "every other day".
+const fn is_other(token: &str) -> bool {
+    token.eq_ignore_ascii_case("other")
+}
+
 /// Processes the given frequency and updates the specified `Cron` structure.
 ///
 /// This function modifies the `cron` stack based on the provided frequency.
 /// If the last item in the stack indicates the start or end of a range,
 /// the function updates the corresponding frequency fields. If the stack
-/// is empty, it adds a new entry with the specified frequency.
-pub fn process(frequency: i32, cron: &mut Cron) {
-    if !cron.stack.is_empty() {
-        if let Some(last_stack) = cron.stack.last_mut() {
-            if last_stack.owner == Kind::RangeEnd {
-                last_stack.frequency_end = Some(frequency);
-                return;
-            } else if last_stack.owner == Kind::RangeStart {
-                last_stack.frequency_start = Some(frequency);
-                return;
+/// is empty, it adds a new entry with the specified frequency. A number following
+/// a period of the day ("every morning at 8") is read as the hour. An "other"
+/// frequency is kept as the qualifier of the new entry so that units can tell
+/// "every other month" apart from "on 2 month".
+///
+/// # Errors
+///
+/// Returns an error if the token is not a number, or if an hour following a period
+/// of the day contradicts it.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    let frequency = parse(token)?;
+
+    if let Some(last_stack) = cron.stack.last_mut() {
+        if last_stack.owner == Kind::RangeEnd {
+            if last_stack.range_separator() == ',' {
+                // Keep every number of a list ("0, 6, 12 and 18 hours") for its unit
+                if last_stack.listed.is_empty() {
+                    last_stack.listed.extend(last_stack.frequency_start);
+                }
+                last_stack.listed.push(frequency);
             }
-        } else {
-            panic!("handle later")
+            last_stack.frequency_end = Some(frequency);
+            return Ok(());
+        } else if last_stack.owner == Kind::RangeStart {
+            last_stack.frequency_start = Some(frequency);
+            // The offset applies to the finest repetition ("every 10 seconds starting at 3",
+            // "every 2 hours starting at 5")
+            let (field, size) = [
+                (FieldPosition::Seconds, 60),
+                (FieldPosition::Minutes, 60),
+                (FieldPosition::Hour, 24),
+            ]
+            .into_iter()
+            .find(|(field, _)| cron.syntax.field(*field).contains('/'))
+            .unwrap_or((FieldPosition::Minutes, 60));
+            if range_start::is_starting(cron)
+                && (0..size).contains(&frequency)
+                && range_start::apply_step_offset(cron, field, frequency)
+            {
+                cron.stack.pop();
+            }
+            return Ok(());
+        } else if last_stack.owner == Kind::DayPeriod {
+            return day_period::process_hour(frequency, cron);
         }
     }
-    cron.stack.push(
-        Stack::builder(Kind::FrequencyOnly)
-            .frequency(frequency)
-            .build(),
-    );
+    let mut builder = Stack::builder(Kind::FrequencyOnly).frequency(frequency);
+    if is_other(token) {
+        builder = builder.qualifier(token.to_lowercase());
+    }
+    cron.stack.push(builder.build());
+
+    Ok(())
 }
@@ -0,0 +1,190 @@
+//! Module for processing a number of runs per unit ("twice a day", "three times a week").
+//!
+//! The tokenizer writes these phrases as "N times per unit". The unit is split into whole
+//! parts of the next smaller unit, so "three times a day" runs every 8 hours and "twice a
+//! year" every 6 months, and a count that does not divide the unit evenly is rejected.
+//!
+//! A week's seven days cannot be split in two or three, so those counts are spread from
+//! Monday as evenly as whole days allow: twice a week runs on `MON,THU` and three times a
+//! week on `MON,WED,FRI`. A month is counted as 28 days, which every month has, so twice a
+//! month runs on the 1st and 15th.
+
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    stack::Stack,
+    Error, Result,
+};
+use super::{day, hour, minute, month, seconds, year};
+use std::sync::LazyLock;
+
+/// Regular expression to match a number of runs per unit (e.g., "3 times per day").
+static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^([0-9]+) times per (minute|hour|day|week|month|year)$").unwrap()
+});
+
+/// Each unit with its length in the next smaller unit, the counts that split it evenly,
+/// and the name of that smaller unit.
+const UNITS: [(&str, u32, &[u32], &str); 6] = [
+    (
+        "minute",
+        60,
+        &[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60],
+        "seconds",
+    ),
+    (
+        "hour",
+        60,
+        &[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60],
+        "minutes",
+    ),
+    ("day", 24, &[1, 2, 3, 4, 6, 8, 12, 24], "hours"),
+    ("week", 7, &[1, 2, 3, 7], "days"),
+    ("month", 28, &[1, 2, 4, 7, 14, 28], "days"),
+    ("year", 12, &[1, 2, 3, 4, 6, 12], "months"),
+];
+
+/// Checks if the provided token is a number of runs per unit.
+pub fn try_from_token(str: &str) -> bool {
+    RE_MATCH.is_match(str)
+}
+
+/// Processes a number of runs per unit.
+///
+/// Once per unit runs the same as "every unit". A larger count repeats every
+/// `unit / count` of the smaller unit, except within a week or a month, where it sets
+/// the days that are run on. Those run at midnight unless a time is given.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if the count is 0 or the unit is unknown, and
+/// [`Error::UnsupportedSchedule`] if the count does not divide the unit evenly.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    let (count, unit) = parse(token)?;
+    let Some(&(_, size, counts, smaller)) = UNITS.iter().find(|(name, ..)| *name == unit) else {
+        return Err(Error::IncorrectValue {
+            state: "frequency_times".to_string(),
+            error: format!("{unit} is not a unit that can be run several times in"),
+        });
+    };
+
+    if count == 0 {
+        return Err(Error::IncorrectValue {
+            state: "frequency_times".to_string(),
+            error: format!("0 times a {unit} should be at least once"),
+        });
+    }
+    if !counts.contains(&count) {
+        let lower = counts.iter().rev().find(|&&candidate| candidate < count);
+        let upper = counts.iter().find(|&&candidate| candidate > count);
+        let suggestion = match (lower, upper) {
+            (Some(lower), Some(upper)) => format!("{lower} or {upper} times a {unit}"),
+            (Some(closest), None) | (None, Some(closest)) => format!("{closest} times a {unit}"),
+            (None, None) => format!("once a {unit}"),
+        };
+        return Err(Error::UnsupportedSchedule {
+            schedule: format!("{count} times a {unit}"),
+            suggestion,
+        });
+    }
+
+    match (unit.as_str(), count) {
+        (_, 1) => repeat(cron, 1, &unit),
+        ("week", 2 | 3) => {
+            let days: Vec<_> = (0..count)
+                .map(|index| day::WEEK_DAYS[(index * size / count) as usize])
+                .collect();
+            cron.syntax.day_of_week = days.join(",");
+            cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+            day::clear_day_of_month(cron);
+            set_day_time(cron);
+            Ok(())
+        }
+        ("month", _) => {
+            let days: Vec<_> = (0..count)
+                .map(|index| (1 + index * size / count).to_string())
+                .collect();
+            cron.syntax.day_of_month = days.join(",");
+            cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+            day::clear_day_of_week(cron);
+            set_day_time(cron);
+            Ok(())
+        }
+        _ => repeat(cron, size / count, smaller),
+    }
+}
+
+/// Splits a number of runs per unit into its count and unit.
+fn parse(token: &str) -> Result<(u32, String)> {
+    RE_MATCH
+        .captures(token)
+        .and_then(|captures| {
+            let count = captures[1].parse::<u32>().ok()?;
+            Some((count, captures[2].to_lowercase()))
+        })
+        .ok_or_else(|| Error::IncorrectValue {
+            state: "frequency_times".to_string(),
+            error: format!("value {token} is not a number of times per unit"),
+        })
+}
+
+/// Returns the field that carries the count of a number of runs per unit ("twice a day"
+/// repeats in the hours), or `None` for once per unit, which a time or day given as well
+/// narrows ("once a day at 9am").
+pub fn counted_field(token: &str) -> Option<FieldPosition> {
+    let (count, unit) = parse(token).ok()?;
+    if count < 2 {
+        return None;
+    }
+    match unit.as_str() {
+        "minute" => Some(FieldPosition::Seconds),
+        "hour" => Some(FieldPosition::Minutes),
+        "day" => Some(FieldPosition::Hour),
+        "week" if count == 7 => Some(FieldPosition::DayOfMonth),
+        "week" => Some(FieldPosition::DayOfWeek),
+        "month" => Some(FieldPosition::DayOfMonth),
+        "year" => Some(FieldPosition::Month),
+        _ => None,
+    }
+}
+
+/// Returns the error for a number of runs per unit whose field is also set by another
+/// clause ("twice a day at 9am", "on Tuesday twice a week"), which would lose the count.
+pub fn overridden(token: &str, field: FieldPosition) -> Error {
+    let what = match field {
+        FieldPosition::Seconds | FieldPosition::Minutes | FieldPosition::Hour => "time",
+        FieldPosition::DayOfMonth | FieldPosition::DayOfWeek => "days",
+        FieldPosition::Month | FieldPosition::Year => "months",
+    };
+    let schedule = parse(token).map_or_else(
+        |_| token.to_string(),
+        |(count, unit)| format!("{count} times a {unit}"),
+    );
+    Error::IncorrectValue {
+        state: "frequency_times".to_string(),
+        error: format!("{schedule} cannot be combined with the {what} also given"),
+    }
+}
+
+/// Repeats every `step` of a unit, the same as "every 8 hours".
+fn repeat(cron: &mut Cron, step: u32, unit: &str) -> Result<()> {
+    let step = i32::try_from(step).unwrap_or(i32::MAX);
+    cron.stack
+        .push(Stack::builder(Kind::FrequencyOnly).frequency(step).build());
+    match unit {
+        "seconds" => seconds::process(unit, cron),
+        "minute" | "minutes" => minute::process(unit, cron),
+        "hour" | "hours" => hour::process(unit, cron),
+        "month" | "months" => month::process(unit, cron),
+        "year" => year::process(unit, cron),
+        _ => day::process(unit, cron),
+    }
+}
+
+/// Runs the days just set at midnight, unless a time is already given.
+fn set_day_time(cron: &mut Cron) {
+    if !day::is_time_set(cron) {
+        day::set_default_time(cron);
+    }
+}
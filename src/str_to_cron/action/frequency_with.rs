@@ -4,12 +4,26 @@
 //! the `process` function applies the detected frequency to the appropriate cron field.
 //!
 //! The file is a part of a larger module that converts human-readable strings into cron syntax.
-use super::super::{action::Kind, cron::Cron, stack::Stack, Error, Result};
-use regex::Regex;
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    stack::Stack,
+    Error, Result,
+};
+use super::day;
 use std::sync::LazyLock;
 
 /// A regex pattern that matches frequency tokens with ordinal suffixes like "th", "nd", "rd", or "st".
-static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]+(th|nd|rd|st)$").unwrap());
+/// A list of ordinals may repeat "the", "on the" or "day" between its items ("the 1st day
+/// and the 15th", "the 2nd, on the 10th and 20th").
+static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[0-9]+(th|nd|rd|st)(( days?)?( and)+( on)?( the)? [0-9]+(th|nd|rd|st))*$")
+        .unwrap()
+});
+
+/// A regex pattern that finds every number in a list of ordinals (e.g., "1st and 10th and 20th").
+static RE_NUMBERS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[0-9]+").unwrap());
 
 /// A regex pattern that extracts the numeric prefix of a token, assuming it starts with a number.
 static RE_NUMERIC_PREFIX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]+").unwrap());
@@ -29,6 +43,12 @@ pub fn try_from_token(str: &str) -> bool {
 /// Returns an error if the token doesn't contain a numeric prefix or if parsing the number fails.
 ///
 pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    day::reject_parity_conflict(cron, token)?;
+
+    if RE_NUMBERS.find_iter(token).nth(1).is_some() {
+        return process_list(token, cron);
+    }
+
     let maybe_numeric_prefix = RE_NUMERIC_PREFIX
         .find(token)
         .ok_or_else(|| Error::Capture {
@@ -44,9 +64,26 @@ pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
                 value: maybe_numeric_prefix.as_str().to_string(),
             })?;
 
+    // A time range that is already complete ("between 9am and 5pm on the 15th") leaves
+    // the ordinal to stand on its own
+    let closes_range = |element: &Stack| {
+        element.owner == Kind::RangeEnd
+            && element.hour.as_ref().is_none_or(|hour| hour.end.is_none())
+    };
     if let Some(element) = cron.stack.last_mut() {
-        if element.owner == Kind::RangeEnd {
+        if closes_range(element) {
             element.frequency_end = Some(frequency);
+            if element.frequency_start.is_some() {
+                // Two ordinals joined by a connector ("the 1st to the 15th") describe days of the month.
+                cron.syntax.day_of_month = day::day_of_month_range(element)?;
+                cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+                day::clear_day_of_week(cron);
+                // Like a single date, a range of dates runs at midnight unless a time is given
+                if !day::is_time_set(cron) {
+                    day::set_default_time(cron);
+                }
+                cron.stack.pop();
+            }
             return Ok(());
         } else if element.owner == Kind::RangeStart {
             element.frequency_start = Some(frequency);
@@ -63,3 +100,46 @@ pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
 
     Ok(())
 }
+
+/// Processes a list of ordinals ("the 1st, 10th and 20th") into a day-of-month list,
+/// keeping the order they were given in and dropping repeated days. Two ordinals after
+/// "between" form a range instead ("between the 5th and 10th").
+///
+/// # Errors
+///
+/// Returns an error if a number cannot be parsed or a day is outside 1-31.
+fn process_list(token: &str, cron: &mut Cron) -> Result<()> {
+    let mut values = Vec::new();
+    let mut days: Vec<String> = Vec::new();
+    for number in RE_NUMBERS.find_iter(token) {
+        let value = number
+            .as_str()
+            .parse::<i32>()
+            .map_err(|_| Error::ParseToNumber {
+                state: "frequency_with".to_string(),
+                value: number.as_str().to_string(),
+            })?;
+        let day = day::validate_day_of_month(Some(value))?;
+        values.push(value);
+        if !days.contains(&day) {
+            days.push(day);
+        }
+    }
+
+    let is_between = cron
+        .stack
+        .last()
+        .is_some_and(|element| element.owner == Kind::RangeStart && element.is_between_range);
+    cron.syntax.day_of_month = match (is_between, values.as_slice()) {
+        (true, &[start, end]) => {
+            day::validate_day_of_month_order(start, end)?;
+            cron.stack.pop();
+            format!("{start}-{end}")
+        }
+        _ => days.join(","),
+    };
+    cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+    day::clear_day_of_week(cron);
+
+    Ok(())
+}
@@ -2,12 +2,13 @@
 //! It validates input tokens representing hours and updates the `Cron` structure
 //! accordingly.
 //!
+use super::super::re::Regex;
 use super::super::{
     action::Kind,
-    cron::Cron,
+    cron::{Cron, FieldPosition},
     stack::{Stack, StartEnd},
+    Error, Result,
 };
-use regex::Regex;
 use std::sync::LazyLock;
 
 /// Regex pattern for matching any form of the word "hour" (including "hrs" and "hours").
@@ -29,25 +30,41 @@ pub fn try_from_token(str: &str) -> bool {
 /// This function modifies the `cron` stack based on the provided hour token.
 /// If the last item in the stack indicates a frequency, the function updates the
 /// corresponding hour fields. If a range start or end is detected, it adjusts
-/// the hour range accordingly.
-pub fn process(token: &str, cron: &mut Cron) {
+/// the hour range accordingly. Hours listed with "and" ("at 0, 6, 12 and 18 hours") are
+/// joined into a list.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if a single hour ("the 25th hour"), a listed hour, or
+/// either end of a range is outside 0-23.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
     if RE_HOUR.is_match(token) {
         let mut hour = None;
+        let mut step = None;
         if let Some(element) = cron.stack.last_mut() {
             if element.owner == Kind::FrequencyOnly {
+                step = element.frequency;
                 hour = Some(StartEnd {
                     start: element.frequency,
                     end: None,
                 });
                 cron.syntax.hour = format!("0/{}", element.frequency_to_string());
+                cron.syntax.mark_explicit(FieldPosition::Hour);
                 cron.syntax.min = "0".to_string();
                 cron.stack.pop();
             } else if element.owner == Kind::FrequencyWith {
+                if let Some(hour) = element.frequency.filter(|hour| !(0..24).contains(hour)) {
+                    return Err(Error::IncorrectValue {
+                        state: "hour".to_string(),
+                        error: format!("hour {hour} should be between 0 and 23"),
+                    });
+                }
                 hour = Some(StartEnd {
                     start: element.frequency,
                     end: None,
                 });
                 cron.syntax.hour = element.frequency_to_string();
+                cron.syntax.mark_explicit(FieldPosition::Hour);
                 cron.syntax.min = "0".to_string();
                 cron.stack.pop();
             } else if element.owner == Kind::RangeStart {
@@ -55,29 +72,64 @@ pub fn process(token: &str, cron: &mut Cron) {
                     start: element.frequency_start,
                     end: None,
                 });
-                return;
+                return Ok(());
             } else if element.owner == Kind::RangeEnd {
                 element.min = Some(StartEnd {
                     start: element.frequency_start,
                     end: element.frequency_end,
                 });
-                element.frequency_end = None;
+                let frequency_end = element.frequency_end.take();
 
-                if let (Some(frequency_start), Some(frequency_end)) =
-                    (element.frequency_start, element.frequency_end)
+                if element.range_separator() == ',' && element.listed.len() > 1 {
+                    // A list of hours joined by "and" ("at 0, 6, 12 and 18 hours")
+                    if let Some(hour) = element.listed.iter().find(|hour| !(0..24).contains(*hour))
+                    {
+                        return Err(Error::IncorrectValue {
+                            state: "hour".to_string(),
+                            error: format!("hour {hour} should be between 0 and 23"),
+                        });
+                    }
+                    let hours: Vec<_> = element.listed.iter().map(ToString::to_string).collect();
+                    cron.syntax.hour = hours.join(",");
+                    cron.syntax.mark_explicit(FieldPosition::Hour);
+                    cron.syntax.min = "0".to_string();
+                    element.owner = Kind::Hour;
+                    element.is_and_connector = false;
+                } else if let (Some(frequency_start), Some(frequency_end)) =
+                    (element.frequency_start, frequency_end)
                 {
+                    if let Some(hour) = [frequency_start, frequency_end]
+                        .into_iter()
+                        .find(|hour| !(0..24).contains(hour))
+                    {
+                        return Err(Error::IncorrectValue {
+                            state: "hour".to_string(),
+                            error: format!("hour {hour} should be between 0 and 23"),
+                        });
+                    }
                     cron.syntax.hour = format!("{frequency_start}-{frequency_end}",);
+                    cron.syntax.mark_explicit(FieldPosition::Hour);
                     cron.syntax.min = "0".to_string();
                 }
 
-                return;
+                return Ok(());
             }
         }
         cron.syntax.min = "0".to_string();
+        if hour.is_none() && !cron.syntax.is_explicit(FieldPosition::Hour) {
+            // A bare "every hour" runs around the clock, replacing any midnight default.
+            cron.syntax.hour = "*".to_string();
+            cron.syntax.mark_explicit(FieldPosition::Hour);
+        }
 
         if let Some(hour) = hour {
-            cron.stack
-                .push(Stack::builder(Kind::Minute).hour(hour).build());
+            // The frequency marks a repetition ("every 5 hours") rather than a fixed value
+            let mut builder = Stack::builder(Kind::Minute).hour(hour);
+            if let Some(step) = step {
+                builder = builder.frequency(step);
+            }
+            cron.stack.push(builder.build());
         }
     }
+    Ok(())
 }
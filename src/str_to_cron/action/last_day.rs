@@ -0,0 +1,120 @@
+//! Module for processing days counted back from the end of the month.
+//!
+//! Quartz writes the last day of the month as `L` and the days before it as `L-N`.
+//! The phrasings below are all recognized:
+//!
+//! | Phrase                                    | Day of month |
+//! |-------------------------------------------|--------------|
+//! | the last day of the month                 | `L`          |
+//! | the next to last / second to last day     | `L-1`        |
+//! | the penultimate day of the month          | `L-1`        |
+//! | the third to last day                     | `L-2`        |
+//! | 3 days before the end of the month        | `L-3`        |
+
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    stack::Stack,
+    Error, Result,
+};
+use super::day;
+use std::sync::LazyLock;
+
+/// The largest offset Quartz accepts after `L-`.
+const MAX_OFFSET: i32 = 30;
+
+/// Word ordinals that can precede "to last", starting with "second".
+const ORDINALS: [&str; 9] = [
+    "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth", "tenth",
+];
+
+/// Regular expression to match a day counted back from the end of the month.
+static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^((([a-z]+|[0-9]+(st|nd|rd|th)) to )?last day|penultimate day|[0-9]+ days? before the end)( of (the|each|every) month)?$").unwrap()
+});
+
+/// Regular expression to capture the ordinal of "<ordinal> to last".
+static RE_ORDINAL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^([a-z]+|[0-9]+)(st|nd|rd|th)? to last").unwrap());
+
+/// Regular expression to capture the count of "N days before the end".
+static RE_DAYS_BEFORE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^([0-9]+) days? before").unwrap());
+
+/// Checks if the provided token is a day counted back from the end of the month.
+pub fn try_from_token(str: &str) -> bool {
+    RE_MATCH.is_match(str)
+}
+
+/// Returns how many days before the last day of the month the token points at.
+fn offset(token: &str) -> Result<i32> {
+    let lowercase = token.to_lowercase();
+    if lowercase.starts_with("penultimate") {
+        return Ok(1);
+    }
+
+    let (count, is_ordinal) = if let Some(captures) = RE_DAYS_BEFORE.captures(&lowercase) {
+        (captures[1].to_string(), false)
+    } else if let Some(captures) = RE_ORDINAL.captures(&lowercase) {
+        (captures[1].to_string(), true)
+    } else {
+        return Ok(0);
+    };
+
+    let number = match count.as_str() {
+        "next" => 2,
+        word if word.starts_with(|c: char| c.is_ascii_alphabetic()) => ORDINALS
+            .iter()
+            .position(|ordinal| *ordinal == word)
+            .and_then(|index| i32::try_from(index + 2).ok())
+            .ok_or_else(|| Error::IncorrectValue {
+                state: "last_day".to_string(),
+                error: format!("{word} is not a supported ordinal"),
+            })?,
+        digits => digits.parse::<i32>().map_err(|_| Error::ParseToNumber {
+            state: "last_day".to_string(),
+            value: digits.to_string(),
+        })?,
+    };
+
+    // "the second to last day" is one day before the last, while "2 days before the end" is two
+    let offset = if is_ordinal { number - 1 } else { number };
+    if !(0..=MAX_OFFSET).contains(&offset) {
+        return Err(Error::IncorrectValue {
+            state: "last_day".to_string(),
+            error: format!(
+                "offset from the end of the month should be between 0 and {MAX_OFFSET}, found {offset}"
+            ),
+        });
+    }
+    Ok(offset)
+}
+
+/// Processes a day counted back from the end of the month, setting the day-of-month field
+/// to `L` or `L-N` and defaulting the time to midnight.
+///
+/// # Errors
+///
+/// Returns an error if the ordinal is not recognized or the offset exceeds 30 days.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    let offset = offset(token)?;
+
+    cron.syntax.day_of_month = if offset == 0 {
+        "L".to_string()
+    } else {
+        format!("L-{offset}")
+    };
+    day::clear_day_of_week(cron);
+    cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+    if cron.syntax.min == "*" {
+        cron.syntax.min = "0".to_string();
+    }
+    if cron.syntax.hour == "*" {
+        cron.syntax.hour = "0".to_string();
+    }
+
+    cron.stack.push(Stack::builder(Kind::LastDay).build());
+
+    Ok(())
+}
@@ -2,12 +2,13 @@
 //! It validates input tokens representing minutes and updates the `Cron` structure
 //! accordingly.
 
+use super::super::re::Regex;
 use super::super::{
     action::Kind,
-    cron::Cron,
+    cron::{Cron, FieldPosition},
     stack::{Stack, StartEnd},
+    Error, Result,
 };
-use regex::Regex;
 use std::sync::LazyLock;
 
 /// Regex pattern for matching any form of the word "minute" (including "mins" and "minutes").
@@ -32,30 +33,47 @@ pub fn try_from_token(str: &str) -> bool {
 /// If the last item in the stack indicates a frequency, the function updates the
 /// corresponding minute fields. If a range start or end is detected, it adjusts
 /// the minute range accordingly.
-pub fn process(token: &str, cron: &mut Cron) {
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if a single minute ("the 75th minute") or either end
+/// of a range of minutes is outside 0-59.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
     if RE_MINUTES.is_match(token) {
         let mut minutes = None;
+        let mut step = None;
         if let Some(element) = cron.stack.last_mut() {
             if element.owner == Kind::FrequencyOnly {
+                step = element.frequency;
                 minutes = Some(StartEnd {
                     start: element.frequency,
                     end: None,
                 });
                 cron.syntax.min = format!("0/{}", element.frequency_to_string());
+                cron.syntax.mark_explicit(FieldPosition::Minutes);
+                cron.syntax.clear_default(FieldPosition::Hour);
                 cron.stack.pop();
             } else if element.owner == Kind::FrequencyWith {
+                if let Some(minute) = element.frequency.filter(|minute| !(0..60).contains(minute)) {
+                    return Err(Error::IncorrectValue {
+                        state: "minute".to_string(),
+                        error: format!("minute {minute} should be between 0 and 59"),
+                    });
+                }
                 minutes = Some(StartEnd {
                     start: element.frequency,
                     end: None,
                 });
                 cron.syntax.min = element.frequency_to_string();
+                cron.syntax.mark_explicit(FieldPosition::Minutes);
+                cron.syntax.clear_default(FieldPosition::Hour);
                 cron.stack.pop();
             } else if element.owner == Kind::RangeStart {
                 element.min = Some(StartEnd {
                     start: element.frequency_start,
                     end: None,
                 });
-                return;
+                return Ok(());
             } else if element.owner == Kind::RangeEnd {
                 element.min = Some(StartEnd {
                     start: element.frequency_start,
@@ -66,16 +84,39 @@ pub fn process(token: &str, cron: &mut Cron) {
                 if let (Some(frequency_start), Some(frequency_end)) =
                     (element.frequency_start, element.frequency_end)
                 {
+                    if let Some(minute) = [frequency_start, frequency_end]
+                        .into_iter()
+                        .find(|minute| !(0..60).contains(minute))
+                    {
+                        return Err(Error::IncorrectValue {
+                            state: "minute".to_string(),
+                            error: format!("minute {minute} should be between 0 and 59"),
+                        });
+                    }
                     cron.syntax.min = format!("{frequency_start}-{frequency_end}",);
+                    cron.syntax.mark_explicit(FieldPosition::Minutes);
                 }
 
-                return;
+                return Ok(());
             }
         }
 
+        if minutes.is_none() && !cron.syntax.is_explicit(FieldPosition::Minutes) {
+            // A bare "every minute" runs around the clock, replacing any midnight default.
+            cron.syntax.min = "*".to_string();
+            cron.syntax.mark_explicit(FieldPosition::Minutes);
+            cron.syntax.clear_default(FieldPosition::Hour);
+            cron.stack.push(Stack::builder(Kind::Minute).build());
+        }
+
         if let Some(minutes) = minutes {
-            cron.stack
-                .push(Stack::builder(Kind::Minute).min(minutes).build());
+            // The frequency marks a repetition ("every 5 minutes") rather than a fixed value
+            let mut builder = Stack::builder(Kind::Minute).min(minutes);
+            if let Some(step) = step {
+                builder = builder.frequency(step);
+            }
+            cron.stack.push(builder.build());
         }
     }
+    Ok(())
 }
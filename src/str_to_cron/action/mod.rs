@@ -1,17 +1,25 @@
 //! This module defines the various kinds of tokens that can be processed in a cron expression.
 //! It provides functions to match and process these tokens accordingly.
 
-use super::{cron::Cron, Error, Result};
+use super::{cron::Cron, Result};
 mod clock_time;
-mod day;
+pub(super) mod day;
+mod day_period;
+mod except;
 mod frequency_only;
+pub(super) mod frequency_times;
 mod frequency_with;
 mod hour;
+mod last_day;
 mod minute;
-mod month;
+pub(super) mod month;
+mod past_hour;
+mod quarter;
 mod range_end;
 mod range_start;
+mod scope;
 mod seconds;
+mod sub_hour;
 mod year;
 
 /// An enumeration of the kinds of tokens that can be processed in a cron expression.
@@ -21,8 +29,20 @@ pub enum Kind {
     FrequencyWith,
     /// Token indicating a frequency without specific intervals.
     FrequencyOnly,
+    /// Token indicating a number of runs per unit such as "twice a day".
+    FrequencyTimes,
+    /// Token indicating a day counted back from the end of the month.
+    LastDay,
+    /// Token indicating a period of the day such as "morning".
+    DayPeriod,
     /// Token indicating a specific time on a clock.
     ClockTime,
+    /// Token indicating a scoping suffix such as "of each hour".
+    Scope,
+    /// Token indicating a window within the hour or minute.
+    SubHour,
+    /// Token indicating a number of minutes past the hour.
+    PastHour,
     /// Token indicating days of the week.
     Day,
     /// Token indicating secund.
@@ -33,6 +53,8 @@ pub enum Kind {
     Hour,
     /// Token indicating months.
     Month,
+    /// Token indicating a business quarter.
+    Quarter,
     /// Token indicating years.
     Year,
     /// Token indicating the start of a range.
@@ -41,6 +63,14 @@ pub enum Kind {
     RangeEnd,
     /// Token indicating "only on" directive.
     OnlyOn,
+    /// Token indicating that the following days or months are excluded.
+    Except,
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description())
+    }
 }
 
 /// Attempts to match the provided token to one of the `Kind` enumerations.
@@ -50,16 +80,24 @@ pub fn try_from_token(token: &str) -> Option<Kind> {
         let is_match = match state_kind {
             Kind::FrequencyWith => frequency_with::try_from_token(token),
             Kind::FrequencyOnly => frequency_only::try_from_token(token),
+            Kind::FrequencyTimes => frequency_times::try_from_token(token),
+            Kind::LastDay => last_day::try_from_token(token),
+            Kind::DayPeriod => day_period::try_from_token(token),
             Kind::ClockTime => clock_time::try_from_token(token),
+            Kind::Scope => scope::try_from_token(token),
+            Kind::SubHour => sub_hour::try_from_token(token),
+            Kind::PastHour => past_hour::try_from_token(token),
             Kind::Day => day::try_from_token(token),
             Kind::Secund => seconds::try_from_token(token),
             Kind::Minute => minute::try_from_token(token),
             Kind::Hour => hour::try_from_token(token),
             Kind::Month => month::try_from_token(token),
+            Kind::Quarter => quarter::try_from_token(token),
             Kind::Year => year::try_from_token(token),
             Kind::RangeStart => range_start::try_from_token(token),
             Kind::RangeEnd => range_end::try_from_token(token),
             Kind::OnlyOn => token.to_lowercase() == "only on",
+            Kind::Except => except::try_from_token(token),
         };
         if is_match {
             return Some(state_kind);
@@ -70,43 +108,98 @@ pub fn try_from_token(token: &str) -> Option<Kind> {
 
 impl Kind {
     /// Provides an iterator over all possible [`Kind`] values.
-    const fn iterator() -> [Self; 12] {
+    const fn iterator() -> [Self; 20] {
         [
             Self::FrequencyWith,
             Self::FrequencyOnly,
+            Self::FrequencyTimes,
+            Self::LastDay,
+            Self::DayPeriod,
             Self::ClockTime,
+            Self::Scope,
+            Self::SubHour,
+            Self::PastHour,
             Self::Day,
             Self::Secund,
             Self::Minute,
             Self::Hour,
             Self::Month,
+            Self::Quarter,
             Self::Year,
             Self::RangeStart,
             Self::RangeEnd,
             Self::OnlyOn,
+            Self::Except,
         ]
     }
 
+    /// Returns a human-readable name for the kind of token.
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::FrequencyWith => "Frequency with ordinal",
+            Self::FrequencyOnly => "Frequency",
+            Self::FrequencyTimes => "Times per unit",
+            Self::LastDay => "Day from the end of the month",
+            Self::DayPeriod => "Period of the day",
+            Self::ClockTime => "Clock time",
+            Self::Scope => "Scope",
+            Self::SubHour => "Window within the hour",
+            Self::PastHour => "Minutes past the hour",
+            Self::Day => "Day",
+            Self::Secund => "Seconds",
+            Self::Minute => "Minutes",
+            Self::Hour => "Hours",
+            Self::Month => "Month",
+            Self::Quarter => "Quarter",
+            Self::Year => "Year",
+            Self::RangeStart => "Range start",
+            Self::RangeEnd => "Range end",
+            Self::OnlyOn => "Only on",
+            Self::Except => "Except",
+        }
+    }
+
+    /// Returns the name of the cron field primarily affected by the kind of token,
+    /// or `None` if the kind does not target a single field (e.g., range connectors).
+    pub const fn cron_field_name(self) -> Option<&'static str> {
+        match self {
+            Self::FrequencyWith | Self::LastDay => Some("day_of_month"),
+            Self::DayPeriod | Self::ClockTime | Self::Hour => Some("hour"),
+            Self::SubHour | Self::PastHour | Self::Minute => Some("minutes"),
+            Self::Day | Self::OnlyOn => Some("day_of_week"),
+            Self::Secund => Some("seconds"),
+            Self::Month | Self::Quarter => Some("month"),
+            Self::Year => Some("year"),
+            Self::FrequencyOnly
+            | Self::FrequencyTimes
+            | Self::Scope
+            | Self::RangeStart
+            | Self::RangeEnd
+            | Self::Except => None,
+        }
+    }
+
     /// Processes the token based on the kind of token.
     /// Each variant has its own processing logic defined in the respective module.
     /// Returns a `Result<()>` indicating success or failure of the operation.
     pub fn process(self, token: &str, cron: &mut Cron) -> Result<()> {
+        except::check_excludable(self, token, cron)?;
         match self {
             Self::FrequencyWith => frequency_with::process(token, cron)?,
-            Self::FrequencyOnly => {
-                let frequency = token.parse::<i32>().map_err(|_| Error::ParseToNumber {
-                    state: "frequency_only".to_string(),
-                    value: token.to_string(),
-                })?;
-
-                frequency_only::process(frequency, cron);
-            }
+            Self::FrequencyOnly => frequency_only::process(token, cron)?,
+            Self::FrequencyTimes => frequency_times::process(token, cron)?,
+            Self::LastDay => last_day::process(token, cron)?,
+            Self::DayPeriod => day_period::process(token, cron)?,
             Self::ClockTime => clock_time::process(token, cron)?,
+            Self::Scope => scope::process(token, cron)?,
+            Self::SubHour => sub_hour::process(token, cron)?,
+            Self::PastHour => past_hour::process(token, cron)?,
             Self::Day => day::process(token, cron)?,
-            Self::Secund => seconds::process(token, cron),
-            Self::Minute => minute::process(token, cron),
-            Self::Hour => hour::process(token, cron),
+            Self::Secund => seconds::process(token, cron)?,
+            Self::Minute => minute::process(token, cron)?,
+            Self::Hour => hour::process(token, cron)?,
             Self::Month => month::process(token, cron)?,
+            Self::Quarter => quarter::process(token, cron)?,
             Self::Year => year::process(token, cron)?,
             Self::RangeStart => range_start::process(token, cron),
             Self::RangeEnd => range_end::process(token, cron),
@@ -114,6 +207,7 @@ impl Kind {
                 // When "only on" is encountered, we don't need to do anything special
                 // The next token should be a day, which will be handled correctly
             }
+            Self::Except => except::process(cron),
         }
 
         Ok(())
@@ -3,20 +3,23 @@
 //! This module provides functionality to interpret and process month-related tokens
 //! in the context of cron scheduling. It defines regular expressions for matching
 //! various month formats and provides functions for token validation and processing.
+//!
+//! Seasons stand for the three months they span in the northern hemisphere, so "summer"
+//! is June through August and "winter" is December through February.
 
+use super::super::re::{Captures, Regex};
 use super::super::{
     action::Kind,
-    cron::Cron,
+    cron::{Cron, FieldPosition},
     stack::{Stack, StartEndString},
-    Error, Result,
+    Error, MonthFormat, Result,
 };
-use regex::Regex;
-use std::fmt::Write;
+use super::{day, except};
 use std::sync::LazyLock;
 
 /// Regular expression to match valid month input in various formats (e.g., "January", "JAN").
 static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"(?i)^((months|month)|(((january|february|march|april|may|june|july|august|september|october|november|december|JAN|FEB|MAR|APR|MAY|JUN|JUL|AUG|SEPT|OCT|NOV|DEC)( ?and)?,? ?)+))$").unwrap()
+    Regex::new(r"(?i)^((months|month)|((((summer|winter|spring|fall|autumn)( months?)?|january|february|march|april|may|june|july|august|september|october|november|december|JAN|FEB|MAR|APR|MAY|JUN|JUL|AUG|SEPT|OCT|NOV|DEC)( ?and)?,? ?)+))$").unwrap()
 });
 
 /// Regular expression to match the word "month" or "months".
@@ -26,6 +29,10 @@ static RE_MONTH: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^(month|mon
 static RE_MONTHS_ABBREVIATION: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"(?i)(JAN|FEB|MAR|APR|MAY|JUN|JUL|AUG|SEP|OCT|NOV|DEC)").unwrap());
 
+/// Regular expression to find seasons in the input string.
+static RE_SEASON: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)summer|winter|spring|fall|autumn").unwrap());
+
 const MONTHS: [&str; 12] = [
     "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
 ];
@@ -35,6 +42,78 @@ pub fn try_from_token(str: &str) -> bool {
     RE_MATCH.is_match(str)
 }
 
+/// Writes the months in a month field in the given format (`JAN-MAR` becomes `1-3` as
+/// numbers). A range that runs over the end of the year is written as a list (`OCT-FEB`
+/// becomes `10,11,12,1,2`).
+pub fn format_months(month: &mut String, format: MonthFormat) {
+    if format == MonthFormat::Numeric {
+        *month = month
+            .split(',')
+            .map(number_months)
+            .collect::<Vec<_>>()
+            .join(",");
+    }
+}
+
+/// Numbers the months of one item of a month field.
+fn number_months(item: &str) -> String {
+    let position = |name: &str| {
+        MONTHS
+            .iter()
+            .position(|month| month.eq_ignore_ascii_case(name))
+    };
+    if let Some((Some(start), Some(end))) = item
+        .split_once('-')
+        .map(|(start, end)| (position(start), position(end)))
+    {
+        if start > end {
+            return (start..=end + MONTHS.len())
+                .map(|index| (index % MONTHS.len() + 1).to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+        }
+    }
+    RE_MONTHS_ABBREVIATION
+        .replace_all(item, |captures: &Captures<'_>| {
+            position(&captures[0])
+                .map_or_else(|| captures[0].to_string(), |index| (index + 1).to_string())
+        })
+        .into_owned()
+}
+
+/// Returns the indices in [`MONTHS`] of a month or a range of months (`OCT-FEB`).
+fn month_indices(item: &str) -> Vec<usize> {
+    let position = |name: &str| MONTHS.iter().position(|month| *month == name);
+    match item
+        .split_once('-')
+        .map(|(start, end)| (position(start), position(end)))
+    {
+        Some((Some(start), Some(end))) if start <= end => (start..=end).collect(),
+        Some((Some(start), Some(end))) => (start..=end + MONTHS.len())
+            .map(|index| index % MONTHS.len())
+            .collect(),
+        _ => position(item).into_iter().collect(),
+    }
+}
+
+/// Returns the months of a season in the northern hemisphere.
+fn season_months(season: &str) -> &'static str {
+    match season.to_lowercase().as_str() {
+        "spring" => "MAR APR MAY",
+        "summer" => "JUN JUL AUG",
+        "winter" => "DEC JAN FEB",
+        _ => "SEP OCT NOV",
+    }
+}
+
+/// Returns whether a number before "month" repeats the schedule ("every 2 months",
+/// "every other month", "once a month") rather than naming a month ("on 9 month").
+fn is_repeated(token: &str, element: &Stack) -> bool {
+    token.to_lowercase().ends_with('s')
+        || element.qualifier.as_deref() == Some("other")
+        || element.frequency == Some(1)
+}
+
 /// Processes the given month token and updates the cron structure accordingly.
 ///
 /// This function interprets the month token and modifies the `cron` object to
@@ -43,111 +122,201 @@ pub fn try_from_token(str: &str) -> bool {
 ///
 /// # Returns
 /// A [`Result<()>`] indicating success or failure. In case of an incorrect month format,
-/// an `Error::IncorrectValue` is returned.
+/// or a numbered month outside 1-12, an `Error::IncorrectValue` is returned.
 ///
 pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
-    if RE_MONTH.is_match(token) {
-        if let Some(element) = cron.stack.last() {
-            if element.owner == Kind::FrequencyOnly {
-                // cron.syntax.month = format!("0/{}", element.frequency_to_string());
-                cron.syntax.month = element.frequency_to_string();
-                cron.stack.pop();
-            } else if element.owner == Kind::FrequencyWith {
-                cron.syntax.month = element.frequency_to_string();
-                cron.stack.pop();
-            } else if element.owner == Kind::RangeEnd {
-                cron.syntax.day_of_month = format!(
-                    "{},{}",
-                    element.frequency_start.unwrap_or_default(),
-                    element.frequency_end.unwrap_or_default()
-                );
-            } else {
-                cron.syntax.month = "*".to_string();
-            }
-        } else {
-            cron.syntax.month = "*".to_string();
+    // "except July and August" runs in every other month
+    let is_except = except::take(cron);
+    let closes_except = is_except
+        .is_none()
+        .then(|| except::take_before_range(cron))
+        .flatten();
+    process_months(token, cron)?;
+    if let Some(marker) = is_except.as_ref().or(closes_except.as_ref()) {
+        if cron.syntax.is_explicit(FieldPosition::Month) {
+            except::complement(cron, FieldPosition::Month, marker)?;
         }
+    }
+    if let Some(marker) = is_except {
+        except::keep_for_range(cron, marker);
+    }
+    Ok(())
+}
+
+/// Processes a month token without regard to a preceding exclusion.
+fn process_months(token: &str, cron: &mut Cron) -> Result<()> {
+    if RE_MONTH.is_match(token) {
+        process_month_keyword(token, cron)?;
+        push_months(cron);
+        Ok(())
     } else {
-        let matches: Vec<_> = RE_MONTHS_ABBREVIATION.find_iter(token).collect();
-        if matches.is_empty() {
+        process_named_months(token, cron)
+    }
+}
+
+/// Marks the months that were just set as explicit and pushes them, for a following
+/// range to continue.
+fn push_months(cron: &mut Cron) {
+    cron.syntax.mark_explicit(FieldPosition::Month);
+
+    cron.stack.push(
+        Stack::builder(Kind::Month)
+            .month(StartEndString {
+                start: Some(cron.syntax.month.clone()),
+                end: None,
+            })
+            .build(),
+    );
+}
+
+/// Processes a "month" token, which repeats every month or every few months, or after
+/// a number names a month.
+fn process_month_keyword(token: &str, cron: &mut Cron) -> Result<()> {
+    if let Some(element) = cron.stack.last() {
+        if element.owner == Kind::FrequencyOnly && is_repeated(token, element) {
+            // "every 2 months" runs at midnight on the first of every second month
+            cron.syntax.month = format!("*/{}", element.frequency_to_string());
+            if !cron.syntax.is_explicit(FieldPosition::DayOfMonth) {
+                cron.syntax.day_of_month = "1".to_string();
+            }
+            if cron.syntax.min == "*" {
+                cron.syntax.min = "0".to_string();
+            }
+            if cron.syntax.hour == "*" {
+                cron.syntax.hour = "0".to_string();
+            }
+            cron.stack.pop();
+        } else if element.owner == Kind::FrequencyOnly || element.owner == Kind::FrequencyWith {
+            if let Some(month) = element.frequency.filter(|month| !(1..=12).contains(month)) {
+                return Err(Error::IncorrectValue {
+                    state: "month".to_string(),
+                    error: format!("month {month} should be between 1 and 12"),
+                });
+            }
+            cron.syntax.month = element.frequency_to_string();
+            cron.stack.pop();
+        } else if element.owner == Kind::RangeEnd
+            && element.frequency_start.is_some()
+            && element.frequency_end.is_some()
+        {
+            cron.syntax.day_of_month = day::day_of_month_range(element)?;
+            cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+        } else if let Some(end) = element
+            .frequency_end
+            .filter(|_| element.owner == Kind::RangeEnd)
+        {
+            // "to 9 month" has nothing to start the range from
             return Err(Error::IncorrectValue {
                 state: "month".to_string(),
-                error: format!("value {token} is not a month format"),
+                error: format!("a range of months ending in month {end} has no start"),
             });
+        } else {
+            cron.syntax.month = "*".to_string();
         }
+    } else {
+        cron.syntax.month = "*".to_string();
+    }
+    Ok(())
+}
 
-        cron.syntax.month = String::new();
+/// Processes a token naming months or seasons, which may start, end or continue a range
+/// or list.
+fn process_named_months(token: &str, cron: &mut Cron) -> Result<()> {
+    let token = RE_SEASON.replace_all(token, |captures: &Captures<'_>| season_months(&captures[0]));
+    let matches: Vec<_> = RE_MONTHS_ABBREVIATION.find_iter(&token).collect();
+    if matches.is_empty() {
+        return Err(Error::IncorrectValue {
+            state: "month".to_string(),
+            error: format!("value {token} is not a month format"),
+        });
+    }
 
-        let months: Vec<String> = matches
-            .iter()
-            .map(|month| month.as_str().to_uppercase())
-            .collect::<Vec<_>>();
+    let mut months: Vec<String> = matches
+        .iter()
+        .map(|month| month.as_str().to_uppercase())
+        .collect::<Vec<_>>();
 
-        if let Some(element) = cron.stack.last_mut() {
-            if element.owner == Kind::FrequencyOnly || element.owner == Kind::FrequencyWith {
-                cron.syntax.day_of_month = element.frequency_to_string();
-                cron.stack.pop();
-            } else if element.owner == Kind::RangeStart {
-                element.month = Some(element.month.as_ref().map_or_else(
-                    || StartEndString {
-                        start: months.first().cloned(),
-                        end: None,
-                    },
-                    |month| StartEndString {
-                        start: months.first().cloned(),
-                        end: month.end.clone(),
-                    },
-                ));
+    if let Some(element) = cron.stack.last_mut() {
+        if element.owner == Kind::FrequencyOnly || element.owner == Kind::FrequencyWith {
+            cron.syntax.day_of_month = day::validate_day_of_month(element.frequency)?;
+            cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+            // A date ("the 15th of June") runs at midnight unless a time is given
+            if !day::is_time_set(cron) {
+                day::set_default_time(cron);
+            }
+            cron.stack.pop();
+        } else if element.owner == Kind::RangeStart {
+            if let (true, [start, end]) = (element.is_between_range, months.as_slice()) {
+                // "between January and March" arrives as a single list token
+                cron.syntax.month = format!("{start}-{end}");
+                cron.syntax.mark_explicit(FieldPosition::Month);
                 cron.stack.pop();
                 return Ok(());
-            } else if element.owner == Kind::RangeEnd {
-                if let Some(frequency_end) = element.frequency_end {
+            }
+            element.month = Some(element.month.as_ref().map_or_else(
+                || StartEndString {
+                    start: months.first().cloned(),
+                    end: None,
+                },
+                |month| StartEndString {
+                    start: months.first().cloned(),
+                    end: month.end.clone(),
+                },
+            ));
+            cron.stack.pop();
+            return Ok(());
+        } else if element.owner == Kind::RangeEnd {
+            if element.frequency_end.is_some() {
+                if !day::is_specific_day(&cron.syntax.day_of_week) {
                     cron.syntax.day_of_week = "?".to_string();
-                    if let Some(frequency_start) = element.frequency_start {
-                        cron.syntax.day_of_month = format!("{frequency_start}-{frequency_end}");
-                    }
                 }
-
-                let data = element.month.as_ref().map_or_else(
-                    || StartEndString {
-                        start: None,
-                        end: months.first().cloned(),
-                    },
-                    |month| StartEndString {
-                        start: month.start.clone(),
-                        end: months.first().cloned(),
-                    },
-                );
-                element.month = Some(data.clone());
-
-                if let (Some(start), Some(end)) = (data.start, data.end) {
-                    cron.syntax.month = format!("{start}-{end}");
+                if element.frequency_start.is_some() {
+                    cron.syntax.day_of_month = day::day_of_month_range(element)?;
+                    cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
                 }
-
-                cron.stack.pop();
-                return Ok(());
-            } else {
-                cron.stack.pop();
             }
-        }
 
-        for &month in &MONTHS {
-            if months.contains(&month.to_string()) && !cron.syntax.month.contains(month) {
-                write!(cron.syntax.month, "{month},").unwrap();
+            // "every month" leaves no month to start a range from, so the months given
+            // are listed on their own
+            let start = element
+                .month
+                .as_ref()
+                .and_then(|month| month.start.clone())
+                .filter(|start| day::is_specific_day(start));
+            let separator = element.range_separator();
+            cron.stack.pop();
+            if let (Some(start), Some(end)) = (start, months.first()) {
+                if separator == '-' {
+                    // "from January to March and May" lists the months after the range
+                    cron.syntax.month = std::iter::once(format!("{start}{separator}{end}"))
+                        .chain(months[1..].iter().cloned())
+                        .collect::<Vec<_>>()
+                        .join(",");
+                    cron.syntax.mark_explicit(FieldPosition::Month);
+                    return Ok(());
+                }
+                // "March, May, and June" continues the list rather than a range
+                months.splice(0..0, start.split(',').map(str::to_string));
             }
+        } else {
+            cron.stack.pop();
         }
-
-        cron.syntax.month = cron.syntax.month.trim_end_matches(',').to_string();
     }
 
-    cron.stack.push(
-        Stack::builder(Kind::Month)
-            .month(StartEndString {
-                start: Some(cron.syntax.month.clone()),
-                end: None,
-            })
-            .build(),
-    );
+    // Months are listed in calendar order, each once ("December and January" is
+    // `JAN,DEC`)
+    let mut indices: Vec<usize> = months
+        .iter()
+        .flat_map(|month| month_indices(month))
+        .collect();
+    indices.sort_unstable();
+    indices.dedup();
+    cron.syntax.month = indices
+        .into_iter()
+        .map(|index| MONTHS[index])
+        .collect::<Vec<_>>()
+        .join(",");
+    push_months(cron);
 
     Ok(())
 }
@@ -0,0 +1,70 @@
+//! Module for processing minutes past the hour in cron expressions.
+//!
+//! Phrases such as "15 minutes past the hour", "half past every hour" or "quarter past
+//! each hour" fix the minute field to a single value and leave the hour to the rest of the
+//! input. "On the hour" and "at the top of the hour" fix it to minute 0.
+
+use super::super::re::Regex;
+use super::super::{
+    cron::{Cron, FieldPosition},
+    Error, Result,
+};
+use std::sync::LazyLock;
+
+/// Regular expression to match a number of minutes past the hour
+/// (e.g., "15 past the hour", "10 minutes past every hour", "half past each hour").
+static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^([0-9]+|half|quarter)( minutes?)? past (the|every|each) hour$").unwrap()
+});
+
+/// Regular expression to match the start of the hour ("on the hour", "at the top of the
+/// hour").
+static RE_TOP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(on|(at )?(the )?top of) (the|every|each) hour$").unwrap());
+
+/// Checks if the provided token names a number of minutes past the hour.
+pub fn try_from_token(str: &str) -> bool {
+    RE_MATCH.is_match(str) || RE_TOP.is_match(str)
+}
+
+/// Processes a minutes-past-the-hour token and sets the minute field to that value.
+///
+/// "half" stands for 30 minutes and "quarter" for 15, while the top of the hour also
+/// fixes the seconds to 0 unless they are given. The hour is left as it is, so "every 2
+/// hours at 15 past the hour" keeps its step.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if the number of minutes is not between 0 and 59.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    if RE_TOP.is_match(token) {
+        // "every 10 seconds on the hour" keeps its seconds
+        if !cron.syntax.is_explicit(FieldPosition::Seconds) {
+            cron.syntax.seconds = "0".to_string();
+        }
+        cron.syntax.min = "0".to_string();
+        cron.syntax.mark_explicit(FieldPosition::Minutes);
+        cron.syntax.clear_default(FieldPosition::Hour);
+        return Ok(());
+    }
+
+    let minutes = RE_MATCH
+        .captures(token)
+        .map(|captures| captures[1].to_lowercase());
+    let minute = match minutes.as_deref() {
+        Some("half") => Some(30),
+        Some("quarter") => Some(15),
+        Some(minutes) => minutes.parse::<u32>().ok().filter(|minute| *minute <= 59),
+        None => None,
+    }
+    .ok_or_else(|| Error::IncorrectValue {
+        state: "past_hour".to_string(),
+        error: format!("minute in {token} should be between 0 and 59"),
+    })?;
+
+    cron.syntax.min = minute.to_string();
+    cron.syntax.mark_explicit(FieldPosition::Minutes);
+    cron.syntax.clear_default(FieldPosition::Hour);
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+//! Module for processing business-quarter tokens in cron expressions.
+//!
+//! This module maps the shorthands "Q1" through "Q4" to the three-month range
+//! covered by each quarter of a calendar year.
+
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    stack::{Stack, StartEndString},
+    Error, Result,
+};
+use super::day;
+use std::sync::LazyLock;
+
+/// Regular expression to match a quarter shorthand (e.g., "Q1", "q4").
+static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^q[1-4]$").unwrap());
+
+/// The first and last month of each quarter, in calendar order.
+const QUARTERS: [(&str, &str); 4] = [
+    ("JAN", "MAR"),
+    ("APR", "JUN"),
+    ("JUL", "SEP"),
+    ("OCT", "DEC"),
+];
+
+/// Checks if the provided token is a quarter shorthand.
+pub fn try_from_token(str: &str) -> bool {
+    RE_MATCH.is_match(str)
+}
+
+/// Processes a quarter token and sets the month field to the quarter's month range.
+///
+/// A pending ordinal or number on the stack (e.g., "on the 15th in Q2") is applied
+/// to the day-of-month field, mirroring how explicit month names are handled.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if the token is not a valid quarter.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    let (start, end) = token
+        .get(1..)
+        .and_then(|quarter| quarter.parse::<usize>().ok())
+        .and_then(|quarter| QUARTERS.get(quarter.checked_sub(1)?))
+        .ok_or_else(|| Error::IncorrectValue {
+            state: "quarter".to_string(),
+            error: format!("value {token} is not a quarter format"),
+        })?;
+
+    if let Some(element) = cron.stack.last() {
+        if element.owner == Kind::FrequencyOnly || element.owner == Kind::FrequencyWith {
+            cron.syntax.day_of_month = day::validate_day_of_month(element.frequency)?;
+            cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+            cron.stack.pop();
+        }
+    }
+
+    cron.syntax.month = format!("{start}-{end}");
+    cron.syntax.mark_explicit(FieldPosition::Month);
+
+    cron.stack.push(
+        Stack::builder(Kind::Month)
+            .month(StartEndString {
+                start: Some((*start).to_string()),
+                end: Some((*end).to_string()),
+            })
+            .build(),
+    );
+
+    Ok(())
+}
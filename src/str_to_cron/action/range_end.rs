@@ -1,9 +1,15 @@
-/// Module for processing range-related tokens in cron expressions.
-///
-/// This module handles the interpretation of tokens that represent ranges or connections
-/// between elements in cron scheduling, such as "to", "through", "ending", and "and".
-use super::super::{action::Kind, cron::Cron, stack::StartEndString};
-use regex::Regex;
+//! Module for processing range-related tokens in cron expressions.
+//!
+//! This module handles the interpretation of tokens that represent ranges or connections
+//! between elements in cron scheduling, such as "to", "through", "ending", and "and".
+
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::Cron,
+    stack::{Stack, StartEndString},
+};
+use super::day;
 use std::sync::LazyLock;
 
 /// Regular expression to match range-related keywords (e.g., "to", "through").
@@ -13,6 +19,12 @@ static RE_MATCH: LazyLock<Regex> =
 /// Regular expression to specifically match "and".
 static RE_MATCH_AND: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)(and)").unwrap());
 
+/// Regular expression to match "ending" or "end", which can close a range on its own.
+static RE_MATCH_ENDING: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^end(ing)?$").unwrap());
+
+/// Marks a range closed by "ending" without a matching "starting" or "between".
+const ENDING: &str = "ending";
+
 /// Checks if the provided token matches range-related keywords.
 pub fn try_from_token(str: &str) -> bool {
     RE_MATCH.is_match(str)
@@ -20,6 +32,21 @@ pub fn try_from_token(str: &str) -> bool {
 
 /// Processes the cron object to interpret range-related tokens.
 pub fn process(token: &str, cron: &mut Cron) {
+    // "ending at 5pm" with no open range bounds the schedule on its own
+    if RE_MATCH_ENDING.is_match(token)
+        && cron
+            .stack
+            .last()
+            .is_none_or(|element| element.owner != Kind::RangeStart)
+    {
+        cron.stack.push(
+            Stack::builder(Kind::RangeEnd)
+                .qualifier(ENDING.to_string())
+                .build(),
+        );
+        return;
+    }
+
     // Check if the token is "and" specifically
     let is_and = RE_MATCH_AND.is_match(token);
 
@@ -33,15 +60,17 @@ pub fn process(token: &str, cron: &mut Cron) {
             }
 
             Kind::Day => {
+                // "every day" leaves no day of the week to start a range from
+                let start = element
+                    .day_of_week
+                    .clone()
+                    .filter(|day_of_week| day::is_specific_day(day_of_week));
                 element.day = match &element.day {
                     Some(day) => Some(StartEndString {
-                        start: element.day_of_week.clone(),
+                        start,
                         end: day.end.clone(),
                     }),
-                    None => Some(StartEndString {
-                        start: element.day_of_week.clone(),
-                        end: None,
-                    }),
+                    None => Some(StartEndString { start, end: None }),
                 };
             }
 
@@ -51,13 +80,28 @@ pub fn process(token: &str, cron: &mut Cron) {
 
             Kind::RangeStart => element.owner = Kind::RangeEnd,
             Kind::Year
+            | Kind::FrequencyTimes
             | Kind::ClockTime
+            | Kind::DayPeriod
             | Kind::Minute
             | Kind::Hour
             | Kind::RangeEnd
             | Kind::Secund
-            | Kind::OnlyOn => {}
+            | Kind::Scope
+            | Kind::SubHour
+            | Kind::PastHour
+            | Kind::Quarter
+            | Kind::LastDay
+            | Kind::OnlyOn
+            | Kind::Except => {}
         }
         element.owner = Kind::RangeEnd;
     }
 }
+
+/// Checks if the last element on the stack was opened by "ending" on its own.
+pub fn is_ending(cron: &Cron) -> bool {
+    cron.stack.last().is_some_and(|element| {
+        element.owner == Kind::RangeEnd && element.qualifier.as_deref() == Some(ENDING)
+    })
+}
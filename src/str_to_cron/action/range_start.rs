@@ -1,7 +1,11 @@
 //! Module for processing range start-related tokens in cron expressions.
 
-use super::super::{action::Kind, cron::Cron, stack::Stack};
-use regex::Regex;
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    stack::Stack,
+};
 use std::sync::LazyLock;
 
 /// Regular expression to match keywords indicating the start of a range (e.g., "between", "starting").
@@ -25,3 +29,25 @@ pub fn process(token: &str, cron: &mut Cron) {
 
     cron.stack.push(stack);
 }
+
+/// Checks if the last element on the stack was opened by "starting" rather than "between".
+pub fn is_starting(cron: &Cron) -> bool {
+    cron.stack
+        .last()
+        .is_some_and(|element| element.owner == Kind::RangeStart && !element.is_between_range)
+}
+
+/// Anchors a stepped field on the value given after "starting" (e.g., "every 6 hours
+/// starting at 1 pm" turns `0/6` into `13/6`).
+///
+/// Returns `true` if the field held a step and was rewritten, or `false` if the field is
+/// not stepped and the offset does not apply to it.
+pub fn apply_step_offset(cron: &mut Cron, field: FieldPosition, offset: i32) -> bool {
+    let value = cron.syntax.field_mut(field);
+    let Some((_, step)) = value.split_once('/') else {
+        return false;
+    };
+    *value = format!("{offset}/{step}");
+    cron.syntax.mark_explicit(field);
+    true
+}
@@ -0,0 +1,58 @@
+//! Module for processing scoping suffixes in cron expressions.
+//!
+//! Phrases often end with a suffix such as "of each hour", "of every day" or
+//! "of the month". These suffixes only confirm the scope of the schedule that
+//! was already described, so they must never overwrite fields that were set by
+//! earlier tokens.
+
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    Result,
+};
+use super::{day, month};
+use std::sync::LazyLock;
+
+/// Regular expression to match a scoping suffix (e.g., "of each hour", "of every month").
+static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^of (each|every|the) (hours?|days?|weeks?|months?)$").unwrap()
+});
+
+/// Regular expression to match a month scope.
+static RE_MONTH_SCOPE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)months?$").unwrap());
+
+/// Checks if the provided token is a scoping suffix.
+pub fn try_from_token(str: &str) -> bool {
+    RE_MATCH.is_match(str)
+}
+
+/// Processes a scoping suffix token.
+///
+/// Hour, day and week scopes are pure confirmations and leave the cron untouched.
+/// A month scope resolves a pending ordinal (e.g., "the 3rd of each month") into
+/// the day-of-month field, since the ordinal has no other field to land in.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    if !RE_MONTH_SCOPE.is_match(token) {
+        return Ok(());
+    }
+
+    if let Some(element) = cron.stack.last() {
+        if element.owner == Kind::FrequencyWith {
+            cron.syntax.day_of_month = day::validate_day_of_month(element.frequency)?;
+            cron.syntax.mark_explicit(FieldPosition::DayOfMonth);
+            day::clear_day_of_week(cron);
+            if cron.syntax.min == "*" {
+                cron.syntax.min = "0".to_string();
+            }
+            if cron.syntax.hour == "*" {
+                cron.syntax.hour = "0".to_string();
+            }
+            cron.stack.pop();
+        } else if element.owner == Kind::RangeEnd {
+            month::process("month", cron)?;
+        }
+    }
+
+    Ok(())
+}
@@ -4,8 +4,13 @@
 //! "second", "seconds", "sec", and "secs". It updates the `Cron` object with
 //! the appropriate values based on the input token.
 
-use super::super::{action::Kind, cron::Cron, stack::Stack};
-use regex::Regex;
+use super::super::re::Regex;
+use super::super::{
+    action::Kind,
+    cron::{Cron, FieldPosition},
+    stack::Stack,
+    Error, Result,
+};
 use std::sync::LazyLock;
 
 /// Regular expression to match any form of the word "second".
@@ -26,20 +31,50 @@ pub fn try_from_token(str: &str) -> bool {
 /// This function interprets second-related tokens, updating the `cron` object's
 /// syntax seconds based on the provided token. It handles both exact keyword matches
 /// and updates the cron stack appropriately.
-pub fn process(token: &str, cron: &mut Cron) {
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if a step is outside 1-59 or a literal second
+/// is outside 0-59.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
     if RE_SECUND.is_match(token) {
-        if let Some(element) = cron.stack.last_mut() {
-            if element.owner == Kind::FrequencyOnly {
+        match cron.stack.last() {
+            Some(element) if element.owner == Kind::FrequencyOnly => {
+                // Steps of a minute or more carry into the minutes once the input is read
+                validate(element.frequency, 1, i32::MAX)?;
                 cron.syntax.seconds = format!("0/{}", element.frequency_to_string());
                 cron.stack.pop();
-            } else if element.owner == Kind::FrequencyWith {
+            }
+            Some(element) if element.owner == Kind::FrequencyWith => {
+                validate(element.frequency, 0, 59)?;
                 cron.syntax.seconds = element.frequency_to_string();
                 cron.stack.pop();
             }
-        } else {
-            cron.syntax.seconds = "*".to_string();
+            // "starting at second 3" names the unit of the offset that follows
+            Some(element) if element.owner == Kind::RangeStart => return Ok(()),
+            _ => cron.syntax.seconds = "*".to_string(),
         }
+        cron.syntax.mark_explicit(FieldPosition::Seconds);
+        cron.syntax.clear_default(FieldPosition::Minutes);
+        cron.syntax.clear_default(FieldPosition::Hour);
 
         cron.stack.push(Stack::builder(Kind::Secund).build());
     }
+
+    Ok(())
+}
+
+/// Validates that a seconds value lies between `min` and `max`.
+fn validate(seconds: Option<i32>, min: i32, max: i32) -> Result<()> {
+    match seconds {
+        Some(value) if !(min..=max).contains(&value) => Err(Error::IncorrectValue {
+            state: "seconds".to_string(),
+            error: if max == i32::MAX {
+                format!("second {value} should be at least {min}")
+            } else {
+                format!("second {value} should be between {min} and {max}")
+            },
+        }),
+        _ => Ok(()),
+    }
 }
@@ -0,0 +1,82 @@
+//! Module for processing sub-hour and sub-minute window tokens in cron expressions.
+//!
+//! Phrases such as "the first half of the hour", "the last 15 minutes of the hour" or
+//! "the first half of the minute" restrict the minute (or seconds) field to a range
+//! instead of the whole hour (or minute).
+
+use super::super::re::Regex;
+use super::super::{
+    cron::{Cron, FieldPosition},
+    Error, Result,
+};
+use std::sync::LazyLock;
+
+/// Regular expression to match a window within the hour or minute
+/// (e.g., "first half of the hour", "last 30 minutes of the hour", "first 10 seconds").
+static RE_MATCH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^((first|second) half of the (hour|minute)|(first|last) [0-9]+ (minutes?|seconds?)( of the (hour|minute))?)$")
+        .unwrap()
+});
+
+/// Regular expression to capture the size of a "first N"/"last N" window.
+static RE_NUMERIC: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[0-9]+").unwrap());
+
+/// Regular expression to match a window over seconds rather than minutes.
+static RE_SECONDS_WINDOW: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)([0-9]+ seconds?|of the minute)").unwrap());
+
+/// Checks if the provided token describes a window within the hour or minute.
+pub fn try_from_token(str: &str) -> bool {
+    RE_MATCH.is_match(str)
+}
+
+/// Processes a window token and restricts the minute or seconds field to the window.
+///
+/// Windows over minutes ("of the hour", "N minutes") apply to the minute field and windows
+/// over seconds ("of the minute", "N seconds") apply to the seconds field. A step already
+/// present in the field is kept, so "every 5 minutes in the first half of the hour"
+/// becomes `0-29/5`.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if the window size is not between 1 and 60.
+pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
+    let lowercase = token.to_lowercase();
+    let field = if RE_SECONDS_WINDOW.is_match(token) {
+        FieldPosition::Seconds
+    } else {
+        FieldPosition::Minutes
+    };
+
+    let (start, end) = if lowercase.contains("half") {
+        if lowercase.starts_with("first") {
+            (0, 29)
+        } else {
+            (30, 59)
+        }
+    } else {
+        let size = RE_NUMERIC
+            .find(&lowercase)
+            .and_then(|size| size.as_str().parse::<i32>().ok())
+            .filter(|size| (1..=60).contains(size))
+            .ok_or_else(|| Error::IncorrectValue {
+                state: "sub_hour".to_string(),
+                error: format!("window in {token} should be between 1 and 60"),
+            })?;
+        if lowercase.starts_with("first") {
+            (0, size - 1)
+        } else {
+            (60 - size, 59)
+        }
+    };
+
+    let value = cron.syntax.field_mut(field);
+    *value = match value.split_once('/') {
+        Some((_, step)) => format!("{start}-{end}/{step}"),
+        None => format!("{start}-{end}"),
+    };
+    cron.syntax.mark_explicit(field);
+    cron.syntax.clear_default(FieldPosition::Hour);
+
+    Ok(())
+}
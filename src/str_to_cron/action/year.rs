@@ -3,13 +3,14 @@
 //! This module handles the interpretation of tokens that specify years,
 //! including keywords like "year" or "years" and numeric year values.
 
+use super::super::re::Regex;
 use super::super::{
     action::Kind,
-    cron::Cron,
+    cron::{Cron, FieldPosition},
     stack::{Stack, StartEnd},
     Error, Result,
 };
-use regex::Regex;
+use super::range_start;
 use std::sync::LazyLock;
 
 /// Regular expression to match keywords related to years (e.g., "years", "year") and numeric values.
@@ -25,6 +26,12 @@ static RE_NUMERIC: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[0-9]+").unwr
 /// Regular expression to validate year format (four digits).
 static RE_YEAR_FORMAT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]{4}$").unwrap());
 
+/// The earliest year a Quartz expression can run in.
+const MIN_YEAR: i32 = 1970;
+
+/// The latest year a Quartz expression can run in.
+const MAX_YEAR: i32 = 2099;
+
 /// Checks if the provided token matches year-related keywords or formats.
 pub fn try_from_token(str: &str) -> bool {
     RE_MATCH.is_match(str)
@@ -34,84 +41,197 @@ pub fn try_from_token(str: &str) -> bool {
 ///
 /// This function interprets year-related tokens, updating the cron's syntax year
 /// based on the provided token. It handles both keyword matches and numeric year values.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if a year is outside 1970-2099.
 pub fn process(token: &str, cron: &mut Cron) -> Result<()> {
     if RE_YEARS.is_match(token) {
-        cron.syntax.year = "?".to_string();
-        if let Some(element) = cron.stack.last_mut() {
-            if element.owner == Kind::FrequencyOnly {
-                cron.syntax.year = format!("0/{}", element.frequency_to_string());
-                cron.stack.pop();
-            } else if element.owner == Kind::FrequencyWith {
-                cron.syntax.year = element.frequency_to_string();
-            } else {
-                cron.syntax.year = "*".to_string();
-            }
-        }
+        process_keyword(cron);
+        Ok(())
     } else {
-        let matches: Vec<_> = RE_NUMERIC.find_iter(token).collect();
+        process_values(token, cron)
+    }
+}
 
-        let years: Vec<i32> = matches
-            .iter()
-            .filter_map(|year| {
-                if RE_YEAR_FORMAT.is_match(year.as_str()) {
-                    if let Ok(year) = year.as_str().parse::<i32>() {
-                        return Some(year);
-                    }
+/// Processes the "year" keyword, which repeats every year or, after a frequency, every
+/// few years.
+fn process_keyword(cron: &mut Cron) {
+    let step = match cron.stack.last() {
+        // "every 3rd year" repeats like "every 3 years"
+        Some(element)
+            if element.owner == Kind::FrequencyOnly || element.owner == Kind::FrequencyWith =>
+        {
+            Some(element.frequency_to_string())
+        }
+        _ => None,
+    };
+    if step.is_some() {
+        cron.stack.pop();
+    }
+
+    // "every 2 years" runs at midnight on January 1st of every second year
+    cron.syntax.year = match step {
+        Some(step) if step != "1" => format!("*/{step}"),
+        _ => "*".to_string(),
+    };
+    for (field, value) in [
+        (FieldPosition::DayOfMonth, "1"),
+        (FieldPosition::Month, "1"),
+    ] {
+        if !cron.syntax.is_explicit(field) {
+            *cron.syntax.field_mut(field) = value.to_string();
+        }
+    }
+    if cron.syntax.min == "*" {
+        cron.syntax.min = "0".to_string();
+    }
+    if cron.syntax.hour == "*" {
+        cron.syntax.hour = "0".to_string();
+    }
+    cron.syntax.mark_explicit(FieldPosition::Year);
+    cron.stack.push(Stack::builder(Kind::Year).build());
+}
+
+/// Processes a list of numeric years, which may start or end a range.
+fn process_values(token: &str, cron: &mut Cron) -> Result<()> {
+    let matches: Vec<_> = RE_NUMERIC.find_iter(token).collect();
+
+    let years: Vec<i32> = matches
+        .iter()
+        .filter_map(|year| {
+            if RE_YEAR_FORMAT.is_match(year.as_str()) {
+                if let Ok(year) = year.as_str().parse::<i32>() {
+                    return Some(year);
                 }
-                None
-            })
-            .collect::<Vec<_>>();
-
-        if let Some(element) = cron.stack.last_mut() {
-            if element.owner == Kind::RangeStart {
-                element.year = Some(element.year.as_ref().map_or_else(
-                    || StartEnd {
-                        start: years.first().copied(),
-                        end: None,
-                    },
-                    |year| StartEnd {
-                        start: years.first().copied(),
-                        end: year.end,
-                    },
-                ));
+            }
+            None
+        })
+        .collect::<Vec<_>>();
+    for &year in &years {
+        validate(year)?;
+    }
 
-                return Ok(());
-            } else if element.owner == Kind::RangeEnd {
-                let year = element.year.as_ref().map_or_else(
-                    || StartEnd {
-                        start: None,
-                        end: years.first().copied(),
-                    },
-                    |year| StartEnd {
-                        start: year.start,
-                        end: years.first().copied(),
-                    },
-                );
-
-                cron.syntax.year = format!(
-                    "{}-{}",
-                    year.start.unwrap_or_default(),
-                    year.end.unwrap_or_default()
-                );
-                cron.stack.pop();
+    if let (true, Some(&year)) = (range_start::is_starting(cron), years.first()) {
+        // "every 2 years starting 2024" anchors the step on that year
+        if range_start::apply_step_offset(cron, FieldPosition::Year, year) {
+            cron.stack.pop();
+            return Ok(());
+        }
+    }
 
+    if let Some(element) = cron.stack.last_mut() {
+        if element.owner == Kind::RangeStart {
+            if let (true, &[start, end]) = (element.is_between_range, years.as_slice()) {
+                // "between 2020 and 2030" arrives as a single list token
+                cron.stack.pop();
+                set_range(cron, start, end);
                 return Ok(());
             }
+            element.year = Some(element.year.as_ref().map_or_else(
+                || StartEnd {
+                    start: years.first().copied(),
+                    end: None,
+                },
+                |year| StartEnd {
+                    start: years.first().copied(),
+                    end: year.end,
+                },
+            ));
+
+            return Ok(());
+        } else if element.owner == Kind::RangeEnd {
+            let year = element.year.as_ref().map_or_else(
+                || StartEnd {
+                    start: None,
+                    end: years.first().copied(),
+                },
+                |year| StartEnd {
+                    start: year.start,
+                    end: years.first().copied(),
+                },
+            );
+
+            cron.stack.pop();
+            match (year.start, year.end) {
+                (Some(start), Some(end)) => set_range(cron, start, end),
+                // A range with no start year ("every day through 2026") runs in its last year
+                (None, Some(end)) => {
+                    cron.syntax.year = end.to_string();
+                    cron.syntax.mark_explicit(FieldPosition::Year);
+                }
+                _ => {}
+            }
+
+            return Ok(());
         }
-        if years.is_empty() {
-            return Err(Error::IncorrectValue {
-                state: "year".to_string(),
-                error: format!("value {token} is not a year format"),
-            });
-        }
-        cron.syntax.year = String::new();
-        for year in years {
-            cron.syntax.year = format!("{}{},", cron.syntax.year, year);
-        }
-        cron.syntax.year = cron.syntax.year.trim_end_matches(',').to_string();
+    }
+    if years.is_empty() {
+        return Err(Error::IncorrectValue {
+            state: "year".to_string(),
+            error: format!("value {token} is not a year format"),
+        });
+    }
+    let single_year = match years.as_slice() {
+        [year] => Some(*year),
+        _ => None,
+    };
+    let step = step(cron);
+    cron.syntax.year = match (single_year, step) {
+        // "every 2 years from 2020" starts the step on that year
+        (Some(year), Some(step)) => format!("{year}/{step}"),
+        _ => years
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(","),
+    };
+    cron.syntax.mark_explicit(FieldPosition::Year);
+
+    if let Some(year) = single_year {
+        // Keep a lone year as the possible start of a range ("from 2018 to 2021")
+        cron.stack.push(
+            Stack::builder(Kind::Year)
+                .year(StartEnd {
+                    start: Some(year),
+                    end: None,
+                })
+                .build(),
+        );
+        return Ok(());
     }
 
     cron.stack.push(Stack::builder(Kind::Year).build());
 
     Ok(())
 }
+
+/// Checks that a year lies within the years a Quartz expression can run in.
+fn validate(year: i32) -> Result<()> {
+    if (MIN_YEAR..=MAX_YEAR).contains(&year) {
+        Ok(())
+    } else {
+        Err(Error::IncorrectValue {
+            state: "year".to_string(),
+            error: format!("year {year} should be between {MIN_YEAR} and {MAX_YEAR}"),
+        })
+    }
+}
+
+/// Returns the step of the year field, if a repetition ("every 2 years") set one.
+fn step(cron: &Cron) -> Option<String> {
+    cron.syntax
+        .year
+        .split_once('/')
+        .map(|(_, step)| step.to_string())
+}
+
+/// Sets the year field to a range, keeping a step given before the range ("every 2 years
+/// from 2020 to 2030" is `2020-2030/2`).
+fn set_range(cron: &mut Cron, start: i32, end: i32) {
+    cron.syntax.year = step(cron).map_or_else(
+        || format!("{start}-{end}"),
+        |step| format!("{start}-{end}/{step}"),
+    );
+    cron.syntax.mark_explicit(FieldPosition::Year);
+}
@@ -0,0 +1,205 @@
+//! This module provides [`CronBuilder`], a fluent API for building a [`Cron`] without
+//! parsing English.
+//!
+//! Fields that are not set keep the same defaults as a parsed schedule (seconds `0`,
+//! every minute, hour, day, month, and year). The result is checked on
+//! [`CronBuilder::build`], so out-of-range values are reported the same way as when
+//! parsing a cron expression with [`ScheduleSpec::from_cron`](super::ScheduleSpec::from_cron).
+
+use super::{
+    cron::{self, Cron, FieldPosition},
+    spec::ScheduleSpec,
+    Error, Result,
+};
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Returns the name used in cron expressions (e.g., `MON`).
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Monday => "MON",
+            Self::Tuesday => "TUE",
+            Self::Wednesday => "WED",
+            Self::Thursday => "THU",
+            Self::Friday => "FRI",
+            Self::Saturday => "SAT",
+            Self::Sunday => "SUN",
+        }
+    }
+}
+
+/// A month of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    /// Returns the name used in cron expressions (e.g., `JAN`).
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::January => "JAN",
+            Self::February => "FEB",
+            Self::March => "MAR",
+            Self::April => "APR",
+            Self::May => "MAY",
+            Self::June => "JUN",
+            Self::July => "JUL",
+            Self::August => "AUG",
+            Self::September => "SEP",
+            Self::October => "OCT",
+            Self::November => "NOV",
+            Self::December => "DEC",
+        }
+    }
+}
+
+/// Builds a [`Cron`] field by field.
+///
+/// # Examples
+///
+/// ```rust
+/// use english_to_cron::{CronBuilder, Month, Weekday};
+///
+/// let cron = CronBuilder::new().every_n_minutes(15).on_weekdays().build().unwrap();
+/// assert_eq!(cron.to_string(), "0 0/15 * ? * MON-FRI *");
+///
+/// let cron = CronBuilder::new()
+///     .at_time(9, 30)
+///     .on_days(&[Weekday::Monday, Weekday::Thursday])
+///     .in_months(&[Month::March])
+///     .build()
+///     .unwrap();
+/// assert_eq!(cron.to_string(), "0 30 9 ? MAR MON,THU *");
+/// ```
+#[derive(Debug, Default)]
+pub struct CronBuilder {
+    cron: Cron,
+}
+
+impl CronBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs every `n` seconds.
+    #[must_use]
+    pub fn every_n_seconds(self, n: u32) -> Self {
+        self.set(FieldPosition::Seconds, format!("0/{n}"))
+    }
+
+    /// Runs every `n` minutes, at the start of the minute.
+    #[must_use]
+    pub fn every_n_minutes(self, n: u32) -> Self {
+        self.set(FieldPosition::Minutes, format!("0/{n}"))
+    }
+
+    /// Runs every `n` hours, at the start of the hour.
+    #[must_use]
+    pub fn every_n_hours(self, n: u32) -> Self {
+        self.set(FieldPosition::Hour, format!("0/{n}"))
+            .set(FieldPosition::Minutes, "0".to_string())
+    }
+
+    /// Runs at the given time of day, on a 24-hour clock.
+    #[must_use]
+    pub fn at_time(self, hour: u8, minute: u8) -> Self {
+        self.set(FieldPosition::Hour, hour.to_string())
+            .set(FieldPosition::Minutes, minute.to_string())
+    }
+
+    /// Runs on the given days of the week.
+    #[must_use]
+    pub fn on_days(self, days: &[Weekday]) -> Self {
+        let days: Vec<_> = days.iter().map(|day| day.abbreviation()).collect();
+        self.set(FieldPosition::DayOfWeek, days.join(","))
+    }
+
+    /// Runs Monday through Friday.
+    #[must_use]
+    pub fn on_weekdays(self) -> Self {
+        self.set(FieldPosition::DayOfWeek, "MON-FRI".to_string())
+    }
+
+    /// Runs in the given months.
+    #[must_use]
+    pub fn in_months(self, months: &[Month]) -> Self {
+        let months: Vec<_> = months.iter().map(|month| month.abbreviation()).collect();
+        self.set(FieldPosition::Month, months.join(","))
+    }
+
+    /// Runs on the given day of the month.
+    #[must_use]
+    pub fn on_day_of_month(self, day: u8) -> Self {
+        self.set(FieldPosition::DayOfMonth, day.to_string())
+    }
+
+    /// Runs only in the given year.
+    #[must_use]
+    pub fn in_year(self, year: u32) -> Self {
+        self.set(FieldPosition::Year, year.to_string())
+    }
+
+    /// Returns the built [`Cron`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncorrectValue`] if a value is outside its field's range (e.g.,
+    /// hour 25 or a step of 0), or if both a day of the month and days of the week
+    /// were given, which Quartz does not allow. A step longer than its field is carried
+    /// into the next unit, as when parsing English (every 120 minutes runs every 2 hours);
+    /// one that is not a whole number of the next unit (every 90 minutes) returns
+    /// [`Error::UnsupportedSchedule`].
+    pub fn build(mut self) -> Result<Cron> {
+        let syntax = &mut self.cron.syntax;
+        cron::carry_intervals(syntax)?;
+        let has_day_of_month = syntax.is_explicit(FieldPosition::DayOfMonth);
+        let has_day_of_week = syntax.is_explicit(FieldPosition::DayOfWeek);
+        match (has_day_of_month, has_day_of_week) {
+            (true, true) => {
+                return Err(Error::IncorrectValue {
+                    state: "builder".to_string(),
+                    error: "set either a day of the month or days of the week, not both"
+                        .to_string(),
+                })
+            }
+            (true, false) => syntax.day_of_week = "?".to_string(),
+            (false, true) => syntax.day_of_month = "?".to_string(),
+            (false, false) => {}
+        }
+
+        ScheduleSpec::try_from(&*syntax)?;
+        Ok(self.cron)
+    }
+
+    fn set(mut self, field: FieldPosition, value: String) -> Self {
+        *self.cron.syntax.field_mut(field) = value;
+        self.cron.syntax.mark_explicit(field);
+        self
+    }
+}
@@ -1,23 +1,234 @@
+use super::re::Regex;
 use crate::str_to_cron::Tokenizer;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::LazyLock;
 
-use super::{action, stack::Stack, Error, Result};
+use super::{
+    action::{self, Kind},
+    spec,
+    stack::Stack,
+    DayOfWeekFormat, Error, Meridiem, MultiCron, Options, Result, ScheduleSpec, Warning,
+};
 
-#[derive(Default, Debug)]
+/// A parsed schedule.
+///
+/// With the `serde` feature, only the [`Syntax`] is serialized: the parser stack and
+/// warnings describe how the text was read and are left empty when deserializing.
+///
+/// [`Debug`] prints the fields one per line, the same as [`Cron::verbose`], followed by
+/// a line for each warning.
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cron {
     pub syntax: Syntax,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub stack: Vec<Stack>,
+    /// Non-fatal findings collected while parsing, such as approximated periods.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub warnings: Vec<Warning>,
 }
 
+/// The fields of a cron expression.
+///
+/// With the `serde` feature, fields are serialized under the names returned by
+/// [`Syntax::field_names`]. As with [`str::parse`], every field read back is
+/// treated as explicitly set.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Syntax {
     pub seconds: String,
+    #[cfg_attr(feature = "serde", serde(rename = "minutes"))]
     pub min: String,
     pub hour: String,
     pub day_of_month: String,
     pub day_of_week: String,
     pub month: String,
     pub year: String,
+    /// Whether each field, indexed by [`FieldPosition`], was set from the input
+    /// rather than left at or filled in with a default.
+    #[cfg_attr(feature = "serde", serde(skip, default = "all_explicit"))]
+    explicit: [bool; 7],
+    /// How the days of the week are numbered, so that they can be read back as Quartz
+    /// numbers them.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) day_of_week_format: DayOfWeekFormat,
+}
+
+/// Marks every field as explicitly set, for fields read back from a full expression.
+#[cfg(feature = "serde")]
+const fn all_explicit() -> [bool; 7] {
+    [true; 7]
+}
+
+/// The position of a field within a cron expression.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FieldPosition {
+    Seconds,
+    Minutes,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+    Year,
+}
+
+impl FieldPosition {
+    /// All field positions in the order they appear in a cron expression.
+    pub const ALL: [Self; 7] = [
+        Self::Seconds,
+        Self::Minutes,
+        Self::Hour,
+        Self::DayOfMonth,
+        Self::Month,
+        Self::DayOfWeek,
+        Self::Year,
+    ];
+}
+
+/// Maps a zero-indexed position in a cron expression to its field, so `0` is
+/// [`FieldPosition::Seconds`] and `6` is [`FieldPosition::Year`].
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if the position is greater than 6.
+impl TryFrom<u8> for FieldPosition {
+    type Error = Error;
+
+    fn try_from(position: u8) -> Result<Self> {
+        Self::ALL
+            .get(usize::from(position))
+            .copied()
+            .ok_or_else(|| Error::IncorrectValue {
+                state: "field_position".to_string(),
+                error: format!("position {position} should be between 0 and 6"),
+            })
+    }
+}
+
+impl Syntax {
+    /// Marks a field as explicitly set from the input.
+    pub const fn mark_explicit(&mut self, field: FieldPosition) {
+        self.explicit[field as usize] = true;
+    }
+
+    /// Returns whether a field was explicitly set from the input.
+    #[must_use]
+    pub const fn is_explicit(&self, field: FieldPosition) -> bool {
+        self.explicit[field as usize]
+    }
+
+    /// Resets a field to `*` unless it was explicitly set from the input.
+    ///
+    /// This undoes defaults filled in by coarser clauses (e.g., the midnight time
+    /// implied by "every day") once a finer-grained clause takes over.
+    pub fn clear_default(&mut self, field: FieldPosition) {
+        if !self.is_explicit(field) {
+            *self.field_mut(field) = "*".to_string();
+        }
+    }
+
+    /// Returns the value of a field.
+    #[must_use]
+    pub fn field(&self, field: FieldPosition) -> &str {
+        match field {
+            FieldPosition::Seconds => &self.seconds,
+            FieldPosition::Minutes => &self.min,
+            FieldPosition::Hour => &self.hour,
+            FieldPosition::DayOfMonth => &self.day_of_month,
+            FieldPosition::Month => &self.month,
+            FieldPosition::DayOfWeek => &self.day_of_week,
+            FieldPosition::Year => &self.year,
+        }
+    }
+
+    /// Returns the value of a field as Quartz reads it, with days of the week that were
+    /// written as numbers in another [`DayOfWeekFormat`] turned back into names.
+    pub(crate) fn quartz_field(&self, field: FieldPosition) -> Cow<'_, str> {
+        match field {
+            FieldPosition::DayOfWeek => {
+                action::day::name_days(&self.day_of_week, self.day_of_week_format)
+            }
+            field => Cow::Borrowed(self.field(field)),
+        }
+    }
+
+    /// Writes the days of the week with the given [`DayOfWeekFormat`], which
+    /// [`Syntax::quartz_field`] then reads them back with.
+    pub(crate) fn format_days(&mut self, format: DayOfWeekFormat) {
+        action::day::format_days(&mut self.day_of_week, format);
+        self.day_of_week_format = format;
+    }
+
+    /// Returns a mutable reference to the value of a field.
+    pub const fn field_mut(&mut self, field: FieldPosition) -> &mut String {
+        match field {
+            FieldPosition::Seconds => &mut self.seconds,
+            FieldPosition::Minutes => &mut self.min,
+            FieldPosition::Hour => &mut self.hour,
+            FieldPosition::DayOfMonth => &mut self.day_of_month,
+            FieldPosition::Month => &mut self.month,
+            FieldPosition::DayOfWeek => &mut self.day_of_week,
+            FieldPosition::Year => &mut self.year,
+        }
+    }
+
+    /// Returns the names of the fields in the order they appear in a cron expression,
+    /// which is also the order of [`FieldPosition::ALL`].
+    ///
+    /// These are the keys used by [`Syntax::to_map`]. Note that the minutes field is
+    /// named `"minutes"` even though the struct field is `min`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{FieldPosition, Syntax};
+    ///
+    /// assert_eq!(Syntax::field_names()[0], "seconds");
+    /// assert_eq!(Syntax::field_names()[FieldPosition::DayOfWeek as usize], "day_of_week");
+    /// ```
+    #[must_use]
+    pub const fn field_names() -> [&'static str; 7] {
+        [
+            "seconds",
+            "minutes",
+            "hour",
+            "day_of_month",
+            "month",
+            "day_of_week",
+            "year",
+        ]
+    }
+
+    /// Returns every field value keyed by its name from [`Syntax::field_names`].
+    ///
+    /// The values borrow from `self` and are trimmed the same way as when the
+    /// expression is displayed, so a template engine or serializer can use them
+    /// without knowing the field names in advance.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// let cron = Cron::new("every day at 4:00 pm").unwrap();
+    /// let fields = cron.syntax.to_map();
+    ///
+    /// assert_eq!(fields.len(), 7);
+    /// assert_eq!(fields["minutes"], "0");
+    /// assert_eq!(fields["hour"], "16");
+    /// assert_eq!(fields["day_of_month"], "*/1");
+    /// assert_eq!(fields["day_of_week"], "?");
+    /// ```
+    #[must_use]
+    pub fn to_map(&self) -> HashMap<&'static str, &str> {
+        FieldPosition::ALL
+            .into_iter()
+            .zip(Self::field_names())
+            .map(|(field, name)| (name, self.field(field).trim()))
+            .collect()
+    }
 }
 
 impl Default for Syntax {
@@ -30,10 +241,38 @@ impl Default for Syntax {
             day_of_week: "?".to_string(),
             month: "*".to_string(),
             year: "*".to_string(),
+            explicit: [false; 7],
+            day_of_week_format: DayOfWeekFormat::Names,
         }
     }
 }
 
+impl FromStr for Syntax {
+    type Err = Error;
+
+    /// Splits a cron expression into its fields.
+    ///
+    /// Accepts the seven-field form this crate produces, or six fields with the
+    /// year left out. Every field is treated as explicitly set. The field values
+    /// themselves are not validated here; see [`ScheduleSpec`](super::ScheduleSpec).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        if !(6..=7).contains(&fields.len()) {
+            return Err(Error::IncorrectValue {
+                state: "cron".to_string(),
+                error: format!("expected 6 or 7 fields, found {}", fields.len()),
+            });
+        }
+
+        let mut syntax = Self::default();
+        for (field, value) in FieldPosition::ALL.into_iter().zip(fields) {
+            *syntax.field_mut(field) = value.to_uppercase();
+            syntax.mark_explicit(field);
+        }
+        Ok(syntax)
+    }
+}
+
 impl std::fmt::Display for Cron {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -50,35 +289,873 @@ impl std::fmt::Display for Cron {
     }
 }
 
+/// A [`Cron`] whose [`Display`](std::fmt::Display) writes each field on its own line
+/// under a label, for debugging and logging. Created by [`Cron::verbose`].
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CronVerbose(pub Cron);
+
+/// The labels written before each field by [`CronVerbose`], in cron order.
+const VERBOSE_LABELS: [&str; 7] = [
+    "Seconds:",
+    "Minutes:",
+    "Hour:",
+    "Day-of-month:",
+    "Month:",
+    "Day-of-week:",
+    "Year:",
+];
+
+impl Cron {
+    /// Writes the fields one per line, each under its label.
+    fn write_verbose(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, (label, value)) in VERBOSE_LABELS.iter().zip(self.fields()).enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{label:<14}{value}")?;
+        }
+        Ok(())
+    }
+
+    /// Wraps the schedule so that it is displayed with one labeled field per line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// let cron = Cron::new("every 15 minutes on weekdays").unwrap();
+    /// assert_eq!(
+    ///     cron.verbose().to_string(),
+    ///     "Seconds:      0
+    /// Minutes:      0/15
+    /// Hour:         *
+    /// Day-of-month: ?
+    /// Month:        *
+    /// Day-of-week:  MON-FRI
+    /// Year:         *"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn verbose(self) -> CronVerbose {
+        CronVerbose(self)
+    }
+}
+
+impl std::fmt::Debug for Cron {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_verbose(f)?;
+        for warning in &self.warnings {
+            write!(f, "\n{:<14}{warning}", "Warning:")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for CronVerbose {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.write_verbose(f)
+    }
+}
+
 impl Cron {
-    /// Creates a new `Cron` instance from a given cron expression string.
+    /// Creates a new `Cron` instance from an English description of a schedule
+    /// (e.g., "every day at 4:00 pm").
     ///
     /// This function tokenizes the input string and processes each token to construct
     /// a valid `Cron` representation. If the input is empty or contains invalid tokens,
-    /// an error is returned.
+    /// an error is returned. To read an existing cron expression instead, use
+    /// [`Cron::parse_cron`].
     ///
     /// # Errors
     ///
-    /// Returns [`Error::InvalidInput`] if the input is empty or contains invalid tokens.
+    /// Returns [`Error::InvalidInput`] if the input is empty or contains invalid tokens,
+    /// and [`Error::TooComplex`] if it has more than eight schedule-bearing clauses.
     ///
+    /// Listed times with different minutes (e.g., "at 9:30am and 5pm") do not fit in
+    /// one expression and return [`Error::UnsupportedSchedule`] naming the expressions
+    /// they need; [`MultiCron::new`](super::MultiCron::new) returns those expressions
+    /// instead.
     pub fn new(text: &str) -> Result<Self> {
+        Self::with_options(text, &Options::default())
+    }
+
+    /// Creates a new `Cron` instance from a given English text using the given [`Options`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the input is empty or contains invalid tokens,
+    /// [`Error::TooComplex`] if it has more schedule-bearing clauses than
+    /// [`Options::max_clauses`], and [`Error::AmbiguousInput`] if it uses a word that can
+    /// be read as two schedules ("bimonthly").
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{Cron, Error, Options};
+    ///
+    /// let mut options = Options::default();
+    /// options.max_clauses = 2;
+    /// assert!(Cron::with_options("every day at 9am", &options).is_ok());
+    /// assert_eq!(
+    ///     Cron::with_options("every day at 9am in March", &options).unwrap_err(),
+    ///     Error::TooComplex { clauses: 3 },
+    /// );
+    /// ```
+    pub fn with_options(text: &str, options: &Options) -> Result<Self> {
+        Self::with_tokenizer(text, &Tokenizer::new(), options)
+    }
+
+    /// Creates a new `Cron` instance from a given English text, splitting it with a custom
+    /// [`Tokenizer`] such as one built by [`Tokenizer::with_synonyms`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Cron::with_options`].
+    pub fn with_tokenizer(text: &str, tokenizer: &Tokenizer, options: &Options) -> Result<Self> {
+        reject_ambiguous(text, tokenizer, |text| {
+            Self::with_tokenizer(text, tokenizer, options).map(|cron| cron.to_string())
+        })?;
+        let MultiCron(mut crons) = MultiCron::from_tokens(&tokenizer.run(text), options)?;
+        if crons.len() > 1 {
+            let expressions: Vec<String> = crons.iter().map(ToString::to_string).collect();
+            return Err(Error::UnsupportedSchedule {
+                schedule: text.trim().to_string(),
+                suggestion: format!("the separate schedules {}", expressions.join(" and ")),
+            });
+        }
+        let mut cron = crons.pop().ok_or(Error::InvalidInput)?;
+        cron.warnings.extend(
+            tokenizer
+                .ignored_words(text)
+                .into_iter()
+                .map(|token| Warning::IgnoredToken { token }),
+        );
+        Ok(cron)
+    }
+
+    /// Parses as much of an English text as possible, returning the schedule along with
+    /// the words and tokens that were not understood.
+    ///
+    /// Tokens that make the text fail to parse are dropped, the first one whose removal
+    /// lets the rest parse, or otherwise the last one, until the rest parses. There is no
+    /// schedule if no token is left.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of a text that was understood but cannot be expressed as written
+    /// ([`Error::UnsupportedSchedule`], [`Error::AmbiguousInput`] or
+    /// [`Error::TooComplex`]), since dropping a token would change what it means.
+    pub(crate) fn new_lenient(text: &str) -> (Result<Option<Self>>, Vec<String>) {
         let tokenizer = Tokenizer::new();
-        let tokens = tokenizer.run(text);
+        let options = Options::default();
+        let mut tokens = tokenizer.run(text);
+        let mut ignored = tokenizer.ignored_words(text);
+        if let Err(error) = reject_ambiguous(text, &tokenizer, |text| {
+            Self::with_options(text, &options).map(|cron| cron.to_string())
+        }) {
+            return (Err(error), ignored);
+        }
 
+        let mut attempts = MAX_LENIENT_ATTEMPTS;
+        while !tokens.is_empty() {
+            match Self::from_tokens(&tokens, &options) {
+                Ok(cron) => return (Ok(Some(cron)), ignored),
+                Err(error) if changes_meaning(&error) => return (Err(error), ignored),
+                Err(_) => {}
+            }
+
+            // Drop the first token whose removal lets the rest parse, within the budget
+            let mut dropped = tokens.len() - 1;
+            for index in 0..tokens.len() {
+                if attempts == 0 {
+                    break;
+                }
+                attempts -= 1;
+                let mut rest = tokens.clone();
+                rest.remove(index);
+                if rest.is_empty() {
+                    continue;
+                }
+                match Self::from_tokens(&rest, &options) {
+                    Ok(_) => {
+                        dropped = index;
+                        break;
+                    }
+                    Err(error) if changes_meaning(&error) => return (Err(error), ignored),
+                    Err(_) => {}
+                }
+            }
+            ignored.push(tokens.remove(dropped).trim().to_string());
+        }
+        (Ok(None), ignored)
+    }
+
+    /// Reads a Quartz cron expression (e.g., `"0 0 16 */1 * ? *"`), unlike [`Cron::new`],
+    /// which expects English.
+    ///
+    /// The expression must have seven fields, or six with the year left out. Every field
+    /// is checked against its allowed range and kept as written, in upper case, and is
+    /// reported by [`Cron::explicit_fields`] as explicitly set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncorrectValue`] if the expression has the wrong number of fields
+    /// or a field value cannot be parsed or is outside its allowed range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// let cron = Cron::parse_cron("0 0/15 9-17 ? * mon-fri *").unwrap();
+    /// assert_eq!(cron.to_string(), "0 0/15 9-17 ? * MON-FRI *");
+    /// assert_eq!(cron.hours(), "9-17");
+    ///
+    /// assert!(Cron::parse_cron("0 0 25 * * ? *").is_err());
+    /// assert!(Cron::parse_cron("every day at 4:00 pm").is_err());
+    /// ```
+    pub fn parse_cron(expression: &str) -> Result<Self> {
+        let syntax = expression.parse::<Syntax>()?;
+        ScheduleSpec::try_from(&syntax)?.check_day_fields()?;
+        Ok(Self {
+            syntax,
+            ..Self::default()
+        })
+    }
+
+    /// Builds a `Cron` from tokens produced by the [`Tokenizer`].
+    pub(crate) fn from_tokens(tokens: &[String], options: &Options) -> Result<Self> {
         if tokens.is_empty() {
             return Err(Error::InvalidInput);
         }
 
+        let mut tokens = tokens.to_vec();
+        let business_hours = expand_business_hours(&mut tokens, options)?;
+        apply_default_meridiem(&mut tokens, options.default_meridiem);
+        let mut states: Vec<_> = tokens
+            .iter()
+            .map(|token| action::try_from_token(token))
+            .collect();
+        read_long_intervals(&tokens, &mut states);
+        defer_days(&mut tokens, &mut states);
+        date_after_month(&mut tokens, &mut states);
+        hoist_repetition(&mut tokens, &mut states);
+
+        let clauses = states
+            .iter()
+            .flatten()
+            .filter(|state| state.cron_field_name().is_some())
+            .count();
+        if clauses > options.max_clauses {
+            return Err(Error::TooComplex { clauses });
+        }
+
         let mut cron = Self::default();
-        for token in tokens {
-            if let Some(state) = action::try_from_token(&token) {
-                state.process(&token, &mut cron)?;
+        let mut opened = Vec::new();
+        let mut counted = Vec::new();
+        for (token, state) in tokens.iter().zip(states) {
+            if let Some(state) = state {
+                // "twice a day" keeps its count only if no other clause sets its hours
+                let field = (state == Kind::FrequencyTimes)
+                    .then(|| action::frequency_times::counted_field(token))
+                    .flatten();
+                if let Some(field) = field.filter(|field| cron.syntax.is_explicit(*field)) {
+                    return Err(action::frequency_times::overridden(token, field));
+                }
+                state.process(token, &mut cron)?;
+                if let Some(field) = field {
+                    counted.push((token, field, cron.syntax.field(field).to_string()));
+                }
+                if cron
+                    .stack
+                    .last()
+                    .is_some_and(|element| element.owner == Kind::RangeStart)
+                {
+                    opened.push(token.as_str());
+                } else {
+                    opened.clear();
+                }
             }
         }
+        warn_unused_range(&mut cron, &opened);
+        if let Some((token, field, _)) = counted
+            .into_iter()
+            .find(|(_, field, value)| cron.syntax.field(*field) != value)
+        {
+            return Err(action::frequency_times::overridden(token, field));
+        }
+
+        action::day::process_leftover_ordinal(&mut cron)?;
+        reject_zero_step(&cron.syntax)?;
+        carry_intervals(&mut cron.syntax)?;
+        // A repetition of days set after the days of the week ("on Monday every 2 days")
+        // gives way to them, the same as one set before
+        if action::day::is_specific_day(&cron.syntax.day_of_week) {
+            action::day::clear_day_of_month(&mut cron);
+        }
+        reject_day_conflict(&cron.syntax)?;
+        reject_long_step(&cron.syntax)?;
+        action::day::start_week(&mut cron.syntax.day_of_week, options.week_start);
+
+        // Business hours fall on weekdays unless the input names its own days, which
+        // "every day" does not
+        if business_hours
+            && !action::day::is_specific_day(&cron.syntax.day_of_month)
+            && !action::day::is_specific_day(&cron.syntax.day_of_week)
+        {
+            cron.syntax.day_of_week = "MON-FRI".to_string();
+            cron.syntax.mark_explicit(FieldPosition::DayOfWeek);
+            action::day::clear_day_of_month(&mut cron);
+        }
+        cron.syntax.format_days(options.day_of_week_format);
+        action::month::format_months(&mut cron.syntax.month, options.month_format);
         Ok(cron)
     }
 }
 
+/// The most token removals [`Cron::new_lenient`] tries while looking for the token to drop,
+/// so that a long input does not take cubic time.
+const MAX_LENIENT_ATTEMPTS: usize = 64;
+
+/// Returns whether an error comes from input that was understood but cannot be expressed
+/// as written, which dropping a token would silently change.
+const fn changes_meaning(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::UnsupportedSchedule { .. } | Error::AmbiguousInput { .. } | Error::TooComplex { .. }
+    )
+}
+
+/// Repetitions that can overflow their field: the field and its unit, the size of the
+/// field, and the field and unit they carry into.
+const INTERVALS: [(FieldPosition, &str, u32, FieldPosition, &str); 3] = [
+    (
+        FieldPosition::Seconds,
+        "seconds",
+        60,
+        FieldPosition::Minutes,
+        "minutes",
+    ),
+    (
+        FieldPosition::Minutes,
+        "minutes",
+        60,
+        FieldPosition::Hour,
+        "hours",
+    ),
+    (
+        FieldPosition::Hour,
+        "hours",
+        24,
+        FieldPosition::DayOfMonth,
+        "days",
+    ),
+];
+
+/// Re-expresses a repetition longer than its field in the next larger unit ("every 120
+/// minutes" runs every 2 hours).
+///
+/// A step only counts within its field, so `0/90` in the minutes would run at minute 0 of
+/// every hour. Intervals that are not a whole number of the larger unit cannot be
+/// expressed and are rejected with [`Error::UnsupportedSchedule`], as are intervals whose
+/// larger unit is already set ("every 48 hours on Monday").
+pub fn carry_intervals(syntax: &mut Syntax) -> Result<()> {
+    for (field, unit, size, next, next_unit) in INTERVALS {
+        let Some(step) = syntax
+            .field(field)
+            .trim()
+            .strip_prefix("0/")
+            .and_then(|step| step.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        if step < size {
+            continue;
+        }
+        if step % size != 0 {
+            let lower = step / size * size;
+            return Err(Error::UnsupportedSchedule {
+                schedule: format!("every {step} {unit}"),
+                suggestion: format!("every {lower} or {} {unit}", lower + size),
+            });
+        }
+        if syntax.field(next).trim() != "*" {
+            return Err(Error::UnsupportedSchedule {
+                schedule: format!("every {step} {unit}"),
+                suggestion: format!("every {} {next_unit}", step / size),
+            });
+        }
+
+        *syntax.field_mut(field) = "0".to_string();
+        let start = if next == FieldPosition::DayOfMonth {
+            "*"
+        } else {
+            "0"
+        };
+        *syntax.field_mut(next) = format!("{start}/{}", step / size);
+        syntax.mark_explicit(next);
+    }
+    Ok(())
+}
+
+/// Reports the tokens of a range whose start was given but never used ("every 5 minutes
+/// starting on Monday"), which would otherwise be dropped without a trace.
+fn warn_unused_range(cron: &mut Cron, opened: &[&str]) {
+    let is_unused = cron.stack.last().is_some_and(|element| {
+        element.owner == Kind::RangeStart
+            && (element.frequency_start.is_some()
+                || element.day.is_some()
+                || element.month.is_some()
+                || element.year.is_some())
+    });
+    if is_unused {
+        cron.warnings
+            .extend(opened.iter().map(|token| Warning::IgnoredToken {
+                token: (*token).to_string(),
+            }));
+    }
+}
+
+/// Reads a number of four or more digits followed by a unit of time ("every 1440 minutes")
+/// as a repetition of that unit rather than as a year, whatever its width.
+fn read_long_intervals(tokens: &[String], states: &mut [Option<Kind>]) {
+    for index in 1..states.len() {
+        if matches!(
+            states[index],
+            Some(Kind::Secund | Kind::Minute | Kind::Hour)
+        ) && states[index - 1] == Some(Kind::Year)
+            && tokens[index - 1].bytes().all(|byte| byte.is_ascii_digit())
+        {
+            states[index - 1] = Some(Kind::FrequencyOnly);
+        }
+    }
+}
+
+/// Rejects a repetition of zero units ("every 0 minutes"), which would never advance.
+fn reject_zero_step(syntax: &Syntax) -> Result<()> {
+    for field in FieldPosition::ALL {
+        let value = syntax.field(field).trim();
+        if value.split(',').any(|item| item.ends_with("/0")) {
+            return Err(Error::IncorrectValue {
+                state: Syntax::field_names()[field as usize].to_string(),
+                error: format!("step in {value} should not be 0"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Rejects a repetition longer than its field ("every 6 weeks" is `*/42` in the days of the
+/// month), which cron would run only once per cycle of the next unit, and which Quartz
+/// rejects. Repetitions of seconds, minutes and hours are carried into the next unit first.
+fn reject_long_step(syntax: &Syntax) -> Result<()> {
+    for field in FieldPosition::ALL {
+        let value = syntax.field(field).trim();
+        let (min, max) = spec::bounds(field);
+        let is_long = value.split(',').any(|item| {
+            item.split_once('/')
+                .and_then(|(_, step)| step.parse::<u32>().ok())
+                .is_some_and(|step| step > max - min + 1)
+        });
+        if is_long {
+            return Err(Error::IncorrectValue {
+                state: Syntax::field_names()[field as usize].to_string(),
+                error: format!("step in {value} should be at most {}", max - min + 1),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Rejects a schedule that names both days of the month and days of the week ("at noon
+/// on the 15th and on Monday").
+///
+/// Quartz requires one of the two fields to be `?`. Days of the week take precedence over
+/// a repetition of days ("every 2 days on Monday" runs on Mondays), but two sets of
+/// specific days cannot be combined: cron would not run on the days both name, so the
+/// input is rejected rather than silently dropping one of them.
+fn reject_day_conflict(syntax: &Syntax) -> Result<()> {
+    if action::day::is_specific_day(&syntax.day_of_month)
+        && action::day::is_specific_day(&syntax.day_of_week)
+    {
+        return Err(Error::IncorrectValue {
+            state: "day".to_string(),
+            error: format!(
+                "day of month {} and day of week {} cannot both be set",
+                syntax.day_of_month.trim(),
+                syntax.day_of_week.trim()
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Matches words that stand for two different schedules: "bimonthly" is every two months
+/// to some readers and twice a month to others, and "biannual" likewise for years.
+static RE_AMBIGUOUS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?-u:\b)bi-?(?:monthly|annual(?:ly)?)(?-u:\b)").unwrap());
+
+/// Rejects a text that uses a word with two readings ("bimonthly"), naming what `parse`
+/// makes of each reading so that the caller can ask which one was meant.
+///
+/// # Errors
+///
+/// Returns [`Error::AmbiguousInput`] if the text uses such a word, or the error of a
+/// reading that cannot be parsed.
+pub fn reject_ambiguous(
+    text: &str,
+    tokenizer: &Tokenizer,
+    parse: impl Fn(&str) -> Result<String>,
+) -> Result<()> {
+    let expanded = tokenizer.apply_synonyms(text);
+    let Some(word) = RE_AMBIGUOUS.find(&expanded) else {
+        return Ok(());
+    };
+    let readings = if word.as_str().to_lowercase().ends_with("monthly") {
+        ["every 2 months", "twice a month"]
+    } else {
+        ["every 2 years", "twice a year"]
+    };
+
+    let alternatives = readings
+        .iter()
+        .map(|reading| {
+            parse(&format!(
+                "{}{reading}{}",
+                &expanded[..word.start()],
+                &expanded[word.end()..]
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Err(Error::AmbiguousInput {
+        input: text.trim().to_string(),
+        alternatives,
+    })
+}
+
+/// Matches "business hours" and its synonyms.
+static RE_BUSINESS_HOURS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(business|office|working) hours$").unwrap());
+
+/// Replaces each mention of "business hours" with the time range it stands for
+/// ("between 9:00 and 17:00"), using the bounds from [`Options::business_hours`].
+///
+/// Returns whether the input mentioned business hours.
+///
+/// # Errors
+///
+/// Returns [`Error::IncorrectValue`] if business hours are mentioned and their bounds are
+/// not hours of the day, or the first comes after the last.
+fn expand_business_hours(tokens: &mut Vec<String>, options: &Options) -> Result<bool> {
+    if !tokens.iter().any(|token| RE_BUSINESS_HOURS.is_match(token)) {
+        return Ok(false);
+    }
+
+    let (start, end) = options.business_hours;
+    if start > 23 || end > 23 || start > end {
+        return Err(Error::IncorrectValue {
+            state: "business_hours".to_string(),
+            error: format!(
+                "business hours {start} to {end} should be hours from 0 to 23, the first no later than the last"
+            ),
+        });
+    }
+    *tokens = std::mem::take(tokens)
+        .into_iter()
+        .flat_map(|token| {
+            if RE_BUSINESS_HOURS.is_match(&token) {
+                vec![
+                    "between".to_string(),
+                    format!("{start}:00"),
+                    "and".to_string(),
+                    format!("{end}:00"),
+                ]
+            } else {
+                vec![token]
+            }
+        })
+        .collect();
+    Ok(true)
+}
+
+/// Matches an hour on the clock given without "am" or "pm" ("7 o'clock").
+static RE_BARE_CLOCK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^([0-9]+) ?o['’]clock$").unwrap());
+
+/// Reads hours from 1 to 12 given without "am" or "pm" in the morning or the evening, as
+/// set by [`Options::default_meridiem`].
+fn apply_default_meridiem(tokens: &mut [String], meridiem: Meridiem) {
+    let suffix = match meridiem {
+        Meridiem::Am => "am",
+        Meridiem::Pm => "pm",
+        Meridiem::TwentyFourHour => return,
+    };
+    for token in tokens {
+        let is_bare = RE_BARE_CLOCK
+            .captures(token)
+            .and_then(|captures| captures[1].parse::<u32>().ok())
+            .is_some_and(|hour| (1..=12).contains(&hour));
+        if is_bare {
+            token.push(' ');
+            token.push_str(suffix);
+        }
+    }
+}
+
+/// Moves a repetition ("every 5 minutes") that directly follows a time range ("from 9am
+/// to 5pm", "between 9am and 5pm") in front of the range, so that both orders of the
+/// two clauses are processed alike.
+fn hoist_repetition(tokens: &mut [String], states: &mut [Option<Kind>]) {
+    let mut start = 0;
+    while start < states.len() {
+        let Some((end, length)) = repetition_after_range(states, start) else {
+            start += 1;
+            continue;
+        };
+        tokens[start..=end + length].rotate_right(length);
+        states[start..=end + length].rotate_right(length);
+        start = end + length + 1;
+    }
+}
+
+/// Returns where the time range beginning at `start` ends and how many tokens the
+/// repetition right after it spans, if `start` begins a time range.
+fn repetition_after_range(states: &[Option<Kind>], start: usize) -> Option<(usize, usize)> {
+    let is = |index: usize, kind: Kind| states.get(index).copied().flatten() == Some(kind);
+    let is_unit =
+        |index: usize| is(index, Kind::Secund) || is(index, Kind::Minute) || is(index, Kind::Hour);
+
+    let end = time_range_end(states, start)?;
+    let length = if is(end + 1, Kind::FrequencyOnly) && is_unit(end + 2) {
+        2
+    } else {
+        usize::from(is_unit(end + 1))
+    };
+    Some((end, length))
+}
+
+/// Returns where the time range beginning at `start` ends, if `start` begins one.
+fn time_range_end(states: &[Option<Kind>], start: usize) -> Option<usize> {
+    let is = |index: usize, kind: Kind| states.get(index).copied().flatten() == Some(kind);
+
+    let first_time = if is(start, Kind::RangeStart) {
+        start + 1
+    } else {
+        start
+    };
+    let end = first_time + 2;
+    (is(first_time, Kind::ClockTime)
+        && is(first_time + 1, Kind::RangeEnd)
+        && is(end, Kind::ClockTime))
+    .then_some(end)
+}
+
+/// Moves days of the week that come between a repetition and a time range ("every 10
+/// minutes on weekdays from 9am to 5pm") after the range, so that the repetition still
+/// applies within it.
+fn defer_days(tokens: &mut [String], states: &mut [Option<Kind>]) {
+    let is_unit =
+        |kind: Option<Kind>| matches!(kind, Some(Kind::Secund | Kind::Minute | Kind::Hour));
+    for index in 1..states.len() {
+        if states[index] != Some(Kind::Day) || !is_unit(states[index - 1]) {
+            continue;
+        }
+        if let Some(end) = time_range_end(states, index + 1) {
+            tokens[index..=end].rotate_left(1);
+            states[index..=end].rotate_left(1);
+        }
+    }
+}
+
+/// Moves a day of the month that follows a month name ("on June 15", "on June 15th") in
+/// front of it as an ordinal, so that it is read the same as "the 15th of June". A number
+/// followed by a unit ("in June 2 hours") is left to that unit, and one followed by more
+/// days ("June 1 and 15") to the range or list it starts. A comma after the day ("every
+/// January 1st, at midnight") reaches here as "and", which does not stop the move.
+fn date_after_month(tokens: &mut [String], states: &mut [Option<Kind>]) {
+    let is_unit = |kind: Option<Kind>| {
+        matches!(
+            kind,
+            Some(Kind::Secund | Kind::Minute | Kind::Hour | Kind::Day | Kind::Month | Kind::Year)
+        )
+    };
+    for index in 1..states.len() {
+        let state = |index: usize| states.get(index).copied().flatten();
+        let continues = state(index + 1) == Some(Kind::RangeEnd)
+            && (!tokens[index + 1].eq_ignore_ascii_case("and")
+                || matches!(
+                    state(index + 2),
+                    Some(Kind::FrequencyOnly | Kind::FrequencyWith)
+                ));
+        let is_date = states[index - 1] == Some(Kind::Month)
+            && !tokens[index - 1].to_lowercase().starts_with("month")
+            && matches!(
+                states[index],
+                Some(Kind::FrequencyOnly | Kind::FrequencyWith)
+            )
+            && tokens[index].starts_with(|c: char| c.is_ascii_digit())
+            && !is_unit(state(index + 1))
+            && !continues;
+        if !is_date {
+            continue;
+        }
+        if states[index] == Some(Kind::FrequencyOnly) {
+            tokens[index].push_str("th");
+            states[index] = Some(Kind::FrequencyWith);
+        }
+        tokens.swap(index - 1, index);
+        states.swap(index - 1, index);
+    }
+}
+
+impl Cron {
+    /// Returns the fields whose values came from the input, in cron order.
+    ///
+    /// Fields that were left untouched, or that were filled in with a default by
+    /// another clause (e.g., the midnight time implied by "every day"), are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{Cron, FieldPosition};
+    ///
+    /// let cron = Cron::new("every day").unwrap();
+    /// assert_eq!(cron.explicit_fields(), vec![FieldPosition::DayOfMonth]);
+    /// ```
+    #[must_use]
+    pub fn explicit_fields(&self) -> Vec<FieldPosition> {
+        FieldPosition::ALL
+            .into_iter()
+            .filter(|field| self.syntax.is_explicit(*field))
+            .collect()
+    }
+
+    /// Returns whether the schedule only approximates the input, because cron could not
+    /// express the requested period exactly.
+    ///
+    /// A [`Warning::ApproximatedPeriod`] describing the approximation is recorded in
+    /// [`Cron::warnings`]. Periods too lossy to approximate, such as "every other Monday"
+    /// or "on alternating Mondays", are rejected with [`Error::UnsupportedSchedule`]
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// assert!(Cron::new("every 2 weeks").unwrap().is_approximate());
+    /// assert!(!Cron::new("every Monday").unwrap().is_approximate());
+    /// ```
+    #[must_use]
+    pub fn is_approximate(&self) -> bool {
+        self.warnings
+            .iter()
+            .any(|warning| matches!(warning, Warning::ApproximatedPeriod { .. }))
+    }
+
+    /// Returns every field value in the order they appear in the expression, trimmed
+    /// the same way as when the expression is displayed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// let cron = Cron::new("Run at 6:00 pm every Monday through Friday").unwrap();
+    /// assert_eq!(cron.fields(), ["0", "0", "18", "?", "*", "MON-FRI", "*"]);
+    ///
+    /// assert_eq!(cron.minutes(), "0");
+    /// assert_eq!(cron.hours(), "18");
+    /// assert_eq!(cron.day_of_week(), "MON-FRI");
+    /// ```
+    #[must_use]
+    pub fn fields(&self) -> [&str; 7] {
+        FieldPosition::ALL.map(|field| self.syntax.field(field).trim())
+    }
+
+    /// Returns the seconds field.
+    #[must_use]
+    pub fn seconds(&self) -> &str {
+        self.syntax.seconds.trim()
+    }
+
+    /// Returns the minutes field.
+    #[must_use]
+    pub fn minutes(&self) -> &str {
+        self.syntax.min.trim()
+    }
+
+    /// Returns the hours field.
+    #[must_use]
+    pub fn hours(&self) -> &str {
+        self.syntax.hour.trim()
+    }
+
+    /// Returns the day-of-month field.
+    #[must_use]
+    pub fn day_of_month(&self) -> &str {
+        self.syntax.day_of_month.trim()
+    }
+
+    /// Returns the month field.
+    #[must_use]
+    pub fn month(&self) -> &str {
+        self.syntax.month.trim()
+    }
+
+    /// Returns the day-of-week field.
+    #[must_use]
+    pub fn day_of_week(&self) -> &str {
+        self.syntax.day_of_week.trim()
+    }
+
+    /// Returns the year field.
+    #[must_use]
+    pub fn year(&self) -> &str {
+        self.syntax.year.trim()
+    }
+
+    /// Returns the field at the given position, trimmed the same way as when the
+    /// expression is displayed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{Cron, FieldPosition};
+    ///
+    /// let cron = Cron::new("Run at 6:00 pm every Monday through Friday").unwrap();
+    /// assert_eq!(cron.field_at(FieldPosition::Hour), "18");
+    /// assert_eq!(cron.field_at(FieldPosition::try_from(5)?), "MON-FRI");
+    /// # Ok::<(), english_to_cron::Error>(())
+    /// ```
+    #[must_use]
+    pub fn field_at(&self, position: FieldPosition) -> &str {
+        self.fields()[position as usize]
+    }
+
+    /// Replaces the field at the given position and marks it as explicitly set.
+    ///
+    /// The value is not validated; use [`Cron::validate`] to check the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{Cron, FieldPosition};
+    ///
+    /// let mut cron = Cron::new("every day at 4:00 pm").unwrap();
+    /// cron.set_field(FieldPosition::Minutes, "30");
+    /// assert_eq!(cron.to_string(), "0 30 16 */1 * ? *");
+    /// ```
+    pub fn set_field(&mut self, position: FieldPosition, value: impl Into<String>) {
+        *self.syntax.field_mut(position) = value.into();
+        self.syntax.mark_explicit(position);
+    }
+}
+
+/// Parses English, the same as [`Cron::new`]. Use [`Cron::parse_cron`] to read a cron
+/// expression.
 impl FromStr for Cron {
     type Err = Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -0,0 +1,500 @@
+//! This module describes a [`Cron`] in plain English, so that a user interface can echo
+//! back how an input was read ("every 15 minutes on Monday through Friday").
+//!
+//! The description is built from the fields as they are, without parsing English again:
+//! the time of day comes first, followed by the days of the month and of the week, the
+//! months, and the years.
+
+use std::fmt::Write as _;
+
+use super::{
+    cron::Cron,
+    spec::{FieldItem, FieldSpec, ScheduleSpec},
+};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Day names in Quartz order, where Sunday is 1.
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const ORDINAL_WORDS: [&str; 5] = ["first", "second", "third", "fourth", "fifth"];
+
+impl Cron {
+    /// Describes the schedule in plain English.
+    ///
+    /// Steps, ranges, lists, and the Quartz `L`, `W`, and `#` specifiers are described in
+    /// every field. A field that cannot be parsed leaves the expression itself as the
+    /// description.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// let cron = Cron::new("every 15 minutes on weekdays").unwrap();
+    /// assert_eq!(cron.describe(), "every 15 minutes on Monday through Friday");
+    ///
+    /// let cron = Cron::new("at 9am and 5pm on the 1st and 15th").unwrap();
+    /// assert_eq!(cron.describe(), "at 09:00 and 17:00 on the 1st and 15th");
+    /// ```
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let Ok(spec) = ScheduleSpec::try_from(&self.syntax) else {
+            return self.to_string();
+        };
+
+        let mut parts = time_of_day(&spec);
+        parts.extend(day_of_month(&spec.day_of_month));
+        parts.extend(day_of_week(&spec.day_of_week));
+        parts.extend(months(&spec.month));
+        parts.extend(years(&spec.year));
+        parts.join(" ")
+    }
+}
+
+/// Describes the seconds, minutes, and hours.
+fn time_of_day(spec: &ScheduleSpec) -> Vec<String> {
+    let second = single_value(&spec.seconds);
+    let minute = single_value(&spec.minutes);
+
+    // Fixed times of day ("at 09:00 and 17:00")
+    if let (Some(second), Some(minute), Some(hours)) = (second, minute, values(&spec.hour)) {
+        let times: Vec<_> = hours
+            .into_iter()
+            .map(|hour| clock(hour, minute, second))
+            .collect();
+        return vec![format!("at {}", join(&times))];
+    }
+
+    let mut parts = Vec::new();
+    match (&spec.seconds, second) {
+        (FieldSpec::Any, _) => parts.push("every second".to_string()),
+        (_, Some(0)) => {}
+        (seconds, _) => parts.push(within_hour(seconds, "second")),
+    }
+
+    let is_every_second = spec.seconds == FieldSpec::Any;
+    match (&spec.minutes, minute) {
+        (FieldSpec::Any, _) if !is_every_second && second == Some(0) => {
+            parts.push("every minute".to_string());
+        }
+        (FieldSpec::Any, _) => {}
+        // A fixed minute repeats with the hours ("every 2 hours"), with a minute past the
+        // hour only when it is not on the hour
+        (_, Some(minute)) if second.is_some() => {
+            if minute != 0 {
+                parts.push(format!("at minute {minute}"));
+            }
+            parts.push(hourly(&spec.hour));
+            return parts;
+        }
+        (minutes, _) => parts.push(within_hour(minutes, "minute")),
+    }
+
+    parts.extend(hours_within_day(&spec.hour));
+    parts
+}
+
+/// Describes a field that counts within an hour or a minute ("every 15 minutes", "at
+/// minutes 0 and 30").
+fn within_hour(spec: &FieldSpec, unit: &str) -> String {
+    let FieldSpec::List(items) = spec else {
+        return format!("every {unit}");
+    };
+    let (values, others): (Vec<_>, Vec<_>) = items
+        .iter()
+        .partition(|item| matches!(item, FieldItem::Value(_)));
+
+    let mut phrases = Vec::new();
+    if !values.is_empty() {
+        let values: Vec<_> = values.iter().map(|item| item_value(item)).collect();
+        let plural = if values.len() > 1 { "s" } else { "" };
+        phrases.push(format!("at {unit}{plural} {}", join(&values)));
+    }
+    for item in others {
+        phrases.push(match item {
+            FieldItem::Range { start, end } => format!("during {unit}s {start} through {end}"),
+            FieldItem::Step { start, end, step } => {
+                let mut phrase = every(*step, unit);
+                match (start, end) {
+                    (Some(start), Some(end)) => {
+                        let _ = write!(phrase, " from {unit} {start} through {end}");
+                    }
+                    (Some(start), None) if *start > 0 => {
+                        let _ = write!(phrase, " starting at {unit} {start}");
+                    }
+                    _ => {}
+                }
+                phrase
+            }
+            item => format!("at {unit} {}", item_value(item)),
+        });
+    }
+    join(&phrases)
+}
+
+/// Describes the hours of a schedule that fires once an hour ("every hour", "every 2
+/// hours from 09:00 through 17:00").
+fn hourly(spec: &FieldSpec) -> String {
+    let FieldSpec::List(items) = spec else {
+        return "every hour".to_string();
+    };
+    let phrases: Vec<_> = items
+        .iter()
+        .map(|item| match item {
+            FieldItem::Value(hour) => format!("at {}", clock(*hour, 0, 0)),
+            FieldItem::Range { start, end } => format!(
+                "every hour from {} through {}",
+                clock(*start, 0, 0),
+                clock(*end, 0, 0)
+            ),
+            FieldItem::Step { start, end, step } => {
+                let mut phrase = every(*step, "hour");
+                match (start, end) {
+                    (Some(start), Some(end)) => {
+                        let _ = write!(
+                            phrase,
+                            " from {} through {}",
+                            clock(*start, 0, 0),
+                            clock(*end, 0, 0)
+                        );
+                    }
+                    (Some(start), None) if *start > 0 => {
+                        let _ = write!(phrase, " starting at {}", clock(*start, 0, 0));
+                    }
+                    _ => {}
+                }
+                phrase
+            }
+            FieldItem::Special(special) => format!("at hour {special}"),
+        })
+        .collect();
+    join(&phrases)
+}
+
+/// Describes the hours of a schedule that repeats within the hour ("every 15 minutes from
+/// 09:00 through 17:59").
+fn hours_within_day(spec: &FieldSpec) -> Option<String> {
+    let FieldSpec::List(items) = spec else {
+        return None;
+    };
+    let phrases: Vec<_> = items
+        .iter()
+        .map(|item| match item {
+            FieldItem::Value(hour) => format!("during the {} hour", clock(*hour, 0, 0)),
+            FieldItem::Range { start, end } => format!(
+                "from {} through {}",
+                clock(*start, 0, 0),
+                clock(*end, 59, 0)
+            ),
+            FieldItem::Step { start, end, step } => {
+                let mut phrase = format!("during {}", every(*step, "hour"));
+                if let (Some(start), Some(end)) = (start, end) {
+                    let _ = write!(
+                        phrase,
+                        " from {} through {}",
+                        clock(*start, 0, 0),
+                        clock(*end, 59, 0)
+                    );
+                }
+                phrase
+            }
+            FieldItem::Special(special) => format!("during hour {special}"),
+        })
+        .collect();
+    Some(join(&phrases))
+}
+
+/// Describes the days of the month ("on the 1st and 15th", "every 2 days").
+fn day_of_month(spec: &FieldSpec) -> Option<String> {
+    let FieldSpec::List(items) = spec else {
+        return None;
+    };
+    let (values, others): (Vec<_>, Vec<_>) = items
+        .iter()
+        .partition(|item| matches!(item, FieldItem::Value(_)));
+
+    let mut phrases = Vec::new();
+    if !values.is_empty() {
+        let days: Vec<_> = values
+            .iter()
+            .map(|item| match item {
+                FieldItem::Value(day) => ordinal(*day),
+                item => item_value(item),
+            })
+            .collect();
+        phrases.push(format!("on the {}", join(&days)));
+    }
+    for item in others {
+        phrases.push(match item {
+            FieldItem::Range { start, end } => {
+                format!("on the {} through the {}", ordinal(*start), ordinal(*end))
+            }
+            FieldItem::Step { start, end, step } => {
+                let mut phrase = every(*step, "day");
+                match (start, end) {
+                    (Some(start), Some(end)) => {
+                        let _ = write!(
+                            phrase,
+                            " from the {} through the {}",
+                            ordinal(*start),
+                            ordinal(*end)
+                        );
+                    }
+                    (Some(start), None) if *start > 1 => {
+                        let _ = write!(phrase, " starting on the {}", ordinal(*start));
+                    }
+                    _ => {}
+                }
+                phrase
+            }
+            FieldItem::Special(special) => special_day_of_month(special),
+            FieldItem::Value(_) => unreachable!("values are described together"),
+        });
+    }
+    Some(join(&phrases))
+}
+
+/// Describes a Quartz day-of-month specifier (`L`, `LW`, `L-3`, `15W`).
+fn special_day_of_month(special: &str) -> String {
+    if special == "L" {
+        return "on the last day of the month".to_string();
+    }
+    if special == "LW" {
+        return "on the last weekday of the month".to_string();
+    }
+    if let Some(offset) = special.strip_prefix("L-") {
+        return format!("{offset} days before the last day of the month");
+    }
+    match special.strip_suffix('W').map(str::parse::<u32>) {
+        Some(Ok(day)) => format!("on the weekday nearest the {}", ordinal(day)),
+        _ => format!("on day {special}"),
+    }
+}
+
+/// Describes the days of the week ("on Monday through Friday").
+fn day_of_week(spec: &FieldSpec) -> Option<String> {
+    let FieldSpec::List(items) = spec else {
+        return None;
+    };
+    let phrases: Vec<_> = items
+        .iter()
+        .map(|item| match item {
+            FieldItem::Value(day) => day_name(*day).to_string(),
+            FieldItem::Range { start, end } => {
+                format!("{} through {}", day_name(*start), day_name(*end))
+            }
+            FieldItem::Step { start, end, step } => format!(
+                "every {} day of the week from {} through {}",
+                ordinal(*step),
+                day_name(start.unwrap_or(1)),
+                day_name(end.unwrap_or(7))
+            ),
+            FieldItem::Special(special) => special_day_of_week(special),
+        })
+        .collect();
+    Some(format!("on {}", join(&phrases)))
+}
+
+/// Describes a Quartz day-of-week specifier (`FRIL`, `6L`, `MON#2`).
+fn special_day_of_week(special: &str) -> String {
+    if let Some((day, nth)) = special.split_once('#') {
+        let nth = nth
+            .parse::<usize>()
+            .ok()
+            .and_then(|nth| ORDINAL_WORDS.get(nth.wrapping_sub(1)));
+        if let (Some(day), Some(nth)) = (parse_day(day), nth) {
+            return format!("the {nth} {} of the month", day_name(day));
+        }
+    }
+    if let Some(day) = special.strip_suffix('L').and_then(parse_day) {
+        return format!("the last {} of the month", day_name(day));
+    }
+    special.to_string()
+}
+
+/// Describes the months ("in January and July", "every 3 months").
+fn months(spec: &FieldSpec) -> Option<String> {
+    let FieldSpec::List(items) = spec else {
+        return None;
+    };
+    let phrases: Vec<_> = items
+        .iter()
+        .map(|item| match item {
+            FieldItem::Value(month) => format!("in {}", month_name(*month)),
+            FieldItem::Range { start, end } => {
+                format!("in {} through {}", month_name(*start), month_name(*end))
+            }
+            FieldItem::Step { start, end, step } => {
+                let mut phrase = every(*step, "month");
+                match (start, end) {
+                    (Some(start), Some(end)) => {
+                        let _ = write!(
+                            phrase,
+                            " from {} through {}",
+                            month_name(*start),
+                            month_name(*end)
+                        );
+                    }
+                    (Some(start), None) if *start > 1 => {
+                        let _ = write!(phrase, " starting in {}", month_name(*start));
+                    }
+                    _ => {}
+                }
+                phrase
+            }
+            FieldItem::Special(special) => format!("in {special}"),
+        })
+        .collect();
+    Some(join_in(&phrases))
+}
+
+/// Describes the years ("in 2025", "every 2 years starting in 2024").
+fn years(spec: &FieldSpec) -> Option<String> {
+    let FieldSpec::List(items) = spec else {
+        return None;
+    };
+    let phrases: Vec<_> = items
+        .iter()
+        .map(|item| match item {
+            FieldItem::Value(year) => format!("in {year}"),
+            FieldItem::Range { start, end } => format!("from {start} through {end}"),
+            FieldItem::Step { start, end, step } => {
+                let mut phrase = every(*step, "year");
+                match (start, end) {
+                    (Some(start), Some(end)) => {
+                        let _ = write!(phrase, " from {start} through {end}");
+                    }
+                    (Some(start), None) => {
+                        let _ = write!(phrase, " starting in {start}");
+                    }
+                    _ => {}
+                }
+                phrase
+            }
+            FieldItem::Special(special) => format!("in {special}"),
+        })
+        .collect();
+    Some(join_in(&phrases))
+}
+
+/// Returns the value of a field that holds a single value.
+fn single_value(spec: &FieldSpec) -> Option<u32> {
+    match values(spec)?.as_slice() {
+        [value] => Some(*value),
+        _ => None,
+    }
+}
+
+/// Returns the values of a field that only lists single values.
+fn values(spec: &FieldSpec) -> Option<Vec<u32>> {
+    let FieldSpec::List(items) = spec else {
+        return None;
+    };
+    items
+        .iter()
+        .map(|item| match item {
+            FieldItem::Value(value) => Some(*value),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Renders a value or specifier on its own.
+fn item_value(item: &FieldItem) -> String {
+    match item {
+        FieldItem::Value(value) => value.to_string(),
+        FieldItem::Special(special) => special.clone(),
+        FieldItem::Range { start, end } => format!("{start}-{end}"),
+        FieldItem::Step { step, .. } => format!("every {step}"),
+    }
+}
+
+fn every(step: u32, unit: &str) -> String {
+    if step == 1 {
+        format!("every {unit}")
+    } else {
+        format!("every {step} {unit}s")
+    }
+}
+
+fn clock(hour: u32, minute: u32, second: u32) -> String {
+    if second == 0 {
+        format!("{hour:02}:{minute:02}")
+    } else {
+        format!("{hour:02}:{minute:02}:{second:02}")
+    }
+}
+
+fn ordinal(day: u32) -> String {
+    let suffix = match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{day}{suffix}")
+}
+
+const fn day_name(day: u32) -> &'static str {
+    DAY_NAMES[(day as usize + 6) % 7]
+}
+
+const fn month_name(month: u32) -> &'static str {
+    MONTH_NAMES[(month as usize + 11) % 12]
+}
+
+/// Reads a day of the week written as a name (`FRI`) or a Quartz number (`6`).
+fn parse_day(day: &str) -> Option<u32> {
+    day.parse().ok().or_else(|| {
+        DAY_NAMES
+            .iter()
+            .position(|name| name[..3].eq_ignore_ascii_case(day))
+            .and_then(|index| u32::try_from(index + 1).ok())
+    })
+}
+
+/// Joins phrases into an English list ("a, b and c").
+fn join(phrases: &[String]) -> String {
+    match phrases {
+        [] => String::new(),
+        [phrase] => phrase.clone(),
+        [init @ .., last] => format!("{} and {last}", init.join(", ")),
+    }
+}
+
+/// Joins phrases that each start with "in", keeping only the first "in" ("in January and
+/// July").
+fn join_in(phrases: &[String]) -> String {
+    let phrases: Vec<_> = phrases
+        .iter()
+        .enumerate()
+        .map(|(index, phrase)| match phrase.strip_prefix("in ") {
+            Some(rest) if index > 0 => rest.to_string(),
+            _ => phrase.clone(),
+        })
+        .collect();
+    join(&phrases)
+}
@@ -0,0 +1,147 @@
+//! This module names the cron dialects a [`Cron`] can be rendered in, and translates it
+//! into the classic five-field Unix format.
+//!
+//! Unix cron has no seconds or year field and none of the Quartz `L`, `W`, or `#`
+//! specifiers. Days of the week are numbered from 0 (Sunday) rather than from 1, and a
+//! step needs a range or `*` before it (`0/15` becomes `0-59/15`).
+
+use super::{
+    cron::{Cron, FieldPosition, Syntax},
+    spec::{self, FieldItem, FieldSpec},
+    Error, Result,
+};
+
+/// A cron dialect that a [`Cron`] can be rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CronDialect {
+    /// The seven-field Quartz format produced by [`Cron`]'s `Display`.
+    Quartz,
+    /// The five-field format read by crontab: minute, hour, day of month, month, and day
+    /// of week.
+    Unix,
+    /// The calendar event syntax of systemd timers.
+    Systemd,
+}
+
+impl std::fmt::Display for CronDialect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Quartz => write!(f, "Quartz"),
+            Self::Unix => write!(f, "Unix cron"),
+            Self::Systemd => write!(f, "systemd"),
+        }
+    }
+}
+
+/// The fields of a Unix cron expression, in order.
+const UNIX_FIELDS: [FieldPosition; 5] = [
+    FieldPosition::Minutes,
+    FieldPosition::Hour,
+    FieldPosition::DayOfMonth,
+    FieldPosition::Month,
+    FieldPosition::DayOfWeek,
+];
+
+impl Cron {
+    /// Renders the schedule in the given dialect.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DialectUnsupported`] if the schedule uses a feature the dialect
+    /// lacks, and [`Error::IncorrectValue`] if a field cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{Cron, CronDialect, Error};
+    ///
+    /// let cron = Cron::new("Run at 6:00 pm every Monday through Friday").unwrap();
+    /// assert_eq!(cron.to_dialect(CronDialect::Quartz).unwrap(), "0 0 18 ? * MON-FRI *");
+    /// assert_eq!(cron.to_dialect(CronDialect::Unix).unwrap(), "0 18 * * 1-5");
+    ///
+    /// let cron = Cron::new("on the last day of the month").unwrap();
+    /// assert!(matches!(
+    ///     cron.to_dialect(CronDialect::Unix),
+    ///     Err(Error::DialectUnsupported { dialect: CronDialect::Unix, .. })
+    /// ));
+    /// ```
+    pub fn to_dialect(&self, dialect: CronDialect) -> Result<String> {
+        match dialect {
+            CronDialect::Quartz => Ok(self.to_string()),
+            CronDialect::Unix => self.to_unix(),
+            CronDialect::Systemd => self.to_systemd_timer(),
+        }
+    }
+
+    /// Translates the schedule into a five-field Unix cron expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DialectUnsupported`] if the schedule fires on seconds other than
+    /// `0`, is restricted to some years, uses a Quartz-only specifier such as `L` or `#`,
+    /// or has a range that wraps around (`22-2`, `FRI-MON`).
+    pub fn to_unix(&self) -> Result<String> {
+        let parse = |field| spec::parse_field(field, self.syntax.quartz_field(field).trim());
+
+        if parse(FieldPosition::Seconds)? != FieldSpec::List(vec![FieldItem::Value(0)]) {
+            return Err(unsupported(FieldPosition::Seconds, &self.syntax.seconds));
+        }
+        if parse(FieldPosition::Year)? != FieldSpec::Any {
+            return Err(unsupported(FieldPosition::Year, &self.syntax.year));
+        }
+
+        let fields = UNIX_FIELDS
+            .into_iter()
+            .map(|field| unix_field(field, &parse(field)?, self.syntax.field(field)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(fields.join(" "))
+    }
+}
+
+/// Renders a single field for Unix cron, numbering days of the week from 0.
+fn unix_field(field: FieldPosition, spec: &FieldSpec, original: &str) -> Result<String> {
+    let items = match spec {
+        FieldSpec::Any | FieldSpec::NoSpecificValue => return Ok("*".to_string()),
+        FieldSpec::List(items) => items,
+    };
+    let value = |value: u32| {
+        if field == FieldPosition::DayOfWeek {
+            value - 1
+        } else {
+            value
+        }
+    };
+    let (_, max) = spec::bounds(field);
+
+    let mut rendered = Vec::new();
+    for item in items {
+        rendered.push(match item {
+            FieldItem::Value(number) => value(*number).to_string(),
+            FieldItem::Range { start, end } if start <= end => {
+                format!("{}-{}", value(*start), value(*end))
+            }
+            FieldItem::Step { start, end, step } => match (start, end) {
+                (None, _) => format!("*/{step}"),
+                (Some(start), end) => {
+                    format!("{}-{}/{step}", value(*start), value(end.unwrap_or(max)))
+                }
+            },
+            FieldItem::Range { .. } | FieldItem::Special(_) => {
+                return Err(unsupported(field, original));
+            }
+        });
+    }
+    Ok(rendered.join(","))
+}
+
+fn unsupported(field: FieldPosition, value: &str) -> Error {
+    Error::DialectUnsupported {
+        dialect: CronDialect::Unix,
+        feature: format!(
+            "{} in {}",
+            value.trim(),
+            Syntax::field_names()[field as usize]
+        ),
+    }
+}
@@ -7,11 +7,14 @@
 //! The module also provides a type alias `Result<T>` for convenience, defaulting to
 //! using the `Error` type as the error variant in the `std::result::Result`.
 
+use super::CronDialect;
+
 /// Represents the different kinds of errors that can occur in the "English to Corn" project.
 ///
 /// The variants capture specific error scenarios, such as invalid input or failed parsing
 /// operations, along with relevant state or context information.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Error {
     /// Error variant for invalid input.
     /// This variant is used when the input provided is not in a human-readable format.
@@ -37,6 +40,50 @@ pub enum Error {
     /// - `state`: The state in which the error occurred.
     /// - `error`: A description of the error or the reason why the value is considered invalid.
     IncorrectValue { state: String, error: String },
+    /// Error variant for expressions that cannot be rendered in a target dialect.
+    /// This occurs when the expression relies on a feature the dialect lacks, such as
+    /// the Quartz-only `L` or `#` specifiers.
+    ///
+    /// # Fields
+    /// - `dialect`: The dialect that was requested.
+    /// - `feature`: The unsupported feature and the field it appears in.
+    DialectUnsupported {
+        dialect: CronDialect,
+        feature: String,
+    },
+    /// Error variant for schedules whose year field lies entirely in the past.
+    /// This occurs when every year, or the end of every year range, is before the current year.
+    ///
+    /// # Fields
+    /// - `year`: The last year in which the schedule could have fired.
+    ScheduleInPast { year: i32 },
+    /// Error variant for inputs with more schedule-bearing clauses than allowed.
+    /// This guards against long texts whose later clauses would overwrite earlier ones.
+    ///
+    /// # Fields
+    /// - `clauses`: The number of schedule-bearing clauses found in the input.
+    TooComplex { clauses: usize },
+    /// Error variant for schedules that cron cannot express, even approximately.
+    /// This occurs for phrases such as "every other Monday", which count weeks across
+    /// month boundaries.
+    ///
+    /// # Fields
+    /// - `schedule`: The phrase that cannot be expressed.
+    /// - `suggestion`: A phrase that can be expressed and comes close to it.
+    UnsupportedSchedule {
+        schedule: String,
+        suggestion: String,
+    },
+    /// Error variant for phrases that can be read as more than one schedule.
+    /// Callers can offer the alternatives to the user and parse their choice instead.
+    ///
+    /// # Fields
+    /// - `input`: The ambiguous phrase.
+    /// - `alternatives`: The cron expressions the phrase could stand for.
+    AmbiguousInput {
+        input: String,
+        alternatives: Vec<String>,
+    },
 }
 
 /// Implements the `Display` trait for the `Error` enum.
@@ -59,6 +106,40 @@ impl std::fmt::Display for Error {
                     "value is invalid in state: {state}. description: {error} "
                 )
             }
+            Self::DialectUnsupported { dialect, feature } => {
+                write!(f, "{dialect} does not support: {feature}")
+            }
+            Self::ScheduleInPast { year } => {
+                write!(
+                    f,
+                    "schedule never fires: its last year {year} is in the past"
+                )
+            }
+            Self::TooComplex { clauses } => {
+                write!(
+                    f,
+                    "input has {clauses} schedule clauses, please simplify it or split it into several schedules"
+                )
+            }
+            Self::UnsupportedSchedule {
+                schedule,
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "{schedule} cannot be expressed in cron, consider {suggestion} instead"
+                )
+            }
+            Self::AmbiguousInput {
+                input,
+                alternatives,
+            } => {
+                write!(
+                    f,
+                    "{input} is ambiguous, it could mean any of: {}",
+                    alternatives.join(", ")
+                )
+            }
         }
     }
 }
@@ -0,0 +1,166 @@
+//! This module expands single cron fields into the values they match, so that two
+//! schedules can be compared.
+//!
+//! Two schedules overlap when every one of their seven fields shares at least one value.
+//! Fields are compared independently, so a day-of-month and a day-of-week that can never
+//! fall on the same date (e.g., the 31st and February) still count as overlapping.
+//!
+//! Quartz-specific items cannot be expanded without a calendar and are widened to every
+//! value they could stand for: `L` in the day-of-month field covers the 28th to the 31st,
+//! and `MON#2` or `MONL` cover every Monday.
+
+use std::collections::BTreeSet;
+
+use super::{
+    cron::Cron,
+    spec::{self, FieldItem, FieldSpec},
+    FieldPosition, Result,
+};
+
+/// The set of values a single cron field matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronField {
+    values: BTreeSet<u32>,
+}
+
+impl CronField {
+    /// Expands the value of a field (`*`, `?`, `0/15`, `1-5`, `MON,WED`, ...) into the
+    /// values it matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](super::Error) if the value cannot be parsed or lies outside
+    /// the field's allowed range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{CronField, FieldPosition};
+    ///
+    /// let quarter_hours = CronField::parse(FieldPosition::Minutes, "0/15").unwrap();
+    /// let half_hours = CronField::parse(FieldPosition::Minutes, "30").unwrap();
+    /// assert!(quarter_hours.intersects(&half_hours));
+    /// ```
+    pub fn parse(field: FieldPosition, value: &str) -> Result<Self> {
+        let (min, max) = spec::bounds(field);
+        let items = match spec::parse_field(field, &value.trim().to_uppercase())? {
+            FieldSpec::Any | FieldSpec::NoSpecificValue => {
+                return Ok(Self {
+                    values: (min..=max).collect(),
+                })
+            }
+            FieldSpec::List(items) => items,
+        };
+
+        let mut values = BTreeSet::new();
+        for item in items {
+            match item {
+                FieldItem::Value(value) => {
+                    values.insert(value);
+                }
+                FieldItem::Range { start, end } if start <= end => values.extend(start..=end),
+                // Ranges such as `FRI-MON` or `22-2` wrap around the end of the field
+                FieldItem::Range { start, end } => {
+                    values.extend(start..=max);
+                    values.extend(min..=end);
+                }
+                FieldItem::Step { start, end, step } => {
+                    let (start, end) = (start.unwrap_or(min), end.unwrap_or(max));
+                    let step = usize::try_from(step).unwrap_or(usize::MAX);
+                    if start <= end {
+                        values.extend((start..=end).step_by(step));
+                    } else {
+                        // Steps such as `22-2/2` continue from the start of the field
+                        let span = max - min + 1;
+                        values.extend((start..=end + span).step_by(step).map(|value| {
+                            if value > max {
+                                value - span
+                            } else {
+                                value
+                            }
+                        }));
+                    }
+                }
+                FieldItem::Special(special) => {
+                    values.extend(expand_special(field, &special)?);
+                }
+            }
+        }
+        Ok(Self { values })
+    }
+
+    /// Returns whether both fields match at least one common value.
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.values.is_disjoint(&other.values)
+    }
+}
+
+/// Widens a Quartz `L`, `W`, or `#` item to every value it could fall on.
+fn expand_special(field: FieldPosition, special: &str) -> Result<Vec<u32>> {
+    if field == FieldPosition::DayOfWeek {
+        // `L` on its own is the last day of the week, Saturday
+        let day = match special.split_once('#') {
+            Some((day, _)) => day,
+            None if special == "L" => "SAT",
+            None => special.trim_end_matches('L'),
+        };
+        return Ok(vec![spec::parse_value(field, day)?]);
+    }
+
+    let days = match special {
+        "L" => 28..=31,
+        "LW" => 26..=31,
+        _ => match (special.strip_prefix("L-"), special.strip_suffix('W')) {
+            (Some(offset), _) => {
+                let offset = spec::parse_value(field, offset).unwrap_or(0);
+                28u32.saturating_sub(offset).max(1)..=31u32.saturating_sub(offset).max(1)
+            }
+            // The nearest weekday is at most two days away from the given day
+            (None, Some(day)) => {
+                let day = spec::parse_value(field, day)?;
+                day.saturating_sub(2).max(1)..=(day + 2).min(31)
+            }
+            (None, None) => {
+                let day = spec::parse_value(field, special)?;
+                day..=day
+            }
+        },
+    };
+    Ok(days.collect())
+}
+
+impl Cron {
+    /// Returns whether both schedules fire at least once at the same moment.
+    ///
+    /// Each of the seven fields is compared on its own: the schedules overlap when every
+    /// field of `self` shares a value with the same field of `other`. A field that cannot
+    /// be parsed is treated as matching every value, so conflicts are never missed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// let every_quarter_hour = Cron::new("every 15 minutes").unwrap();
+    /// let monday_morning = Cron::new("every Monday at 9:30am").unwrap();
+    /// let monday_evening = Cron::new("every Monday at 9:10pm").unwrap();
+    ///
+    /// assert!(every_quarter_hour.overlaps_with(&monday_morning));
+    /// assert!(!every_quarter_hour.overlaps_with(&monday_evening));
+    /// ```
+    #[must_use]
+    pub fn overlaps_with(&self, other: &Self) -> bool {
+        let expand = |cron: &Self, field| {
+            CronField::parse(field, &cron.syntax.quartz_field(field)).unwrap_or_else(|_| {
+                let (min, max) = spec::bounds(field);
+                CronField {
+                    values: (min..=max).collect(),
+                }
+            })
+        };
+        FieldPosition::ALL
+            .into_iter()
+            .all(|field| expand(self, field).intersects(&expand(other, field)))
+    }
+}
@@ -0,0 +1,185 @@
+//! This module reads ISO 8601 durations (e.g., `PT15M`) as repeating schedules.
+//!
+//! A duration has the form `P[n]Y[n]M[n]W[n]D[T[n]H[n]M[n]S]`, where the `M` before the
+//! `T` counts months and the one after it counts minutes. A cron expression can only
+//! repeat one unit, so exactly one component may be non-zero: `PT1H30M` has no
+//! equivalent. Seconds, minutes and hours longer than their field are carried into the
+//! next unit the same way as in English (`PT120M` runs every 2 hours).
+
+use super::re::Regex;
+use super::{
+    action::day,
+    cron::{self, Cron, FieldPosition},
+    Error, Result, ScheduleSpec, Warning,
+};
+use std::sync::LazyLock;
+
+/// Matches an ISO 8601 duration, capturing years, months, weeks, days, hours, minutes
+/// and seconds in that order.
+static RE_DURATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^P(?:([0-9]+)Y)?(?:([0-9]+)M)?(?:([0-9]+)W)?(?:([0-9]+)D)?(?:T(?:([0-9]+)H)?(?:([0-9]+)M)?(?:([0-9]+)S)?)?$").unwrap()
+});
+
+/// The unit of each capture of [`RE_DURATION`].
+#[derive(Debug, Clone, Copy)]
+enum Unit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+const UNITS: [Unit; 7] = [
+    Unit::Year,
+    Unit::Month,
+    Unit::Week,
+    Unit::Day,
+    Unit::Hour,
+    Unit::Minute,
+    Unit::Second,
+];
+
+impl Cron {
+    /// Creates a `Cron` that repeats every ISO 8601 duration (e.g., `PT15M` for every 15
+    /// minutes or `P1D` for every day).
+    ///
+    /// Schedules start at the beginning of their unit, the same way as the equivalent
+    /// English: `P1D` runs at midnight, `P1M` at midnight on the 1st, and `P1W` at
+    /// midnight on Sunday.
+    ///
+    /// Repetitions of more than one week are approximated in the day-of-month field and
+    /// reported with a [`Warning::ApproximatedPeriod`](super::Warning::ApproximatedPeriod),
+    /// like "every 2 weeks".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the duration is malformed, is zero, combines
+    /// several units (e.g., `P1Y6M`), or repeats more slowly than its field allows
+    /// (e.g., `P32D`). Returns [`Error::UnsupportedSchedule`] if seconds, minutes or hours
+    /// are not a whole number of the next unit (e.g., `PT90M`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// assert_eq!(Cron::from_iso8601("PT15M").unwrap().to_string(), "0 0/15 * * * ? *");
+    /// assert_eq!(Cron::from_iso8601("P1D").unwrap().to_string(), "0 0 0 */1 * ? *");
+    /// assert_eq!(Cron::from_iso8601("PT60M").unwrap().to_string(), "0 0 0/1 * * ? *");
+    /// assert!(Cron::from_iso8601("PT1H30M").is_err());
+    /// ```
+    pub fn from_iso8601(duration: &str) -> Result<Self> {
+        let captures = RE_DURATION
+            .captures(duration.trim())
+            .ok_or(Error::InvalidInput)?;
+
+        let mut components = Vec::new();
+        for (unit, capture) in UNITS.into_iter().zip(captures.iter().skip(1)) {
+            let Some(capture) = capture else { continue };
+            // A component too large to read repeats more slowly than any field allows
+            let value = capture
+                .as_str()
+                .parse::<u32>()
+                .map_err(|_| Error::InvalidInput)?;
+            if value > 0 {
+                components.push((unit, value));
+            }
+        }
+
+        let [(unit, value)] = components.as_slice() else {
+            return Err(Error::InvalidInput);
+        };
+        let mut cron = repeat(*unit, *value)?;
+        cron::carry_intervals(&mut cron.syntax)?;
+        // A carried repetition may still be longer than the days of the month
+        ScheduleSpec::try_from(&cron.syntax).map_err(|_| Error::InvalidInput)?;
+        if let (Unit::Week, Ok(weeks @ 2..)) = (unit, i32::try_from(*value)) {
+            cron.warnings.push(Warning::ApproximatedPeriod {
+                phrase: duration.trim().to_string(),
+                approximation: day::days_of_weeks(weeks),
+            });
+        }
+        Ok(cron)
+    }
+}
+
+/// Builds the schedule that repeats every `value` of `unit`.
+fn repeat(unit: Unit, value: u32) -> Result<Cron> {
+    let step = |start: &str| format!("{start}/{value}");
+    // Seconds, minutes and hours have no limit here, since they are carried afterwards
+    let (fields, max) = match unit {
+        Unit::Second => (
+            vec![
+                (FieldPosition::Seconds, step("0")),
+                (FieldPosition::Minutes, "*".to_string()),
+            ],
+            u32::MAX,
+        ),
+        Unit::Minute => (vec![(FieldPosition::Minutes, step("0"))], u32::MAX),
+        Unit::Hour => (
+            vec![
+                (FieldPosition::Minutes, "0".to_string()),
+                (FieldPosition::Hour, step("0")),
+            ],
+            u32::MAX,
+        ),
+        Unit::Day => (midnight(&[(FieldPosition::DayOfMonth, step("*"))]), 31),
+        // A single week is anchored on Sunday; longer ones count days, like "every 2 weeks"
+        Unit::Week if value == 1 => (
+            midnight(&[(FieldPosition::DayOfWeek, "SUN".to_string())]),
+            1,
+        ),
+        Unit::Week => (
+            midnight(&[(FieldPosition::DayOfMonth, format!("*/{}", value * 7))]),
+            4,
+        ),
+        Unit::Month => (
+            midnight(&[
+                (FieldPosition::DayOfMonth, "1".to_string()),
+                (FieldPosition::Month, step("*")),
+            ]),
+            12,
+        ),
+        Unit::Year => (
+            midnight(&[
+                (FieldPosition::DayOfMonth, "1".to_string()),
+                (FieldPosition::Month, "1".to_string()),
+                (
+                    FieldPosition::Year,
+                    if value == 1 {
+                        "*".to_string()
+                    } else {
+                        step("*")
+                    },
+                ),
+            ]),
+            2099 - 1970,
+        ),
+    };
+    if value > max {
+        return Err(Error::InvalidInput);
+    }
+
+    let mut cron = Cron::default();
+    for (field, value) in fields {
+        *cron.syntax.field_mut(field) = value;
+        cron.syntax.mark_explicit(field);
+    }
+    if cron.syntax.is_explicit(FieldPosition::DayOfWeek) {
+        cron.syntax.day_of_month = "?".to_string();
+    }
+    Ok(cron)
+}
+
+/// Returns the fields of a schedule that runs at midnight, followed by `days`.
+fn midnight(days: &[(FieldPosition, String)]) -> Vec<(FieldPosition, String)> {
+    let mut fields = vec![
+        (FieldPosition::Minutes, "0".to_string()),
+        (FieldPosition::Hour, "0".to_string()),
+    ];
+    fields.extend_from_slice(days);
+    fields
+}
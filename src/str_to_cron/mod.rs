@@ -1,9 +1,29 @@
 mod action;
+mod builder;
 mod cron;
+mod describe;
+mod dialect;
 mod errors;
+mod field;
+mod iso8601;
+mod multi_cron;
+mod options;
+mod re;
+mod simplify;
+mod spec;
 mod stack;
+mod systemd;
 mod tokens;
+mod validate;
+mod warning;
 
-pub use cron::Cron;
+pub use builder::{CronBuilder, Month, Weekday};
+pub use cron::{Cron, CronVerbose, FieldPosition, Syntax};
+pub use dialect::CronDialect;
 pub use errors::{Error, Result};
+pub use field::CronField;
+pub use multi_cron::MultiCron;
+pub use options::{Clock, DayOfWeekFormat, Meridiem, MonthFormat, Options, WeekStart};
+pub use spec::{FieldItem, FieldSpec, ScheduleSpec};
 pub use tokens::Tokenizer;
+pub use warning::Warning;
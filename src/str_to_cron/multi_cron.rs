@@ -0,0 +1,155 @@
+//! This module provides [`MultiCron`], a set of cron expressions that together describe
+//! one English phrase.
+//!
+//! A single cron line can list several hours, but all of them share one minute field.
+//! "every day at 9:30am and 5pm" therefore needs two expressions: `0 30 9 */1 * ? *` and
+//! `0 0 17 */1 * ? *`. Listed clock times are grouped by minute and each group becomes
+//! one [`Cron`], so times that do share a minute stay in a single expression.
+
+use super::{
+    action::{self, Kind},
+    cron::{self, Cron},
+    Options, Result, Tokenizer,
+};
+
+/// One or more cron expressions produced from a single English phrase.
+#[derive(Debug, Default)]
+pub struct MultiCron(pub Vec<Cron>);
+
+impl MultiCron {
+    /// Parses an English phrase into as many cron expressions as it needs.
+    ///
+    /// When the phrase fits in a single cron expression, the result holds exactly one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`](super::Error) if the phrase cannot be parsed, even after
+    /// splitting its listed clock times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::MultiCron;
+    ///
+    /// let multi = MultiCron::new("every day at 9:30am and 5pm").unwrap();
+    /// assert_eq!(multi.to_string(), "0 30 9 */1 * ? *\n0 0 17 */1 * ? *");
+    /// ```
+    pub fn new(text: &str) -> Result<Self> {
+        Self::with_options(text, &Options::default())
+    }
+
+    /// Parses an English phrase into as many cron expressions as it needs, using the
+    /// given [`Options`] for each of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`MultiCron::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{MonthFormat, MultiCron, Options};
+    ///
+    /// let mut options = Options::default();
+    /// options.month_format = MonthFormat::Numeric;
+    /// let multi = MultiCron::with_options("in June at 9:30am and 5pm", &options).unwrap();
+    /// assert_eq!(multi.to_string(), "0 30 9 * 6 ? *\n0 0 17 * 6 ? *");
+    /// ```
+    pub fn with_options(text: &str, options: &Options) -> Result<Self> {
+        let tokenizer = Tokenizer::new();
+        cron::reject_ambiguous(text, &tokenizer, |text| {
+            Self::with_options(text, options).map(|multi| multi.to_string())
+        })?;
+        Self::from_tokens(&tokenizer.run(text), options)
+    }
+
+    /// Builds the cron expressions for a list of tokens, splitting listed clock times
+    /// by minute when they do not fit in a single expression.
+    pub(crate) fn from_tokens(tokens: &[String], options: &Options) -> Result<Self> {
+        let error = match Cron::from_tokens(tokens, options) {
+            Ok(cron) => return Ok(Self(vec![cron])),
+            Err(error) => error,
+        };
+
+        let states: Vec<_> = tokens
+            .iter()
+            .map(|token| action::try_from_token(token))
+            .collect();
+        let times: Vec<usize> = (0..tokens.len())
+            .filter(|&index| states[index] == Some(Kind::ClockTime))
+            .collect();
+        if times.len() < 2 {
+            return Err(error);
+        }
+
+        // Group the listed times by the minute each one resolves to.
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        for &index in &times {
+            let minute = Cron::from_tokens(&tokens[index..=index], options)?
+                .syntax
+                .min;
+            match groups
+                .iter_mut()
+                .find(|(group_minute, _)| *group_minute == minute)
+            {
+                Some((_, group)) => group.push(index),
+                None => groups.push((minute, vec![index])),
+            }
+        }
+
+        let is_time = |index: usize| times.contains(&index);
+        let joins_times = |index: usize| {
+            tokens[index].eq_ignore_ascii_case("and")
+                && (index.checked_sub(1).is_some_and(is_time) || is_time(index + 1))
+        };
+
+        let mut crons = Vec::new();
+        for (_, group) in groups {
+            let mut group_tokens = Vec::new();
+            for (index, token) in tokens.iter().enumerate() {
+                if index == times[0] {
+                    for (position, &time) in group.iter().enumerate() {
+                        if position > 0 {
+                            group_tokens.push("and".to_string());
+                        }
+                        group_tokens.push(tokens[time].clone());
+                    }
+                } else if !is_time(index) && !joins_times(index) {
+                    group_tokens.push(token.clone());
+                }
+            }
+            crons.push(Cron::from_tokens(&group_tokens, options)?);
+        }
+
+        Ok(Self(crons))
+    }
+}
+
+impl std::fmt::Display for MultiCron {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, cron) in self.0.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{cron}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::Deref for MultiCron {
+    type Target = [Cron];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for MultiCron {
+    type Item = Cron;
+    type IntoIter = std::vec::IntoIter<Cron>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
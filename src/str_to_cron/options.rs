@@ -0,0 +1,157 @@
+//! Options that adjust how a [`Cron`](super::Cron) is parsed and checked.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The default cap on schedule-bearing clauses in a single input.
+pub const DEFAULT_MAX_CLAUSES: usize = 8;
+
+/// The default first and last hour of "business hours", 9:00 to 17:00.
+pub const DEFAULT_BUSINESS_HOURS: (u8, u8) = (9, 17);
+
+/// Settings for [`Cron::with_options`](super::Cron::with_options) and
+/// [`Cron::validate_with`](super::Cron::validate_with).
+///
+/// New settings may be added in minor releases, so start from [`Options::default`] and
+/// change the fields you need:
+///
+/// ```rust
+/// use english_to_cron::{Options, WeekStart};
+///
+/// let mut options = Options::default();
+/// options.week_start = WeekStart::Sunday;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Options {
+    /// Whether a year field that lies entirely in the past is rejected. On by default.
+    pub reject_past_years: bool,
+    /// The clock used to decide which years are in the past.
+    pub clock: Clock,
+    /// The largest number of schedule-bearing clauses (e.g., "every 5 minutes", "on Monday",
+    /// "at 9am") accepted in one input. Connectors such as "between" or "and" do not count.
+    pub max_clauses: usize,
+    /// The first and last hour, on a 24-hour clock, that "business hours", "office hours"
+    /// and "working hours" stand for. Defaults to 9 to 17. Input that mentions business
+    /// hours is rejected unless both are from 0 to 23 and the first is no later than the
+    /// last.
+    pub business_hours: (u8, u8),
+    /// The day that lists of days of the week begin with. Defaults to Monday, so
+    /// "on Sunday and Monday" is `MON,SUN`.
+    pub week_start: WeekStart,
+    /// How days of the week are written in the expression. Defaults to names (`SUN`).
+    pub day_of_week_format: DayOfWeekFormat,
+    /// How months are written in the expression. Defaults to names (`SEP`).
+    pub month_format: MonthFormat,
+    /// How an hour given without "am" or "pm" is read ("at 7", "7 o'clock"). Defaults to
+    /// the 24-hour clock.
+    pub default_meridiem: Meridiem,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            reject_past_years: true,
+            clock: Clock::System,
+            max_clauses: DEFAULT_MAX_CLAUSES,
+            business_hours: DEFAULT_BUSINESS_HOURS,
+            week_start: WeekStart::Monday,
+            day_of_week_format: DayOfWeekFormat::Names,
+            month_format: MonthFormat::Names,
+            default_meridiem: Meridiem::TwentyFourHour,
+        }
+    }
+}
+
+/// The first day of the week, which orders listed days of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WeekStart {
+    /// Days are listed from Monday to Sunday (`MON,WED,SUN`).
+    #[default]
+    Monday,
+    /// Days are listed from Sunday to Saturday (`SUN,MON,WED`).
+    Sunday,
+}
+
+/// How days of the week are written, since cron dialects number them differently.
+///
+/// Numbers are written as the chosen dialect counts them, and a range that would run
+/// backwards in that count is written as a list (`5,6,0` for Friday through Sunday from
+/// Sunday as 0). [`Cron::describe`] and [`Cron::to_dialect`] still read them as the days
+/// they stand for.
+///
+/// [`Cron::describe`]: super::Cron::describe
+/// [`Cron::to_dialect`]: super::Cron::to_dialect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DayOfWeekFormat {
+    /// Three-letter names (`SUN`, `MON`).
+    #[default]
+    Names,
+    /// Numbers from Sunday as 0 to Saturday as 6, as in Unix cron.
+    ZeroBasedSunday0,
+    /// Numbers from Monday as 1 to Sunday as 7.
+    ZeroBasedSunday7,
+    /// Numbers from Monday as 0 to Sunday as 6.
+    SixBasedMon0,
+}
+
+/// How months are written, for dialects that only accept numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MonthFormat {
+    /// Three-letter names (`JAN`, `SEP`).
+    #[default]
+    Names,
+    /// Numbers from January as 1 to December as 12.
+    Numeric,
+}
+
+/// How an hour from 1 to 12 given without "am" or "pm" is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Meridiem {
+    /// "at 7" is 7:00 in the morning.
+    Am,
+    /// "at 7" is 7:00 in the evening.
+    Pm,
+    /// "at 7" is 7:00 and "at 19" is 19:00.
+    #[default]
+    TwentyFourHour,
+}
+
+/// The source of the current year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Clock {
+    /// The system clock, in UTC.
+    #[default]
+    System,
+    /// A fixed year, which keeps checks deterministic in tests.
+    Fixed(i32),
+}
+
+impl Clock {
+    /// Returns the current year according to this clock.
+    #[must_use]
+    pub fn current_year(self) -> i32 {
+        match self {
+            Self::System => {
+                let seconds = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_secs());
+                year_from_days(i64::try_from(seconds / 86_400).unwrap_or_default())
+            }
+            Self::Fixed(year) => year,
+        }
+    }
+}
+
+/// Converts a day count since 1970-01-01 into its year in the proleptic Gregorian calendar.
+fn year_from_days(days: i64) -> i32 {
+    // Shift the epoch to 0000-03-01 so that leap days fall at the end of each year.
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let year = year_of_era + era * 400 + i64::from(month >= 10);
+    i32::try_from(year).unwrap_or(i32::MAX)
+}
@@ -0,0 +1,15 @@
+//! The regular expression engine used to read English text.
+//!
+//! This is the `regex` crate by default. With the `regex-lite` feature it is `regex-lite`
+//! instead, which is much smaller in a binary, such as a wasm build, at the cost of slower
+//! matching. The patterns in this crate only rely on syntax that both accept, and the
+//! phrases they read are ASCII, so the two engines read them the same way. When both
+//! features are enabled, `regex-lite` is used.
+
+#[cfg(not(any(feature = "regex", feature = "regex-lite")))]
+compile_error!("either the `regex` or the `regex-lite` feature must be enabled");
+
+#[cfg(all(feature = "regex", not(feature = "regex-lite")))]
+pub use regex::{escape, Captures, Regex};
+#[cfg(feature = "regex-lite")]
+pub use regex_lite::{escape, Captures, Regex};
@@ -0,0 +1,229 @@
+//! This module rewrites a [`Cron`] into the simplest expression with the same schedule.
+//!
+//! Steps of 1 become `*` or a range, steps too large to repeat within their field become
+//! the single value they fire on (`0/60` in the minutes is `0`), and runs of consecutive
+//! values become ranges (`MON,TUE,WED,THU,FRI` is `MON-FRI`). A field keeps the way it
+//! was written, so `2,3,4,5,6` in the days of the week is `2-6`.
+//!
+//! Two schedules are compared by the values each field matches, so that `==` on [`Cron`]
+//! compares what they run on rather than how they are written.
+
+use super::{
+    cron::{Cron, FieldPosition},
+    field::CronField,
+    spec::{self, FieldItem, FieldSpec},
+};
+
+/// The shortest run of consecutive values that is collapsed into a range.
+const MIN_RUN: u32 = 3;
+
+impl Cron {
+    /// Returns an equivalent schedule written with the simplest expression for each field.
+    ///
+    /// Fields that are already as simple as they can be, or that cannot be parsed, are
+    /// kept as they are. Simplified months and days of the week are written with names,
+    /// unless the field was written with numbers only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// let cron = Cron::parse_cron("0 0/60 */1 * * ? *").unwrap();
+    /// assert_eq!(cron.simplify().to_string(), "0 0 * * * ? *");
+    ///
+    /// let cron = Cron::parse_cron("0 0 9 ? * MON,TUE,WED,THU,FRI *").unwrap();
+    /// assert_eq!(cron.simplify().to_string(), "0 0 9 ? * MON-FRI *");
+    /// ```
+    #[must_use]
+    pub fn simplify(&self) -> Self {
+        let mut cron = Self::default();
+        for field in FieldPosition::ALL {
+            let value = self.syntax.quartz_field(field);
+            let value = value.trim();
+            *cron.syntax.field_mut(field) = match spec::parse_field(field, value) {
+                Ok(FieldSpec::List(items)) => {
+                    let simplified = simplify_items(field, &items);
+                    if simplified == FieldSpec::List(items) {
+                        value.to_string()
+                    } else if value.bytes().any(|byte| byte.is_ascii_alphabetic()) {
+                        spec::render_field(field, &simplified)
+                    } else {
+                        spec::render_numeric_field(field, &simplified)
+                    }
+                }
+                _ => value.to_string(),
+            };
+            if self.syntax.is_explicit(field) {
+                cron.syntax.mark_explicit(field);
+            }
+        }
+        cron.syntax.format_days(self.syntax.day_of_week_format);
+        cron.warnings.clone_from(&self.warnings);
+        cron
+    }
+
+    /// Returns whether two schedules run at the same times, however they are written.
+    ///
+    /// Each field is compared by the set of values it matches, so `0 0 */1 * * ? *` equals
+    /// `0 0 * * * ? *`, `MON-FRI` equals `2-6`, `MON,WED,FRI` equals `FRI,MON,WED`, `0/12`
+    /// in the hours equals `0,12`, and `?` equals `*`. Quartz-specific items (`L`, `W`,
+    /// `#`) depend on the calendar, so fields holding them are compared after simplifying,
+    /// and fields that cannot be parsed are compared as written. This is what `==`
+    /// compares; use [`Cron::eq_literal`] to compare the expressions themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// let hourly = Cron::parse_cron("0 0 */1 * * ? *").unwrap();
+    /// let every_hour = Cron::parse_cron("0 0 * * * ? *").unwrap();
+    /// assert!(hourly.eq_semantically(&every_hour));
+    /// assert!(!hourly.eq_literal(&every_hour));
+    /// ```
+    #[must_use]
+    pub fn eq_semantically(&self, other: &Self) -> bool {
+        let (left, right) = (self.simplify(), other.simplify());
+        FieldPosition::ALL.into_iter().all(|field| {
+            let (left, right) = (
+                left.syntax.quartz_field(field),
+                right.syntax.quartz_field(field),
+            );
+            let (left, right) = (left.trim(), right.trim());
+            if let (Some(left), Some(right)) = (values(field, left), values(field, right)) {
+                return left == right;
+            }
+            match (
+                spec::parse_field(field, left),
+                spec::parse_field(field, right),
+            ) {
+                (Ok(left), Ok(right)) => unrestricted(left) == unrestricted(right),
+                _ => left == right,
+            }
+        })
+    }
+
+    /// Returns whether two schedules are written with the same expression, field by field.
+    ///
+    /// Unlike `==`, `0 0 */1 * * ? *` and `0 0 * * * ? *` are not equal here.
+    #[must_use]
+    pub fn eq_literal(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+/// Compares schedules with [`Cron::eq_semantically`].
+impl PartialEq for Cron {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_semantically(other)
+    }
+}
+
+impl Eq for Cron {}
+
+/// Expands a field into the values it matches, or `None` if it cannot be parsed or holds a
+/// Quartz-specific item, which [`CronField`] widens to every value it could fall on.
+fn values(field: FieldPosition, value: &str) -> Option<CronField> {
+    match spec::parse_field(field, &value.to_uppercase()).ok()? {
+        FieldSpec::List(items)
+            if items
+                .iter()
+                .any(|item| matches!(item, FieldItem::Special(_))) =>
+        {
+            None
+        }
+        _ => CronField::parse(field, value).ok(),
+    }
+}
+
+/// Reads `?` as `*`, since both leave a field unrestricted.
+fn unrestricted(spec: FieldSpec) -> FieldSpec {
+    match spec {
+        FieldSpec::NoSpecificValue => FieldSpec::Any,
+        spec => spec,
+    }
+}
+
+/// Simplifies the items of a list, returning [`FieldSpec::Any`] if they cover the whole
+/// field.
+fn simplify_items(field: FieldPosition, items: &[FieldItem]) -> FieldSpec {
+    let (min, max) = spec::bounds(field);
+
+    let mut simplified: Vec<FieldItem> = Vec::new();
+    for item in items {
+        let item = simplify_item(item, min, max);
+        if item
+            == (FieldItem::Range {
+                start: min,
+                end: max,
+            })
+        {
+            return FieldSpec::Any;
+        }
+
+        // Extend a run of consecutive values ("1,2,3") that ends in this one
+        let run = simplified
+            .iter()
+            .rev()
+            .zip(1..)
+            .take_while(|(previous, offset)| match (previous, &item) {
+                (FieldItem::Value(previous), FieldItem::Value(value)) => {
+                    value.checked_sub(*offset) == Some(*previous)
+                }
+                _ => false,
+            })
+            .count();
+        let length = u32::try_from(run).unwrap_or(u32::MAX);
+        match (&item, simplified.last()) {
+            (FieldItem::Value(value), Some(FieldItem::Range { start, end }))
+                if *end + 1 == *value && *start + MIN_RUN <= *end + 1 =>
+            {
+                let start = *start;
+                simplified.pop();
+                simplified.push(FieldItem::Range { start, end: *value });
+            }
+            (FieldItem::Value(value), _) if length >= MIN_RUN - 1 => {
+                simplified.truncate(simplified.len() - run);
+                simplified.push(FieldItem::Range {
+                    start: *value - length,
+                    end: *value,
+                });
+            }
+            _ => simplified.push(item),
+        }
+    }
+
+    if simplified
+        == [FieldItem::Range {
+            start: min,
+            end: max,
+        }]
+    {
+        return FieldSpec::Any;
+    }
+    FieldSpec::List(simplified)
+}
+
+/// Simplifies a single item of a field that runs from `min` to `max`.
+fn simplify_item(item: &FieldItem, min: u32, max: u32) -> FieldItem {
+    match *item {
+        FieldItem::Range { start, end } if start == end => FieldItem::Value(start),
+        FieldItem::Step { start, end, step } => {
+            let start = start.unwrap_or(min);
+            let end = end.unwrap_or(max);
+            if start > end {
+                // A range that wraps around ("22-2/2") is kept as written
+                item.clone()
+            } else if start + step > end {
+                // The step never repeats within the field ("0/60" in the minutes)
+                FieldItem::Value(start)
+            } else if step == 1 {
+                FieldItem::Range { start, end }
+            } else {
+                item.clone()
+            }
+        }
+        _ => item.clone(),
+    }
+}
@@ -0,0 +1,378 @@
+//! A typed, scheduler-agnostic model of a cron schedule.
+//!
+//! [`ScheduleSpec`] can be built from either an English description or a cron
+//! expression. Both go through the same validation, so the two input forms end up
+//! as the same value and can be rendered back out through [`Cron`].
+
+use std::fmt::Write as _;
+
+use super::{cron::Syntax, Cron, Error, FieldPosition, Result};
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// Day names in Quartz order, where `SUN` is 1.
+const DAYS_OF_WEEK: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// A schedule broken down into typed fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleSpec {
+    pub seconds: FieldSpec,
+    pub minutes: FieldSpec,
+    pub hour: FieldSpec,
+    pub day_of_month: FieldSpec,
+    pub month: FieldSpec,
+    pub day_of_week: FieldSpec,
+    pub year: FieldSpec,
+}
+
+/// The value of a single field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldSpec {
+    /// Every value (`*`).
+    Any,
+    /// No specific value (`?`), used by the day-of-month and day-of-week fields.
+    NoSpecificValue,
+    /// A comma-separated list of items.
+    List(Vec<FieldItem>),
+}
+
+/// One item of a [`FieldSpec::List`].
+///
+/// Months and days of the week are stored as numbers: `JAN` is 1 and, following
+/// Quartz, `SUN` is 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldItem {
+    /// A single value (`5`).
+    Value(u32),
+    /// An inclusive range (`1-5`).
+    Range { start: u32, end: u32 },
+    /// A repetition (`*/15`, `0/15`, `0-29/5`). A `start` of `None` stands for `*`.
+    Step {
+        start: Option<u32>,
+        end: Option<u32>,
+        step: u32,
+    },
+    /// A Quartz-specific item kept verbatim, such as `L`, `15W`, or `MON#2`.
+    Special(String),
+}
+
+impl ScheduleSpec {
+    /// Parses an English description of a schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the text cannot be parsed or produces a field value
+    /// outside its allowed range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::ScheduleSpec;
+    ///
+    /// assert_eq!(
+    ///     ScheduleSpec::from_english("every day at 4:00 pm").unwrap(),
+    ///     ScheduleSpec::from_cron("0 0 16 */1 * ? *").unwrap(),
+    /// );
+    /// ```
+    pub fn from_english(text: &str) -> Result<Self> {
+        Self::try_from(&Cron::new(text)?.syntax)
+    }
+
+    /// Parses a cron expression with six or seven fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the expression has the wrong number of fields or a
+    /// field value cannot be parsed or is outside its allowed range.
+    pub fn from_cron(expression: &str) -> Result<Self> {
+        let spec = Self::try_from(&expression.parse::<Syntax>()?)?;
+        spec.check_day_fields()?;
+        Ok(spec)
+    }
+
+    /// Checks that exactly one of the day fields is `?`, as Quartz requires of an
+    /// expression.
+    pub(super) fn check_day_fields(&self) -> Result<()> {
+        match (&self.day_of_month, &self.day_of_week) {
+            (FieldSpec::NoSpecificValue, FieldSpec::NoSpecificValue) => Err(incorrect(
+                FieldPosition::DayOfWeek,
+                "? cannot be used in both day fields".to_string(),
+            )),
+            (FieldSpec::NoSpecificValue, _) | (_, FieldSpec::NoSpecificValue) => Ok(()),
+            _ => Err(incorrect(
+                FieldPosition::DayOfWeek,
+                "one of the day fields should be ?".to_string(),
+            )),
+        }
+    }
+
+    /// Returns the value of a field.
+    #[must_use]
+    pub const fn field(&self, field: FieldPosition) -> &FieldSpec {
+        match field {
+            FieldPosition::Seconds => &self.seconds,
+            FieldPosition::Minutes => &self.minutes,
+            FieldPosition::Hour => &self.hour,
+            FieldPosition::DayOfMonth => &self.day_of_month,
+            FieldPosition::Month => &self.month,
+            FieldPosition::DayOfWeek => &self.day_of_week,
+            FieldPosition::Year => &self.year,
+        }
+    }
+
+    /// Renders the schedule as a [`Cron`], which can then be formatted or
+    /// converted to another dialect.
+    #[must_use]
+    pub fn to_cron(&self) -> Cron {
+        let mut cron = Cron::default();
+        for field in FieldPosition::ALL {
+            *cron.syntax.field_mut(field) = render_field(field, self.field(field));
+            cron.syntax.mark_explicit(field);
+        }
+        cron
+    }
+}
+
+impl TryFrom<&Syntax> for ScheduleSpec {
+    type Error = Error;
+
+    fn try_from(syntax: &Syntax) -> Result<Self> {
+        let parse = |field| parse_field(field, syntax.quartz_field(field).trim());
+        Ok(Self {
+            seconds: parse(FieldPosition::Seconds)?,
+            minutes: parse(FieldPosition::Minutes)?,
+            hour: parse(FieldPosition::Hour)?,
+            day_of_month: parse(FieldPosition::DayOfMonth)?,
+            month: parse(FieldPosition::Month)?,
+            day_of_week: parse(FieldPosition::DayOfWeek)?,
+            year: parse(FieldPosition::Year)?,
+        })
+    }
+}
+
+impl std::fmt::Display for ScheduleSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_cron())
+    }
+}
+
+const fn field_name(field: FieldPosition) -> &'static str {
+    Syntax::field_names()[field as usize]
+}
+
+pub(super) const fn bounds(field: FieldPosition) -> (u32, u32) {
+    match field {
+        FieldPosition::Seconds | FieldPosition::Minutes => (0, 59),
+        FieldPosition::Hour => (0, 23),
+        FieldPosition::DayOfMonth => (1, 31),
+        FieldPosition::Month => (1, 12),
+        FieldPosition::DayOfWeek => (1, 7),
+        FieldPosition::Year => (1970, 2099),
+    }
+}
+
+const fn names(field: FieldPosition) -> &'static [&'static str] {
+    match field {
+        FieldPosition::Month => &MONTHS,
+        FieldPosition::DayOfWeek => &DAYS_OF_WEEK,
+        _ => &[],
+    }
+}
+
+fn incorrect(field: FieldPosition, error: String) -> Error {
+    Error::IncorrectValue {
+        state: field_name(field).to_string(),
+        error,
+    }
+}
+
+pub(super) fn parse_field(field: FieldPosition, value: &str) -> Result<FieldSpec> {
+    match value {
+        "*" => Ok(FieldSpec::Any),
+        "?" if matches!(field, FieldPosition::DayOfMonth | FieldPosition::DayOfWeek) => {
+            Ok(FieldSpec::NoSpecificValue)
+        }
+        _ => {
+            let items: Vec<FieldItem> = value
+                .split(',')
+                .map(|item| parse_item(field, item))
+                .collect::<Result<_>>()?;
+            // Quartz only accepts a numbered weekday ("MON#2") as the whole field
+            if items.len() > 1 && value.contains('#') {
+                return Err(incorrect(
+                    field,
+                    format!("{value} lists a numbered weekday, which must stand alone"),
+                ));
+            }
+            Ok(FieldSpec::List(items))
+        }
+    }
+}
+
+fn parse_item(field: FieldPosition, item: &str) -> Result<FieldItem> {
+    if let Some((base, step)) = item.split_once('/') {
+        let step = parse_number(field, step)?;
+        if step == 0 {
+            return Err(incorrect(field, format!("step in {item} should not be 0")));
+        }
+        let (min, max) = bounds(field);
+        if step > max - min + 1 {
+            return Err(incorrect(
+                field,
+                format!("step in {item} should be at most {}", max - min + 1),
+            ));
+        }
+        let (start, end) = match base.split_once('-') {
+            _ if base == "*" => (None, None),
+            Some((start, end)) => (
+                Some(parse_value(field, start)?),
+                Some(parse_value(field, end)?),
+            ),
+            None => (Some(parse_value(field, base)?), None),
+        };
+        return Ok(FieldItem::Step { start, end, step });
+    }
+
+    if is_special(field, item) {
+        check_special(field, item)?;
+        return Ok(FieldItem::Special(item.to_string()));
+    }
+
+    match item.split_once('-') {
+        Some((start, end)) => Ok(FieldItem::Range {
+            start: parse_value(field, start)?,
+            end: parse_value(field, end)?,
+        }),
+        None => parse_value(field, item).map(FieldItem::Value),
+    }
+}
+
+/// Returns whether an item uses the Quartz `L`, `W`, or `#` specifiers.
+fn is_special(field: FieldPosition, item: &str) -> bool {
+    matches!(field, FieldPosition::DayOfMonth | FieldPosition::DayOfWeek)
+        && !names(field).contains(&item)
+        && (item.contains('#') || item.starts_with('L') || item.ends_with(['L', 'W']))
+}
+
+/// Checks the numbers in a Quartz `L`, `W`, or `#` item: `15W` needs a day of the month,
+/// `L-3` an offset of at most 30 days, `MON#2` a day of the week and a week from 1 to 5,
+/// and `6L` a day of the week.
+fn check_special(field: FieldPosition, item: &str) -> Result<()> {
+    let invalid = || {
+        incorrect(
+            field,
+            format!("{item} is not a valid {}", field_name(field)),
+        )
+    };
+    let within = |value: &str, range: std::ops::RangeInclusive<u32>| {
+        value
+            .parse::<u32>()
+            .ok()
+            .filter(|number| range.contains(number))
+            .ok_or_else(invalid)
+    };
+
+    if field == FieldPosition::DayOfMonth {
+        return match item {
+            "L" | "LW" => Ok(()),
+            _ => match (item.strip_prefix("L-"), item.strip_suffix('W')) {
+                (Some(offset), _) => within(offset, 0..=30).map(|_| ()),
+                (None, Some(day)) => within(day, 1..=31).map(|_| ()),
+                (None, None) => Err(invalid()),
+            },
+        };
+    }
+
+    match (item.split_once('#'), item.strip_suffix('L')) {
+        _ if item == "L" => Ok(()),
+        (Some((day, week)), _) => {
+            parse_value(field, day)?;
+            within(week, 1..=5).map(|_| ())
+        }
+        (None, Some(day)) => parse_value(field, day).map(|_| ()),
+        (None, None) => Err(invalid()),
+    }
+}
+
+fn parse_number(field: FieldPosition, value: &str) -> Result<u32> {
+    value.parse().map_err(|_| Error::ParseToNumber {
+        state: field_name(field).to_string(),
+        value: value.to_string(),
+    })
+}
+
+pub(super) fn parse_value(field: FieldPosition, value: &str) -> Result<u32> {
+    let number = match names(field).iter().position(|name| *name == value) {
+        Some(index) => u32::try_from(index + 1).unwrap_or_default(),
+        None => parse_number(field, value)?,
+    };
+
+    let (min, max) = bounds(field);
+    if !(min..=max).contains(&number) {
+        return Err(incorrect(
+            field,
+            format!(
+                "{} {number} should be between {min} and {max}",
+                field_name(field)
+            ),
+        ));
+    }
+    Ok(number)
+}
+
+fn render_value(field: FieldPosition, value: u32, named: bool) -> String {
+    (value as usize)
+        .checked_sub(1)
+        .and_then(|index| names(field).get(index).filter(|_| named))
+        .map_or_else(|| value.to_string(), ToString::to_string)
+}
+
+pub(super) fn render_field(field: FieldPosition, spec: &FieldSpec) -> String {
+    render(field, spec, true)
+}
+
+/// Renders a field like [`render_field`], but writes months and days of the week as
+/// numbers.
+pub(super) fn render_numeric_field(field: FieldPosition, spec: &FieldSpec) -> String {
+    render(field, spec, false)
+}
+
+fn render(field: FieldPosition, spec: &FieldSpec, named: bool) -> String {
+    let items = match spec {
+        FieldSpec::Any => return "*".to_string(),
+        FieldSpec::NoSpecificValue => return "?".to_string(),
+        FieldSpec::List(items) => items,
+    };
+
+    let mut rendered = String::new();
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            rendered.push(',');
+        }
+        let _ = match item {
+            FieldItem::Value(value) => {
+                write!(rendered, "{}", render_value(field, *value, named))
+            }
+            FieldItem::Range { start, end } => write!(
+                rendered,
+                "{}-{}",
+                render_value(field, *start, named),
+                render_value(field, *end, named)
+            ),
+            FieldItem::Step { start, end, step } => {
+                match start {
+                    Some(start) => rendered.push_str(&start.to_string()),
+                    None => rendered.push('*'),
+                }
+                if let Some(end) = end {
+                    let _ = write!(rendered, "-{end}");
+                }
+                write!(rendered, "/{step}")
+            }
+            FieldItem::Special(special) => write!(rendered, "{special}"),
+        };
+    }
+    rendered
+}
@@ -24,8 +24,11 @@ pub struct Stack {
     pub month: Option<StartEndString>,
     pub year: Option<StartEnd>,
     pub day_of_week: Option<String>,
+    pub qualifier: Option<String>,
     pub is_and_connector: bool,
     pub is_between_range: bool,
+    /// Numbers listed with "and" so far ("0, 6, 12 and 18"), in order.
+    pub listed: Vec<i32>,
 }
 
 impl Stack {
@@ -42,11 +45,25 @@ impl Stack {
                 month: None,
                 year: None,
                 day_of_week: None,
+                qualifier: None,
                 is_and_connector: false,
                 is_between_range: false,
+                listed: Vec::new(),
             },
         }
     }
+
+    /// Returns the separator joining the two boundaries collected on a range element.
+    ///
+    /// Boundaries joined by "and" form a list (`,`), while "to"/"through" and
+    /// "between ... and ..." form a range (`-`).
+    pub const fn range_separator(&self) -> char {
+        if self.is_and_connector && !self.is_between_range {
+            ','
+        } else {
+            '-'
+        }
+    }
 }
 
 pub struct Builder {
@@ -74,11 +91,21 @@ impl Builder {
         self
     }
 
+    pub const fn year(mut self, year: StartEnd) -> Self {
+        self.stack.year = Some(year);
+        self
+    }
+
     pub fn day_of_week(mut self, day_of_week: String) -> Self {
         self.stack.day_of_week = Some(day_of_week);
         self
     }
 
+    pub fn qualifier(mut self, qualifier: String) -> Self {
+        self.stack.qualifier = Some(qualifier);
+        self
+    }
+
     pub fn build(self) -> Stack {
         self.stack
     }
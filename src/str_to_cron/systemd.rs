@@ -0,0 +1,164 @@
+//! This module translates a parsed [`Cron`] into the calendar event syntax used by
+//! systemd timers (the value of an `OnCalendar=` directive).
+//!
+//! systemd expresses calendar events as `DayOfWeek Year-Month-Day Hour:Minute:Second`,
+//! using `..` for ranges, `,` for lists and `start/step` for repetitions. Weekdays are
+//! written as `Mon`, `Tue`, ..., while months are always numeric.
+
+use super::{
+    cron::{Cron, FieldPosition},
+    CronDialect, Error, Result,
+};
+
+/// Weekday abbreviations as emitted by the parser, paired with their systemd spelling.
+const WEEK_DAYS: [(&str, &str); 7] = [
+    ("MON", "Mon"),
+    ("TUE", "Tue"),
+    ("WED", "Wed"),
+    ("THU", "Thu"),
+    ("FRI", "Fri"),
+    ("SAT", "Sat"),
+    ("SUN", "Sun"),
+];
+
+/// Month abbreviations in calendar order; the position is the month number minus one.
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+impl Cron {
+    /// Translates the cron expression into a systemd `OnCalendar=` value.
+    ///
+    /// Wildcards (`*` and `?`) become `*`, ranges such as `MON-FRI` become `Mon..Fri`,
+    /// month abbreviations are converted to month numbers and lists are preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DialectUnsupported`] if the expression uses a Quartz-only
+    /// feature such as `L` or `#`, a step that systemd cannot express, or a range that
+    /// runs over the end of its field (`22-2` in the hours).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::Cron;
+    ///
+    /// let cron = Cron::new("Run at 6:00 pm every Monday through Friday").unwrap();
+    /// assert_eq!(cron.to_systemd_timer().unwrap(), "Mon..Fri *-*-* 18:00:00");
+    /// ```
+    pub fn to_systemd_timer(&self) -> Result<String> {
+        let day_of_week = field(
+            &self.syntax.quartz_field(FieldPosition::DayOfWeek),
+            "day_of_week",
+            week_day,
+        )?;
+        let year = field(&self.syntax.year, "year", |value| Ok(value.to_string()))?;
+        let month = field(&self.syntax.month, "month", |value| Ok(month(value)))?;
+        let day_of_month = field(&self.syntax.day_of_month, "day_of_month", |value| {
+            Ok(pad(value))
+        })?;
+        let hour = field(&self.syntax.hour, "hour", |value| Ok(pad(value)))?;
+        let min = field(&self.syntax.min, "min", |value| Ok(pad(value)))?;
+        let seconds = field(&self.syntax.seconds, "seconds", |value| Ok(pad(value)))?;
+
+        let calendar = format!("{year}-{month}-{day_of_month} {hour}:{min}:{seconds}");
+        if day_of_week == "*" {
+            Ok(calendar)
+        } else {
+            Ok(format!("{day_of_week} {calendar}"))
+        }
+    }
+}
+
+/// Translates a single cron field, applying `value` to every list element,
+/// range boundary and step start.
+fn field(expression: &str, name: &str, value: impl Fn(&str) -> Result<String>) -> Result<String> {
+    let expression = expression.trim();
+    let quartz_only = expression.contains('#')
+        || (name == "day_of_month" && (expression.contains('L') || expression.contains('W')))
+        || (name == "day_of_week" && expression.split(',').any(|day| day.ends_with('L')));
+    if quartz_only {
+        return Err(unsupported(name, expression));
+    }
+    if expression == "*" || expression == "?" {
+        return Ok("*".to_string());
+    }
+
+    let mut elements = Vec::new();
+    for element in expression.split(',') {
+        let (base, step) = match element.split_once('/') {
+            Some((base, step)) => (base, Some(step)),
+            None => (element, None),
+        };
+        let mut rendered = if base == "*" {
+            // systemd has no wildcard step; anchor it on the field's first value.
+            match name {
+                "day_of_month" | "month" => "01".to_string(),
+                "year" => return Err(unsupported(name, expression)),
+                _ => "00".to_string(),
+            }
+        } else if let Some((start, end)) = base.split_once('-') {
+            // systemd ranges cannot run over the end of the field ("22-2" in the hours)
+            if order(start) > order(end) {
+                return Err(unsupported(name, expression));
+            }
+            format!("{}..{}", value(start)?, value(end)?)
+        } else {
+            value(base)?
+        };
+        if let Some(step) = step {
+            rendered.push('/');
+            rendered.push_str(step);
+        }
+        elements.push(rendered);
+    }
+
+    Ok(elements.join(","))
+}
+
+/// Returns the position of a value within its field, reading weekday and month
+/// abbreviations in calendar order.
+fn order(value: &str) -> Option<usize> {
+    value
+        .parse()
+        .ok()
+        .or_else(|| {
+            WEEK_DAYS
+                .iter()
+                .position(|(day, _)| day.eq_ignore_ascii_case(value))
+        })
+        .or_else(|| {
+            MONTHS
+                .iter()
+                .position(|month| month.eq_ignore_ascii_case(value))
+        })
+}
+
+/// Zero-pads a numeric value to two digits.
+fn pad(value: &str) -> String {
+    format!("{value:0>2}")
+}
+
+/// Converts a weekday abbreviation into its systemd spelling.
+fn week_day(value: &str) -> Result<String> {
+    WEEK_DAYS
+        .iter()
+        .find(|(day, _)| day.eq_ignore_ascii_case(value))
+        .map(|(_, systemd)| (*systemd).to_string())
+        .ok_or_else(|| unsupported("day_of_week", value))
+}
+
+/// Converts a month abbreviation into a zero-padded month number.
+fn month(value: &str) -> String {
+    MONTHS
+        .iter()
+        .position(|month| month.eq_ignore_ascii_case(value))
+        .map_or_else(|| pad(value), |index| pad(&(index + 1).to_string()))
+}
+
+fn unsupported(field: &str, value: &str) -> Error {
+    Error::DialectUnsupported {
+        dialect: CronDialect::Systemd,
+        feature: format!("{value} in {field}"),
+    }
+}
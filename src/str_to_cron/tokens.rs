@@ -1,12 +1,177 @@
-use regex::Regex;
+use super::re::{Captures, Regex};
+use super::{spec, Error, FieldPosition, Result};
+use std::collections::HashMap;
 use std::sync::LazyLock;
 
 static RE_TOKENS: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"(?i)(?:seconds|second|secs|sec)|(?:hours?|hrs?)|(?:minutes?|mins?|min)|(?:months?|(?:january|february|march|april|may|june|july|august|september|october|november|december|jan|feb|mar|apr|may|jun|jul|aug|sept|oct|nov|dec)(?: ?and)?,? ?)+|[0-9]+(?:th|nd|rd|st)|(?:[0-9]+:)?[0-9]+ ?(?:am|pm)|[0-9]+:[0-9]+|(?:noon|midnight)|(?:days?|(?:monday|tuesday|wednesday|thursday|friday|saturday|sunday|weekend|mon|tue|wed|thu|fri|sat|sun)(?: ?and)?,? ?)+|(?:[0-9]{4}[0-9]*(?: ?and)?,? ?)+|[0-9]+|(?:only on)|(?:to|through|ending|end|and)|(?:between|starting|start)").unwrap()
+    Regex::new(r"(?i)(?-u:\b)[0-9]+ times per (?:minute|hour|day|week|month|year)(?-u:\b)|(?-u:\b)(?:[0-9]+(?:st|nd|rd|th)|first|second|third|fourth|fifth)(?: (?:and|or) (?:[0-9]+(?:st|nd|rd|th)|first|second|third|fourth|fifth))* (?:monday|tuesday|wednesday|thursday|friday|saturday|sunday|mon|tue|wed|thu|fri|sat|sun)(?-u:\b)(?: of (?:the|each|every) month)?|(?:(?:(?:[a-z]+|[0-9]+(?:st|nd|rd|th)) to )?last day|penultimate day|[0-9]+ days? before the end)(?: of (?:the|each|every) month)?|last (?:monday|tuesday|wednesday|thursday|friday|saturday|sunday|mon|tue|wed|thu|fri|sat|sun)(?: of (?:the|each|every) month)?|(?:fortnight(?:ly)?|bi-?weekly|two weeks)|(?:(?:[0-9]+|half|quarter)(?: minutes?)? past|on|(?:at )?(?:the )?top of) (?:the|every|each) hour|(?:(?:first|second) half of the (?:hour|minute)|(?:first|last) [0-9]+ (?:minutes?|seconds?)(?: of the (?:hour|minute))?)|(?:of (?:each|every|the) (?:hours?|days?|weeks?|months?))|(?:seconds|second|secs|sec)|(?:business|office|working) hours|(?:hours?|hrs?)|(?:minutes?|mins?|min)|years?|(?:months?|(?-u:\b)(?:(?:summer|winter|spring|fall|autumn)(?: months?)?|january|february|march|april|may|june|july|august|september|october|november|december|jan|feb|mar|apr|may|jun|jul|aug|sept|oct|nov|dec)(?-u:\b)(?: ?and)?,? ?)+|[0-9]+ ?o['’]clock(?: ?(?:am|pm|noon|midday|midnight))?(?: (?:in the (?:morning|afternoon|evening)|at night))?|[0-9]+(?:th|nd|rd|st)(?:(?: days?)?(?: and)+(?: on)?(?: the)? [0-9]+(?:th|nd|rd|st))*|(?:[0-9]+:){0,2}[0-9]+ ?(?:am|pm)(?: (?:in the (?:morning|afternoon|evening)|at night))?|[0-9]+(?::[0-9]+){0,2} (?:in the (?:morning|afternoon|evening)|at night)|[0-9]+:[0-9]+(?::[0-9]+)?|(?-u:\b)(?:noon|midday|midnight)(?-u:\b)|(?-u:\b)(?:mornings?|afternoons?|evenings?|nights?|nightly)(?-u:\b)|(?:monday|tuesday|wednesday|thursday|friday|saturday|sunday|mon|tue|wed|thu|fri|sat|sun) ?[-–] ?(?:monday|tuesday|wednesday|thursday|friday|saturday|sunday|mon|tue|wed|thu|fri|sat|sun)(?-u:\b)|(?:even|odd)(?:-numbered)? days?|(?:days?|(?:monday|tuesday|wednesday|thursday|friday|saturday|sunday|weekdays?|business ?days?|work ?days?|weekend|mon|tue|wed|thu|fri|sat|sun)(?: ?and)?,? ?)+|(?-u:\b)(?:weekly|weeks?)(?-u:\b)|(?:[0-9]{4}[0-9]*(?: ?and)?,? ?)+|[0-9]+|(?:other)|(?:q[1-4])|(?:only on)|(?:except|excluding)(?: on| for)?|(?:to|through|ending|end|and)|(?:between|starting|start)").unwrap()
 });
 
+/// Matches a word of the input.
+static RE_WORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[A-Za-z0-9'’]+").unwrap());
+
+/// Words that connect the parts of a schedule without adding to it.
+const FILLER_WORDS: [&str; 19] = [
+    "a", "an", "at", "by", "during", "each", "every", "for", "from", "in", "it", "of", "on",
+    "once", "only", "per", "run", "runs", "the",
+];
+
+/// Matches "am"/"pm" written with periods or spaces after a time ("9 a.m.", "7 P. M."), so
+/// that they can be written the usual way.
+static RE_DOTTED_MERIDIEM: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)([0-9]) ?([ap])(?:\. ?m\.?|m\.)").unwrap());
+
+/// Matches three- or four-digit military times after "at" or before "hours" ("at 1430",
+/// "at 0900 and 1700", "1800 hrs"), along with the word before them, so that
+/// [`military_time`] can write them with a colon rather than as years.
+static RE_MILITARY_TIME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(?:([a-z]+) )?([0-9]{3,4}(?: and [0-9]{3,4})*)( hours| hrs)?(?-u:\b)")
+        .unwrap()
+});
+
+/// Matches a range between two three- or four-digit numbers ("between 0900 and 1700",
+/// "from 0900 to 1700"), which [`military_range`] writes as times unless both are years.
+static RE_MILITARY_RANGE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(between|from) ([0-9]{3,4}) (and|to|through) ([0-9]{3,4})(?-u:\b)")
+        .unwrap()
+});
+
+/// Matches a single military time, split into its hour and minute.
+static RE_MILITARY_PART: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([0-9]{1,2})([0-9]{2})").unwrap());
+
+/// Matches a number of runs per unit ("twice a day", "three times a week", "4 times per
+/// hour"), capturing the count and the unit.
+static RE_TIMES_PER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(twice|thrice|([a-z]+|[0-9]+) times?) (?:a|an|per|each|every) (minute|hour|day|week|month|year)(?-u:\b)").unwrap()
+});
+
+/// Matches "every week" or "each week", along with an "of" before it ("every day of every
+/// week"), so that [`every_week`] can tell a weekly repetition from a week that only scopes
+/// the schedule ("this week").
+static RE_EVERY_WEEK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?-u:\b)(of )?(?:every|each) week(?-u:\b)").unwrap());
+
+/// Matches "a"/"an" used as a frequency of one before a unit ("once a day", "every an hour").
+static RE_IMPLICIT_ONE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(?:once |every |each )?an? (seconds?|minutes?|hours?|days?|weeks?|months?|years?)(?-u:\b)").unwrap()
+});
+
+/// Matches a duration with an extra half unit ("an hour and a half", "two and a half
+/// hours", "2 hours and a half"), capturing the count and the unit.
+static RE_AND_A_HALF: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(?:(an?|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve|[0-9]+) (?:and a half (hour|minute|day)s?|(hour|minute|day)s? and a half)|(?:an? )?(hour|minute|day) and a half)(?-u:\b)").unwrap()
+});
+
+/// Number words accepted as the count of [`RE_AND_A_HALF`], indexed by their value.
+const NUMBER_WORDS: [&str; 13] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve",
+];
+
+/// Matches a compact duration of one or more numbers with unit letters ("5m", "1h30m").
+static RE_COMPACT_DURATION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?-u:\b)(?:[0-9]+[dhms])+(?-u:\b)").unwrap());
+
+/// Matches a single part of a compact duration, capturing the number and the unit letter.
+static RE_DURATION_PART: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)([0-9]+)([dhms])").unwrap());
+
+/// Matches "every second" before a weekday, which means every other one rather than the
+/// second of the month ("every second Tuesday").
+static RE_EVERY_SECOND_DAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(every|each) second ((?:monday|tuesday|wednesday|thursday|friday|saturday|sunday|mon|tue|wed|thu|fri|sat|sun)(?-u:\b))").unwrap()
+});
+
+/// Matches an ordinal after "every" before a unit within the day ("every 5th minute"), which
+/// repeats like "every 5 minutes" rather than naming a single minute ("the 5th minute").
+static RE_EVERY_ORDINAL_UNIT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(every|each) ([0-9]+)(?:st|nd|rd|th) (second|minute|hour)(?-u:\b)")
+        .unwrap()
+});
+
+/// Matches a minute or second named before its number ("at minute 30", "at second 15"),
+/// which sets that minute or second the same as "the 30th minute". An offset after
+/// "starting" is captured so it can be left as it is.
+static RE_UNIT_NUMBER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(starting (?:at |on )?)?(?:at |on )?(minute|second) ([0-9]+)(?-u:\b)")
+        .unwrap()
+});
+
+/// Matches "alternating" or "alternate" before a weekday ("on alternating Mondays"), which
+/// means every other one.
+static RE_ALTERNATING_DAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(?:on )?alternat(?:e|ing) (monday|tuesday|wednesday|thursday|friday|saturday|sunday|mon|tue|wed|thu|fri|sat|sun)s?(?-u:\b)").unwrap()
+});
+
+/// Matches a range of bare numbers after "between" or "from" ("between 9 and 17"), along with
+/// the word that follows it so that [`hour_range`] can tell an hour range from other numbers.
+static RE_BETWEEN_HOURS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(?-u:\b)(between |from )([0-9]{1,2}) (and|to) ([0-9]{1,2})(?-u:\b)(:| *[a-z'’.]+)?",
+    )
+    .unwrap()
+});
+
+/// Matches a compact range of bare numbers ("9-17"), along with the character before it
+/// and the word after it. Ranges that are part of a date or a time ("2024-01-15",
+/// "9:30-17") are left alone.
+static RE_COMPACT_HOURS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(^|[^-–/:.0-9a-z])([0-9]{1,2})( ?[-–] ?)([0-9]{1,2})(?-u:\b)(:| *[a-z'’.]+)?")
+        .unwrap()
+});
+
+/// Matches bare numbers after "at" ("at 7", "at 7 and 19"), along with the words around
+/// them so that [`bare_hour`] can tell hours of the day from other numbers.
+static RE_BARE_HOUR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(?-u:\b)(starting )?(at )([0-9]{1,2}(?: and [0-9]{1,2})*)(?-u:\b)(:| *[a-z'’.]+)?",
+    )
+    .unwrap()
+});
+
+/// Matches bare numbers listed after a clock time ("at 7:30 and 9"), in the same groups as
+/// [`RE_BARE_HOUR`].
+static RE_HOUR_AFTER_TIME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(starting )?([0-9]:[0-9]{2} and )([0-9]{1,2}(?: and [0-9]{1,2})*)(?-u:\b)(:| *[a-z'’.]+)?",
+    )
+    .unwrap()
+});
+
+/// Matches a period of the day, which reads bare numbers of the same clause as hours in that
+/// period ("at 7 every Friday evening").
+static RE_DAY_PERIOD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?-u:\b)(mornings?|afternoons?|evenings?|nights?|nightly)(?-u:\b)").unwrap()
+});
+
+/// Matches what ends the clause of a bare number on the way to a period of the day: another
+/// number, or a new clause joined with "and" ("at 7 on Monday and every evening at 8").
+static RE_CLAUSE_BREAK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)[0-9]|(?-u:\b)and (?:every|each|at)(?-u:\b)").unwrap());
+
+/// Matches a word after a bare number that already places it in the day ("at 7 in the
+/// evening", "at 15 past the hour").
+static RE_PLACED_NUMBER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^ *(in|at|past)$").unwrap());
+
+/// Matches a word after a number range that shows the numbers are not hours of a 24-hour
+/// clock: a meridiem, a unit, or "of" ("from 1 to 15 of the month").
+static RE_NOT_HOURS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(:| *(am?|pm?|a\.m\.|p\.m\.|o['’]?clock|of|(sec|second|min|minute|hour|hr|day|week|month|year)s?)$)").unwrap()
+});
+
+#[derive(Clone)]
 pub struct Tokenizer {
     regex: Regex,
+    synonyms: Option<Synonyms>,
+}
+
+/// Custom phrases and the canonical text each one is rewritten to.
+#[derive(Clone)]
+struct Synonyms {
+    regex: Regex,
+    replacements: HashMap<String, String>,
 }
 
 impl Default for Tokenizer {
@@ -19,28 +184,413 @@ impl Tokenizer {
     pub fn new() -> Self {
         Self {
             regex: RE_TOKENS.clone(),
+            synonyms: None,
         }
     }
 
+    /// Creates a tokenizer that rewrites custom phrases before tokenizing.
+    ///
+    /// Each key of `map` is a phrase to recognize and its value is the English text it
+    /// stands for, e.g. `"every sprint"` to `"every 2 weeks"`. Phrases are matched as whole
+    /// words, ignoring case.
+    ///
+    /// # Precedence
+    ///
+    /// - Synonyms are rewritten first, before any built-in pattern sees the input, so a
+    ///   synonym that overlaps a built-in word (e.g., `"day"`) replaces it everywhere.
+    /// - When several synonyms match at the same place, the longest phrase wins.
+    /// - Rewrites happen in a single pass: the replacement text is not checked for
+    ///   further synonyms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{Cron, Options, Tokenizer};
+    /// use std::collections::HashMap;
+    ///
+    /// let tokenizer = Tokenizer::with_synonyms(HashMap::from([
+    ///     ("every standup".to_string(), "every weekday at 9:30am".to_string()),
+    /// ]))?;
+    /// let cron = Cron::with_tokenizer("every standup", &tokenizer, &Options::default()).unwrap();
+    /// assert_eq!(cron.to_string(), "0 30 9 ? * MON-FRI *");
+    /// # Ok::<(), english_to_cron::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncorrectValue`] if the phrases are too many or too long to be
+    /// matched together.
+    pub fn with_synonyms(map: HashMap<String, String>) -> Result<Self> {
+        let mut phrases: Vec<&String> = map.keys().filter(|phrase| !phrase.is_empty()).collect();
+        // Longer phrases first, so the alternation prefers them over their prefixes
+        phrases.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        if phrases.is_empty() {
+            return Ok(Self::new());
+        }
+
+        let alternation = phrases
+            .iter()
+            .map(|phrase| super::re::escape(phrase))
+            .collect::<Vec<_>>()
+            .join("|");
+        let regex =
+            Regex::new(&format!(r"(?i)(?-u:\b)(?:{alternation})(?-u:\b)")).map_err(|error| {
+                Error::IncorrectValue {
+                    state: "synonyms".to_string(),
+                    error: format!("the synonym phrases cannot be matched: {error}"),
+                }
+            })?;
+        let replacements = map
+            .into_iter()
+            .map(|(phrase, replacement)| (phrase.to_lowercase(), replacement))
+            .collect();
+
+        Ok(Self {
+            regex: RE_TOKENS.clone(),
+            synonyms: Some(Synonyms {
+                regex,
+                replacements,
+            }),
+        })
+    }
+
+    /// Splits the input into tokens.
+    ///
+    /// "every" and "each" are not tokens: the unit that follows them carries the
+    /// schedule, so "each day" and "every day" produce the same tokens.
     #[must_use]
     pub fn run(&self, input_string: &str) -> Vec<String> {
+        let processed_input = self.preprocess(input_string);
+        let matches = self
+            .regex
+            .find_iter(&processed_input)
+            .map(|m| m.as_str().trim().to_string())
+            .collect();
+
+        matches
+    }
+
+    /// Returns the words of the input that no token covers, leaving out filler words such
+    /// as "at" or "every" that only connect the parts of a schedule.
+    pub(crate) fn ignored_words(&self, input_string: &str) -> Vec<String> {
+        let processed_input = self.preprocess(input_string);
+        let matches: Vec<_> = self
+            .regex
+            .find_iter(&processed_input)
+            .map(|m| m.range())
+            .collect();
+
+        RE_WORD
+            .find_iter(&processed_input)
+            .filter(|word| {
+                !matches
+                    .iter()
+                    .any(|m| m.start < word.end() && word.start() < m.end)
+            })
+            .map(|word| word.as_str().to_string())
+            .filter(|word| !FILLER_WORDS.contains(&word.to_lowercase().as_str()))
+            .collect()
+    }
+
+    /// Replaces the phrases given to [`Tokenizer::with_synonyms`] with their meanings.
+    pub(crate) fn apply_synonyms(&self, input_string: &str) -> String {
+        self.synonyms.as_ref().map_or_else(
+            || input_string.to_string(),
+            |synonyms| {
+                synonyms
+                    .regex
+                    .replace_all(input_string, |captures: &Captures<'_>| {
+                        synonyms
+                            .replacements
+                            .get(&captures[0].to_lowercase())
+                            .cloned()
+                            .unwrap_or_else(|| captures[0].to_string())
+                    })
+                    .into_owned()
+            },
+        )
+    }
+
+    /// Applies synonyms and rewrites special cases before the input is split into tokens.
+    fn preprocess(&self, input_string: &str) -> String {
+        let processed_input = self.apply_synonyms(input_string);
+
         // Preprocess the input to handle special cases
-        let processed_input = input_string.replace(", ", " and ");
+        let processed_input = processed_input.replace(", ", " and ");
+        let processed_input = RE_DOTTED_MERIDIEM
+            .replace_all(&processed_input, "${1}${2}m")
+            .into_owned();
+        let processed_input = RE_MILITARY_RANGE
+            .replace_all(&processed_input, military_range)
+            .into_owned();
+        let processed_input = RE_MILITARY_TIME
+            .replace_all(&processed_input, military_time)
+            .into_owned();
+        let processed_input = RE_COMPACT_DURATION
+            .replace_all(&processed_input, compact_duration)
+            .into_owned();
+        let processed_input = RE_AND_A_HALF
+            .replace_all(&processed_input, and_a_half)
+            .into_owned();
+        let processed_input = RE_TIMES_PER
+            .replace_all(&processed_input, times_per)
+            .into_owned();
+        let processed_input = RE_IMPLICIT_ONE
+            .replace_all(&processed_input, "every 1 $1")
+            .into_owned();
+        let processed_input = RE_EVERY_WEEK
+            .replace_all(&processed_input, every_week)
+            .into_owned();
+        let processed_input = RE_EVERY_SECOND_DAY
+            .replace_all(&processed_input, "$1 other $2")
+            .into_owned();
+        let processed_input = RE_EVERY_ORDINAL_UNIT
+            .replace_all(&processed_input, "$1 $2 ${3}s")
+            .into_owned();
+        let processed_input = RE_UNIT_NUMBER
+            .replace_all(&processed_input, |captures: &Captures<'_>| {
+                if captures.get(1).is_some() {
+                    captures[0].to_string()
+                } else {
+                    format!("at the {}th {}", &captures[3], &captures[2])
+                }
+            })
+            .into_owned();
+        let processed_input = RE_ALTERNATING_DAY
+            .replace_all(&processed_input, "every other $1")
+            .into_owned();
+
+        let processed_input = RE_BETWEEN_HOURS
+            .replace_all(&processed_input, |captures: &Captures<'_>| {
+                hour_range(captures, &captures[3])
+            })
+            .into_owned();
+        let processed_input = RE_COMPACT_HOURS
+            .replace_all(&processed_input, |captures: &Captures<'_>| {
+                hour_range(captures, "to")
+            })
+            .into_owned();
+        let processed_input = RE_BARE_HOUR
+            .replace_all(&processed_input, |captures: &Captures<'_>| {
+                bare_hour(captures, &processed_input)
+            })
+            .into_owned();
+        let processed_input = RE_HOUR_AFTER_TIME
+            .replace_all(&processed_input, |captures: &Captures<'_>| {
+                bare_hour(captures, &processed_input)
+            })
+            .into_owned();
 
         // Handle "only on" followed by day names as a special pattern
-        let processed_input = if processed_input.contains("only on") {
+        if processed_input.contains("only on") {
             // Remove "and" before "only on" to prevent misinterpretation
             processed_input.replace(" and only on", " only on")
         } else {
             processed_input
+        }
+    }
+}
+
+/// Rewrites bare hours after "at" or a clock time as times on the clock ("at 7" to "at 7 o'clock"), unless
+/// the word after them shows that they are something else or already placed in the day.
+/// An offset after "starting" is left as it is ("every 15 minutes starting at 5"), as are
+/// numbers in the same clause as a period of the day. When the last number is the start of
+/// a clock time ("at 7 and 9:30"), only the numbers before it are rewritten.
+fn bare_hour(captures: &Captures<'_>, input: &str) -> String {
+    let suffix = captures.get(4).map_or("", |m| m.as_str());
+    let mut hours: Vec<_> = captures[3].split(" and ").collect();
+    let time = if suffix == ":" { hours.pop() } else { None };
+    if captures.get(1).is_some()
+        || hours.is_empty()
+        || (time.is_none() && RE_NOT_HOURS.is_match(suffix))
+        || RE_PLACED_NUMBER.is_match(suffix)
+        || hours
+            .iter()
+            .any(|hour| hour.parse::<u32>().map_or(true, |hour| hour > 23))
+        || has_day_period(input, captures.get(3).map_or(0..0, |hours| hours.range()))
+    {
+        return captures[0].to_string();
+    }
+    let mut hours: Vec<_> = hours.iter().map(|hour| format!("{hour} o'clock")).collect();
+    hours.extend(time.map(ToString::to_string));
+    format!("{}{}{suffix}", &captures[2], hours.join(" and "))
+}
+
+/// Returns whether a period of the day lies in the same clause as the numbers at `range`,
+/// with no other number or new clause between them.
+fn has_day_period(input: &str, range: std::ops::Range<usize>) -> bool {
+    RE_DAY_PERIOD.find_iter(input).any(|period| {
+        let between = if period.start() >= range.end {
+            &input[range.end..period.start()]
+        } else {
+            &input[period.end().min(range.start)..range.start]
         };
+        !RE_CLAUSE_BREAK.is_match(between)
+    })
+}
 
-        let matches = self
-            .regex
-            .find_iter(&processed_input)
-            .map(|m| m.as_str().trim().to_string())
-            .collect();
+/// Rewrites "every week" as "weekly", which repeats once a week, unless it follows "of".
+fn every_week(captures: &Captures<'_>) -> String {
+    if captures.get(1).is_some() {
+        captures[0].to_string()
+    } else {
+        "weekly".to_string()
+    }
+}
 
-        matches
+/// Rewrites military times with a colon ("at 1800 hours" to "at 18:00"). Numbers after
+/// "every", "each" or "for" are counts, and other numbers are only times after "at" or before
+/// "hours", so "in 2025" stays a year.
+fn military_time(captures: &Captures<'_>) -> String {
+    let word = captures.get(1).map(|word| word.as_str().to_lowercase());
+    let is_at = word.as_deref() == Some("at");
+    let is_hours = captures.get(3).is_some();
+    if matches!(word.as_deref(), Some("every" | "each" | "for")) || !(is_at || is_hours) {
+        return captures[0].to_string();
+    }
+    let times = RE_MILITARY_PART.replace_all(&captures[2], "$1:$2");
+    match captures.get(1) {
+        Some(word) => format!("{} {times}", word.as_str()),
+        None => times.into_owned(),
+    }
+}
+
+/// Rewrites both ends of a range of military times with a colon ("from 0900 to 1700" to
+/// "from 9:00 to 17:00"). A range where either end is not a time of day, or where both ends
+/// are years ("from 2025 to 2030"), is kept.
+fn military_range(captures: &Captures<'_>) -> String {
+    let (first_year, last_year) = spec::bounds(FieldPosition::Year);
+    let is_year = |number: &str| {
+        number
+            .parse::<u32>()
+            .is_ok_and(|year| (first_year..=last_year).contains(&year))
+    };
+    let is_time = |number: &str| {
+        RE_MILITARY_PART.captures(number).is_some_and(|parts| {
+            parts[1].parse::<u32>().is_ok_and(|hour| hour < 24)
+                && parts[2].parse::<u32>().is_ok_and(|minute| minute < 60)
+        })
+    };
+    let (start, end) = (&captures[2], &captures[4]);
+    if !(is_time(start) && is_time(end)) || (is_year(start) && is_year(end)) {
+        return captures[0].to_string();
+    }
+    format!(
+        "{} {} {} {}",
+        &captures[1],
+        RE_MILITARY_PART.replace(start, "$1:$2"),
+        &captures[3],
+        RE_MILITARY_PART.replace(end, "$1:$2")
+    )
+}
+
+/// The units of a compact duration, from the largest, with their letter and length in
+/// seconds.
+const DURATION_UNITS: [(&str, &str, u32); 4] = [
+    ("d", "days", 86_400),
+    ("h", "hours", 3_600),
+    ("m", "minutes", 60),
+    ("s", "seconds", 1),
+];
+
+/// Rewrites a compact duration in words, adding up its parts and writing the total in the
+/// largest unit that divides it ("1h30m" to "90 minutes", "1h0s" to "1 hours").
+fn compact_duration(captures: &Captures<'_>) -> String {
+    let mut total: Option<u32> = Some(0);
+    let mut smallest = DURATION_UNITS[0];
+    for part in RE_DURATION_PART.captures_iter(&captures[0]) {
+        let letter = part[2].to_ascii_lowercase();
+        let unit = DURATION_UNITS
+            .into_iter()
+            .find(|(unit, _, _)| *unit == letter)
+            .unwrap_or(smallest);
+        if unit.2 < smallest.2 {
+            smallest = unit;
+        }
+        total = total
+            .zip(part[1].parse::<u32>().ok())
+            .and_then(|(total, value)| total.checked_add(value.checked_mul(unit.2)?));
+    }
+    total.map_or_else(
+        || captures[0].to_string(),
+        |total| {
+            let (_, name, size) = DURATION_UNITS
+                .into_iter()
+                .find(|(_, _, size)| total > 0 && total % size == 0)
+                .unwrap_or(smallest);
+            format!("{} {name}", total / size)
+        },
+    )
+}
+
+/// Rewrites a duration with an extra half unit as a whole number of the next smaller unit
+/// ("an hour and a half" to "90 minutes"), so that it repeats like any other interval.
+fn and_a_half(captures: &Captures<'_>) -> String {
+    let (count, unit) = match (
+        captures.get(1),
+        captures.get(2).or_else(|| captures.get(3)),
+        captures.get(4),
+    ) {
+        (Some(count), Some(unit), _) => (count.as_str().to_lowercase(), unit.as_str()),
+        (_, _, Some(unit)) => ("1".to_string(), unit.as_str()),
+        _ => return captures[0].to_string(),
+    };
+    let count = match count.as_str() {
+        "a" | "an" => Some(1),
+        count => NUMBER_WORDS
+            .iter()
+            .position(|word| *word == count)
+            .and_then(|count| u32::try_from(count).ok())
+            .or_else(|| count.parse().ok()),
+    };
+    let (smaller, size) = match unit.to_lowercase().as_str() {
+        "day" => ("hours", 24),
+        "hour" => ("minutes", 60),
+        _ => ("seconds", 60),
+    };
+    count
+        .and_then(|count| count.checked_mul(size))
+        .and_then(|total| total.checked_add(size / 2))
+        .map_or_else(
+            || captures[0].to_string(),
+            |total| format!("{total} {smaller}"),
+        )
+}
+
+/// Rewrites a number of runs per unit with the count in digits ("three times a day" to "3
+/// times per day"). Counts that are not numbers are left as they are.
+fn times_per(captures: &Captures<'_>) -> String {
+    let count = match captures[1].to_lowercase().as_str() {
+        "twice" => Some(2),
+        "thrice" => Some(3),
+        _ => {
+            let count = captures[2].to_lowercase();
+            NUMBER_WORDS
+                .iter()
+                .position(|word| *word == count)
+                .or_else(|| count.parse().ok())
+        }
+    };
+    count.map_or_else(
+        || captures[0].to_string(),
+        |count| format!("{count} times per {}", &captures[3]),
+    )
+}
+
+/// Rewrites a range of bare numbers as a range of clock times ("9-17" to "9:00 to 17:00"),
+/// so that it restricts the hour field like "from 9am to 5pm".
+///
+/// Bare numbers are only read as hours when nothing after them says otherwise: a range
+/// followed by a colon, AM/PM, "o'clock", a unit ("1-5 days"), or "of" ("from 1 to 15 of
+/// the month") is kept as written. Hours above 23 are rewritten too, and rejected when the
+/// clock time is processed.
+fn hour_range(captures: &Captures<'_>, connector: &str) -> String {
+    let suffix = captures.get(5).map_or("", |m| m.as_str());
+    if RE_NOT_HOURS.is_match(suffix) {
+        return captures[0].to_string();
     }
+    format!(
+        "{}{}:00 {connector} {}:00{suffix}",
+        &captures[1], &captures[2], &captures[4]
+    )
 }
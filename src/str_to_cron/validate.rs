@@ -0,0 +1,75 @@
+//! This module checks a parsed [`Cron`] for schedules that can never fire.
+//!
+//! Only the year field can place a schedule entirely in the past. A year field whose
+//! every value and range has ended is rejected, while a field that still has future
+//! years only produces a [`Warning`] for each element that has passed.
+
+use super::{cron::Cron, Error, Options, Result, Warning};
+
+impl Cron {
+    /// Checks the schedule with the default [`Options`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ScheduleInPast`] if the year field lies entirely in the past.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use english_to_cron::{Clock, Cron, Error, Options};
+    ///
+    /// let cron = Cron::new("every day in 2019").unwrap();
+    /// assert_eq!(cron.validate(), Err(Error::ScheduleInPast { year: 2019 }));
+    ///
+    /// let mut options = Options::default();
+    /// options.reject_past_years = false;
+    /// assert!(cron.validate_with(&options).is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<Vec<Warning>> {
+        self.validate_with(&Options::default())
+    }
+
+    /// Checks the schedule with the given [`Options`], returning any non-fatal warnings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ScheduleInPast`] if past years are rejected and the year field
+    /// lies entirely in the past.
+    pub fn validate_with(&self, options: &Options) -> Result<Vec<Warning>> {
+        if !options.reject_past_years {
+            return Ok(Vec::new());
+        }
+
+        let current_year = options.clock.current_year();
+        let mut past = Vec::new();
+        let mut has_future = false;
+
+        for item in self.syntax.year.trim().split(',') {
+            match last_year(item) {
+                Some(year) if year < current_year => past.push(year),
+                _ => has_future = true,
+            }
+        }
+
+        if has_future {
+            Ok(past
+                .into_iter()
+                .map(|year| Warning::PastYear { year })
+                .collect())
+        } else {
+            let year = past.into_iter().max().unwrap_or(current_year);
+            Err(Error::ScheduleInPast { year })
+        }
+    }
+}
+
+/// Returns the last year a year-field item can fire in, or `None` if it is open-ended
+/// (`*`, `?`, or a step without an end).
+fn last_year(item: &str) -> Option<i32> {
+    let range = item.split_once('/').map_or(item, |(range, _)| range);
+    let end = range.split_once('-').map_or(range, |(_, end)| end);
+    if item.contains('/') && !range.contains('-') {
+        return None;
+    }
+    end.parse().ok()
+}
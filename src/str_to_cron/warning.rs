@@ -0,0 +1,56 @@
+//! Non-fatal findings about a parsed schedule.
+
+/// A finding that does not stop a schedule from being used but may surprise its author.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Warning {
+    /// Part of the year field lies in the past and will never fire.
+    ///
+    /// # Fields
+    /// - `year`: The past year, or the last year of a past range.
+    PastYear { year: i32 },
+    /// A period cron cannot express exactly was replaced with an approximation.
+    ///
+    /// # Fields
+    /// - `phrase`: The phrase as it was read, including the chosen meaning of ambiguous words.
+    /// - `approximation`: The schedule produced instead.
+    ApproximatedPeriod {
+        phrase: String,
+        approximation: String,
+    },
+    /// A word of the input was not understood, or was read but left out of the schedule
+    /// (the start of "every 5 minutes starting on Monday").
+    ///
+    /// # Fields
+    /// - `token`: The word as it appeared in the input.
+    IgnoredToken { token: String },
+    /// A repetition of days was dropped in favor of days of the week, since cron cannot
+    /// combine the two ("every 2 days on Monday" runs every Monday).
+    ///
+    /// # Fields
+    /// - `day_of_month`: The dropped day-of-month repetition.
+    /// - `day_of_week`: The days of the week that were kept.
+    AmbiguousDayFields {
+        day_of_month: String,
+        day_of_week: String,
+    },
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PastYear { year } => write!(f, "year {year} is in the past and will never fire"),
+            Self::ApproximatedPeriod {
+                phrase,
+                approximation,
+            } => write!(f, "{phrase} is approximated as {approximation}"),
+            Self::IgnoredToken { token } => write!(f, "{token} was ignored"),
+            Self::AmbiguousDayFields {
+                day_of_month,
+                day_of_week,
+            } => write!(
+                f,
+                "day of month {day_of_month} cannot be combined with day of week {day_of_week}, only the day of week is kept"
+            ),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use english_to_cron::{CronBuilder, Error, Month, Weekday};
+use rstest::rstest;
+
+#[rstest]
+#[case(CronBuilder::new(), "0 * * * * ? *")]
+#[case(CronBuilder::new().every_n_seconds(10), "0/10 * * * * ? *")]
+#[case(CronBuilder::new().every_n_minutes(15).on_weekdays(), "0 0/15 * ? * MON-FRI *")]
+#[case(CronBuilder::new().every_n_hours(2), "0 0 0/2 * * ? *")]
+#[case(CronBuilder::new().at_time(18, 0).on_weekdays(), "0 0 18 ? * MON-FRI *")]
+#[case(
+    CronBuilder::new().at_time(9, 30).on_days(&[Weekday::Monday, Weekday::Thursday]),
+    "0 30 9 ? * MON,THU *"
+)]
+#[case(
+    CronBuilder::new().at_time(0, 0).on_day_of_month(1).in_months(&[Month::January, Month::July]),
+    "0 0 0 1 JAN,JUL ? *"
+)]
+#[case(
+    CronBuilder::new().at_time(12, 0).on_day_of_month(25).in_months(&[Month::December]).in_year(2030),
+    "0 0 12 25 DEC ? 2030"
+)]
+#[case(CronBuilder::new().every_n_seconds(120), "0 0/2 * * * ? *")]
+#[case(CronBuilder::new().every_n_minutes(120), "0 0 0/2 * * ? *")]
+#[case(CronBuilder::new().every_n_hours(48), "0 0 0 */2 * ? *")]
+#[test]
+fn builds_expressions(#[case] builder: CronBuilder, #[case] expected: &str) {
+    assert_eq!(builder.build().unwrap().to_string(), expected);
+}
+
+#[rstest]
+#[case(CronBuilder::new().at_time(24, 0), "hour")]
+#[case(CronBuilder::new().at_time(9, 60), "minutes")]
+#[case(CronBuilder::new().every_n_minutes(0), "minutes")]
+#[case(CronBuilder::new().on_day_of_month(32), "day_of_month")]
+#[case(CronBuilder::new().in_year(1969), "year")]
+#[case(
+    CronBuilder::new().on_day_of_month(1).on_days(&[Weekday::Friday]),
+    "builder"
+)]
+#[test]
+fn rejects_invalid_fields(#[case] builder: CronBuilder, #[case] expected_state: &str) {
+    match builder.build() {
+        Err(Error::IncorrectValue { state, .. }) => assert_eq!(state, expected_state),
+        other => panic!("expected an incorrect value in {expected_state}, got {other:?}"),
+    }
+}
+
+#[rstest]
+#[case(CronBuilder::new().every_n_seconds(75), "every 75 seconds", "every 60 or 120 seconds")]
+#[case(CronBuilder::new().every_n_minutes(90), "every 90 minutes", "every 60 or 120 minutes")]
+#[case(
+    CronBuilder::new().every_n_hours(36),
+    "every 36 hours",
+    "every 24 or 48 hours"
+)]
+#[case(
+    CronBuilder::new().every_n_hours(48).on_day_of_month(1),
+    "every 48 hours",
+    "every 2 days"
+)]
+#[test]
+fn rejects_steps_that_cannot_be_carried(
+    #[case] builder: CronBuilder,
+    #[case] schedule: &str,
+    #[case] suggestion: &str,
+) {
+    assert_eq!(
+        builder.build(),
+        Err(Error::UnsupportedSchedule {
+            schedule: schedule.to_string(),
+            suggestion: suggestion.to_string(),
+        })
+    );
+}
@@ -0,0 +1,71 @@
+use english_to_cron::Cron;
+use rstest::rstest;
+
+#[rstest]
+#[case(
+    "every 15 minutes on weekdays",
+    "every 15 minutes on Monday through Friday"
+)]
+#[case(
+    "at 9am and 5pm on the 1st and 15th",
+    "at 09:00 and 17:00 on the 1st and 15th"
+)]
+#[case("every minute", "every minute")]
+#[case("every hour", "every hour")]
+#[case("every 2 hours", "every 2 hours")]
+#[case("every 15 seconds", "every 15 seconds")]
+#[case("every day at 4pm", "at 16:00 every day")]
+#[case("at 6:30 and 18:30 every 2 days", "at 06:30 and 18:30 every 2 days")]
+#[case(
+    "every 15 minutes between 9am and 5pm",
+    "every 15 minutes from 09:00 through 17:59"
+)]
+#[case(
+    "on the last day of the month",
+    "at 00:00 on the last day of the month"
+)]
+#[case(
+    "at noon on the second monday of the month",
+    "at 12:00 on the second Monday of the month"
+)]
+#[case(
+    "last friday of the month at 11pm",
+    "at 23:00 on the last Friday of the month"
+)]
+#[case("every 3 months", "at 00:00 on the 1st every 3 months")]
+#[case("at 10:15 in january and july", "at 10:15 in January and July")]
+#[test]
+fn describes_english(#[case] input: &str, #[case] expected: &str) {
+    let cron = Cron::new(input).unwrap();
+
+    assert_eq!(cron.describe(), expected);
+}
+
+#[rstest]
+#[case("* * * * * ? *", "every second")]
+#[case("0 30 * * * ? *", "at minute 30 every hour")]
+#[case(
+    "0 0 9-17 ? * MON-FRI *",
+    "every hour from 09:00 through 17:00 on Monday through Friday"
+)]
+#[case("0 0,30 * * * ? *", "at minutes 0 and 30")]
+#[case("0 0/10 9 * * ? *", "every 10 minutes during the 09:00 hour")]
+#[case("30 15 8 * * ? *", "at 08:15:30")]
+#[case("0 0 12 15W * ? *", "at 12:00 on the weekday nearest the 15th")]
+#[case("0 0 12 LW * ? *", "at 12:00 on the last weekday of the month")]
+#[case("0 0 12 1-7 * ? *", "at 12:00 on the 1st through the 7th")]
+#[case("0 0 12 ? * SAT,SUN *", "at 12:00 on Saturday and Sunday")]
+#[case(
+    "0 0 0 1 JAN-MAR ? 2030",
+    "at 00:00 on the 1st in January through March in 2030"
+)]
+#[case(
+    "0 0 0 1 1 ? 2025/2",
+    "at 00:00 on the 1st in January every 2 years starting in 2025"
+)]
+#[test]
+fn describes_cron(#[case] input: &str, #[case] expected: &str) {
+    let cron = Cron::parse_cron(input).unwrap();
+
+    assert_eq!(cron.describe(), expected);
+}
@@ -0,0 +1,90 @@
+use english_to_cron::{Cron, CronDialect, Error};
+use rstest::rstest;
+
+#[rstest]
+#[case("every minute", "* * * * *")]
+#[case("every 15 minutes", "0-59/15 * * * *")]
+#[case("every day at 4:00 pm", "0 16 */1 * *")]
+#[case("Run at 6:00 pm every Monday through Friday", "0 18 * * 1-5")]
+#[case("at 10:00 am on Sunday and Saturday", "0 10 * * 6,0")]
+#[case("midnight on the 1st and 15th", "0 0 1,15 * *")]
+#[test]
+fn converts_to_unix(#[case] input: &str, #[case] expected: &str) {
+    let cron = Cron::new(input).unwrap();
+
+    assert_eq!(cron.to_unix().as_deref(), Ok(expected));
+    assert_eq!(cron.to_dialect(CronDialect::Unix).as_deref(), Ok(expected));
+}
+
+#[rstest]
+#[case("every 10 seconds", "0/10 in seconds")]
+#[case("at 10:00 am in 2030", "2030 in year")]
+#[case("at 10:00 am on the last day of the month", "L in day_of_month")]
+#[case("at 10:00 am on the last Friday of the month", "FRIL in day_of_week")]
+#[test]
+fn rejects_features_missing_from_unix(#[case] input: &str, #[case] feature: &str) {
+    let cron = Cron::new(input).unwrap();
+
+    assert_eq!(
+        cron.to_unix(),
+        Err(Error::DialectUnsupported {
+            dialect: CronDialect::Unix,
+            feature: feature.to_string(),
+        })
+    );
+}
+
+#[test]
+fn rejects_wrapping_ranges_in_unix() {
+    let mut cron = Cron::new("at 10:00 am").unwrap();
+    cron.syntax.day_of_week = "FRI-MON".to_string();
+    cron.syntax.day_of_month = "?".to_string();
+
+    assert!(matches!(
+        cron.to_unix(),
+        Err(Error::DialectUnsupported {
+            dialect: CronDialect::Unix,
+            ..
+        })
+    ));
+}
+
+#[rstest]
+#[case(CronDialect::Quartz, "0 0 10 */1 * ? *")]
+#[case(CronDialect::Unix, "0 10 */1 * *")]
+#[case(CronDialect::Systemd, "*-*-01/1 10:00:00")]
+#[test]
+fn converts_to_each_dialect(#[case] dialect: CronDialect, #[case] expected: &str) {
+    let cron = Cron::new("every day at 10:00 am").unwrap();
+
+    assert_eq!(cron.to_dialect(dialect).as_deref(), Ok(expected));
+}
+
+#[test]
+fn displays_dialect_errors() {
+    let error = Error::DialectUnsupported {
+        dialect: CronDialect::Unix,
+        feature: "L in day_of_month".to_string(),
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "Unix cron does not support: L in day_of_month"
+    );
+}
+
+#[test]
+fn displays_ambiguous_input() {
+    let error = Error::AmbiguousInput {
+        input: "bimonthly".to_string(),
+        alternatives: vec![
+            "0 0 0 1 */2 ? *".to_string(),
+            "0 0 0 1,15 * ? *".to_string(),
+        ],
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "bimonthly is ambiguous, it could mean any of: 0 0 0 1 */2 ? *, 0 0 0 1,15 * ? *"
+    );
+}
@@ -0,0 +1,97 @@
+use english_to_cron::{str_cron_syntax, str_cron_syntax_from_iso8601, Cron, Error, Warning};
+use rstest::rstest;
+
+#[rstest]
+#[case("PT30S", "0/30 * * * * ? *")]
+#[case("PT1S", "0/1 * * * * ? *")]
+#[case("PT15M", "0 0/15 * * * ? *")]
+#[case("PT1M", "0 0/1 * * * ? *")]
+#[case("PT1H", "0 0 0/1 * * ? *")]
+#[case("PT6H", "0 0 0/6 * * ? *")]
+#[case("P1D", "0 0 0 */1 * ? *")]
+#[case("P2D", "0 0 0 */2 * ? *")]
+#[case("P1W", "0 0 0 ? * SUN *")]
+#[case("P2W", "0 0 0 */14 * ? *")]
+#[case("P1M", "0 0 0 1 */1 ? *")]
+#[case("P3M", "0 0 0 1 */3 ? *")]
+#[case("P1Y", "0 0 0 1 1 ? *")]
+#[case("P2Y", "0 0 0 1 1 ? */2")]
+#[case("PT60S", "0 0/1 * * * ? *")]
+#[case("PT60M", "0 0 0/1 * * ? *")]
+#[case("PT120M", "0 0 0/2 * * ? *")]
+#[case("PT24H", "0 0 0 */1 * ? *")]
+#[case("PT48H", "0 0 0 */2 * ? *")]
+#[case("P0DT15M", "0 0/15 * * * ? *")]
+#[case(" PT15M ", "0 0/15 * * * ? *")]
+#[test]
+fn converts_durations(#[case] duration: &str, #[case] expected: &str) {
+    assert_eq!(
+        str_cron_syntax_from_iso8601(duration).unwrap(),
+        expected,
+        "Failed for duration: '{duration}'"
+    );
+}
+
+#[rstest]
+#[case("PT15M", "every 15 minutes")]
+#[case("PT30S", "every 30 seconds")]
+#[case("PT2H", "every 2 hours")]
+#[case("P3D", "every 3 days")]
+#[case("P2W", "every 2 weeks")]
+#[case("P6M", "every 6 months")]
+#[case("P1Y", "every year")]
+#[case("P2Y", "every 2 years")]
+#[case("PT60M", "every 60 minutes")]
+#[case("PT24H", "every 24 hours")]
+#[case("PT90M", "every 90 minutes")]
+#[test]
+fn matches_english(#[case] duration: &str, #[case] english: &str) {
+    assert_eq!(
+        str_cron_syntax_from_iso8601(duration),
+        str_cron_syntax(english)
+    );
+}
+
+#[rstest]
+#[case("")]
+#[case("P")]
+#[case("PT")]
+#[case("PT0S")]
+#[case("15M")]
+#[case("P1Y6M")]
+#[case("PT1H30M")]
+#[case("P1DT12H")]
+#[case("P5W")]
+#[case("P13M")]
+#[case("PT1.5H")]
+#[case("PT99999999999M")]
+#[case("every 15 minutes")]
+#[test]
+fn rejects_durations_cron_cannot_express(#[case] duration: &str) {
+    assert_eq!(
+        str_cron_syntax_from_iso8601(duration),
+        Err(Error::InvalidInput),
+        "Failed for duration: '{duration}'"
+    );
+}
+
+#[test]
+fn warns_about_approximated_weeks() {
+    let cron = Cron::from_iso8601("P2W").unwrap();
+    let english = Cron::new("every 2 weeks").unwrap();
+
+    assert_eq!(
+        cron.warnings,
+        vec![Warning::ApproximatedPeriod {
+            phrase: "P2W".to_string(),
+            approximation: "every 14 days (*/14 in day_of_month), restarting each month"
+                .to_string(),
+        }]
+    );
+    assert!(matches!(
+        english.warnings.as_slice(),
+        [Warning::ApproximatedPeriod { approximation, .. }]
+            if *approximation == "every 14 days (*/14 in day_of_month), restarting each month"
+    ));
+    assert!(Cron::from_iso8601("P1W").unwrap().warnings.is_empty());
+}
@@ -0,0 +1,84 @@
+use english_to_cron::{str_cron_syntax_multi, Cron, Error, MonthFormat, MultiCron, Options};
+use rstest::rstest;
+
+#[rstest]
+#[case("every day at 9:30am and 5pm", vec!["0 30 9 */1 * ? *", "0 0 17 */1 * ? *"])]
+#[case("at 9:30am and 5pm", vec!["0 30 9 * * ? *", "0 0 17 * * ? *"])]
+#[case(
+    "every Monday at 8:15, 9:30 and 12:15",
+    vec!["0 15 8,12 ? * MON *", "0 30 9 ? * MON *"]
+)]
+#[case("every day at 9am and 9pm", vec!["0 0 9,21 */1 * ? *"])]
+#[case("every 15 minutes", vec!["0 0/15 * * * ? *"])]
+#[test]
+fn splits_times_by_minute(#[case] input: &str, #[case] expected: Vec<&str>) {
+    let multi = str_cron_syntax_multi(input).unwrap();
+
+    assert_eq!(
+        multi.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        expected,
+        "Failed for input: '{input}'"
+    );
+    assert_eq!(multi.to_string(), expected.join("\n"));
+}
+
+#[test]
+fn iterates_over_crons() {
+    let crons: Vec<_> = str_cron_syntax_multi("at 9:30am and 5pm")
+        .unwrap()
+        .into_iter()
+        .map(|cron| cron.syntax.hour)
+        .collect();
+
+    assert_eq!(crons, vec!["9", "17"]);
+}
+
+#[test]
+fn splits_with_options() {
+    let mut options = Options::default();
+    options.month_format = MonthFormat::Numeric;
+    let multi = MultiCron::with_options("every day in June at 9:30am and 5pm", &options).unwrap();
+
+    assert_eq!(multi.to_string(), "0 30 9 */1 6 ? *\n0 0 17 */1 6 ? *");
+}
+
+#[rstest]
+#[case("every day at 9am and 9pm", Ok("0 0 9,21 */1 * ? *"))]
+#[case(
+    "every day at 9:30am and 5pm",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every day at 9:30am and 5pm".to_string(),
+        suggestion: "the separate schedules 0 30 9 */1 * ? * and 0 0 17 */1 * ? *".to_string(),
+    })
+)]
+#[test]
+fn single_cron_names_the_split(#[case] input: &str, #[case] expected: Result<&str, Error>) {
+    assert_eq!(
+        Cron::new(input).map(|cron| cron.to_string()),
+        expected.map(ToString::to_string)
+    );
+}
+
+#[rstest]
+#[case("", Error::InvalidInput)]
+#[case(
+    "bimonthly at 9:30am and 5pm",
+    Error::AmbiguousInput {
+        input: "bimonthly at 9:30am and 5pm".to_string(),
+        alternatives: vec![
+            "0 30 9 1 */2 ? *\n0 0 17 1 */2 ? *".to_string(),
+            "0 30 9 1,15 * ? *\n0 0 17 1,15 * ? *".to_string(),
+        ],
+    }
+)]
+#[case(
+    "at 9:30:75 and 5pm",
+    Error::IncorrectValue {
+        state: "clock_time".to_string(),
+        error: "second 75 should be between 0 and 59".to_string(),
+    }
+)]
+#[test]
+fn keeps_errors(#[case] input: &str, #[case] expected: Error) {
+    assert_eq!(str_cron_syntax_multi(input).unwrap_err(), expected);
+}
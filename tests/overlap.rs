@@ -0,0 +1,58 @@
+use english_to_cron::{Cron, CronField, FieldPosition};
+use rstest::rstest;
+
+#[rstest]
+#[case("every 15 minutes", "every Monday at 9:30am", true)]
+#[case("every 15 minutes", "every Monday at 9:10pm", false)]
+#[case("every day at 9am", "every Monday at 9am", true)]
+#[case("every day at 9am", "every Monday at 10am", false)]
+#[case(
+    "Run at 6:00 pm every Monday through Friday",
+    "on Sunday at 6:00 pm",
+    false
+)]
+#[case(
+    "Run at 6:00 pm every Monday through Friday",
+    "on Friday at 6:00 pm",
+    true
+)]
+#[case("every day in January", "every day in February", false)]
+#[case("every 5 minutes between 9am and 5pm", "at 5pm", true)]
+#[case("on the last day of the month", "on the 30th day at midnight", true)]
+#[case("on the last day of the month", "on the 15th day at midnight", false)]
+#[test]
+fn detects_overlapping_schedules(
+    #[case] first: &str,
+    #[case] second: &str,
+    #[case] expected: bool,
+) {
+    let first = Cron::new(first).unwrap();
+    let second = Cron::new(second).unwrap();
+
+    assert_eq!(first.overlaps_with(&second), expected);
+    assert_eq!(second.overlaps_with(&first), expected);
+}
+
+#[rstest]
+#[case(FieldPosition::Minutes, "*", "59", true)]
+#[case(FieldPosition::Minutes, "0/15", "10,20", false)]
+#[case(FieldPosition::Minutes, "0/15", "10-20", true)]
+#[case(FieldPosition::Hour, "22-2", "1", true)]
+#[case(FieldPosition::Hour, "9-17/2", "10", false)]
+#[case(FieldPosition::DayOfMonth, "?", "31", true)]
+#[case(FieldPosition::DayOfWeek, "MON-FRI", "SAT,SUN", false)]
+#[case(FieldPosition::DayOfWeek, "mon#2", "2", true)]
+#[case(FieldPosition::DayOfWeek, "FRIL", "MON-THU", false)]
+#[case(FieldPosition::Month, "JAN-MAR", "3", true)]
+#[test]
+fn intersects_fields(
+    #[case] field: FieldPosition,
+    #[case] first: &str,
+    #[case] second: &str,
+    #[case] expected: bool,
+) {
+    let first = CronField::parse(field, first).unwrap();
+    let second = CronField::parse(field, second).unwrap();
+
+    assert_eq!(first.intersects(&second), expected);
+}
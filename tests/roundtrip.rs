@@ -0,0 +1,219 @@
+use english_to_cron::{Clock, Cron, Options};
+use proptest::{
+    prelude::*,
+    sample::{select, subsequence},
+    test_runner::{Config, RngAlgorithm, TestError, TestRng, TestRunner},
+};
+
+/// Options with the year fixed, so that the years drawn below stay in the future.
+fn options() -> Options {
+    let mut options = Options::default();
+    options.clock = Clock::Fixed(2025);
+    options
+}
+
+/// The seed every run starts from, so that a failure shows up again on the next run.
+const SEED: [u8; 32] = *b"english-to-cron round trip seed!";
+
+const DAYS: [&str; 7] = ["MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"];
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const DAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+fn runner() -> TestRunner {
+    let config = Config {
+        cases: 256,
+        failure_persistence: None,
+        ..Config::default()
+    };
+    TestRunner::new_with_rng(config, TestRng::from_seed(RngAlgorithm::ChaCha, &SEED))
+}
+
+/// Runs a property, printing the smallest input it fails for.
+fn check<S: Strategy>(strategy: &S, test: impl Fn(&S::Value) -> Result<(), String>)
+where
+    S::Value: std::fmt::Debug,
+{
+    let result = runner().run(strategy, |value| test(&value).map_err(TestCaseError::fail));
+    match result {
+        Ok(()) => {}
+        Err(TestError::Fail(reason, value)) => {
+            panic!("minimal failing case: {value:?}\n{reason}")
+        }
+        Err(error) => panic!("{error}"),
+    }
+}
+
+/// Renders a list of values picked from `names`, in order.
+fn list(
+    names: &'static [&'static str],
+    len: std::ops::RangeInclusive<usize>,
+) -> BoxedStrategy<String> {
+    subsequence(names, len)
+        .prop_map(|names| names.join(","))
+        .boxed()
+}
+
+/// The seconds, minutes, and hours of an expression.
+fn time_fields() -> impl Strategy<Value = String> {
+    prop_oneof![
+        (0..60u32, subsequence((0..24).collect::<Vec<u32>>(), 1..=3)).prop_map(
+            |(minute, hours)| {
+                let hours: Vec<_> = hours.iter().map(ToString::to_string).collect();
+                format!("0 {minute} {}", hours.join(","))
+            }
+        ),
+        select(vec![2, 3, 4, 5, 6, 10, 12, 15, 20, 30]).prop_map(|step| format!("0 0/{step} *")),
+        select(vec![2, 3, 4, 6, 8, 12]).prop_map(|step| format!("0 0 0/{step}")),
+    ]
+}
+
+/// The day-of-month and day-of-week fields of an expression.
+fn day_fields() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("* ?".to_string()),
+        Just("? MON-FRI".to_string()),
+        list(&DAYS, 1..=3).prop_map(|days| format!("? {days}")),
+        subsequence((1..=28).collect::<Vec<u32>>(), 1..=3).prop_map(|days| {
+            let days: Vec<_> = days.iter().map(ToString::to_string).collect();
+            format!("{} ?", days.join(","))
+        }),
+    ]
+}
+
+/// The month field of an expression.
+fn month_field() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("*".to_string()),
+        list(&MONTHS, 1..=3),
+        (0..6usize, 1..6usize).prop_map(|(start, length)| {
+            format!("{}-{}", MONTHS[start], MONTHS[start + length])
+        }),
+    ]
+}
+
+/// The year field of an expression.
+fn year_field() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("*".to_string()),
+        (2030..2100u32).prop_map(|year| year.to_string())
+    ]
+}
+
+/// A Quartz expression whose fields are drawn from the shapes that can be described.
+fn expression() -> impl Strategy<Value = String> {
+    (time_fields(), day_fields(), month_field(), year_field()).prop_map(
+        |(time, days, month, year)| {
+            let (day_of_month, day_of_week) = days.split_once(' ').unwrap();
+            format!("{time} {day_of_month} {month} {day_of_week} {year}")
+        },
+    )
+}
+
+/// An English phrase built from a small grammar of clauses.
+fn phrase() -> impl Strategy<Value = String> {
+    let time = prop_oneof![
+        Just(String::new()),
+        select(vec![1, 2, 5, 10, 15, 20, 30]).prop_map(|step| format!("every {step} minutes")),
+        select(vec![1, 2, 3, 4, 6, 12]).prop_map(|step| format!("every {step} hours")),
+        (0..24u32, 0..60u32).prop_map(|(hour, minute)| format!("at {hour}:{minute:02}")),
+        (1..=12u32, select(vec!["am", "pm"]))
+            .prop_map(|(hour, meridiem)| format!("at {hour}{meridiem}")),
+    ];
+    let days = prop_oneof![
+        Just(String::new()),
+        Just("every day".to_string()),
+        Just("on weekdays".to_string()),
+        Just("on weekends".to_string()),
+        select(DAY_NAMES.to_vec()).prop_map(|day| format!("on {day}")),
+        (1..=14u32, 15..=28u32).prop_map(|(first, second)| {
+            format!("on the {} and {}", ordinal(first), ordinal(second))
+        }),
+    ];
+    let months = prop_oneof![
+        Just(String::new()),
+        select(MONTH_NAMES.to_vec()).prop_map(|month| format!("in {month}")),
+        (0..6usize, 1..6usize).prop_map(|(start, length)| {
+            format!(
+                "from {} to {}",
+                MONTH_NAMES[start],
+                MONTH_NAMES[start + length]
+            )
+        }),
+    ];
+    (time, days, months)
+        .prop_map(|(time, days, months)| {
+            [time, days, months]
+                .into_iter()
+                .filter(|clause| !clause.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .prop_filter("an empty phrase", |phrase| !phrase.is_empty())
+}
+
+fn ordinal(day: u32) -> String {
+    let suffix = match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{day}{suffix}")
+}
+
+#[test]
+fn describes_expressions_that_read_back_the_same() {
+    check(&expression(), |expression| {
+        let cron = Cron::parse_cron(expression).map_err(|error| error.to_string())?;
+        let description = cron.describe();
+        let read_back = Cron::with_options(&description, &options()).map_err(|error| {
+            format!("{expression} is described as \"{description}\", which fails with {error}")
+        })?;
+        if read_back == cron {
+            Ok(())
+        } else {
+            Err(format!(
+                "{expression} is described as \"{description}\", which reads back as {read_back}"
+            ))
+        }
+    });
+}
+
+#[test]
+fn reads_phrases_into_valid_schedules() {
+    check(&phrase(), |phrase| {
+        let cron = Cron::with_options(phrase, &options())
+            .map_err(|error| format!("\"{phrase}\" fails with {error}"))?;
+        cron.validate_with(&options())
+            .map(|_| ())
+            .map_err(|error| format!("\"{phrase}\" reads as {cron}, which fails with {error}"))
+    });
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "serde")]
+
+use english_to_cron::{Cron, Error, Syntax};
+use rstest::rstest;
+use serde_json::json;
+
+#[test]
+fn serializes_cron_as_its_fields() {
+    let cron = Cron::new("Run at 6:00 pm every Monday through Friday").unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&cron).unwrap(),
+        json!({
+            "syntax": {
+                "seconds": "0",
+                "minutes": "0",
+                "hour": "18",
+                "day_of_month": "?",
+                "day_of_week": "MON-FRI",
+                "month": "*",
+                "year": "*",
+            }
+        })
+    );
+}
+
+#[rstest]
+#[case("every 15 seconds")]
+#[case("every day at 4:00 pm")]
+#[case("Run at midnight on the 1st and 15th of the month")]
+#[case("on the last Friday of the month at 5pm")]
+#[test]
+fn round_trips_through_json(#[case] input: &str) {
+    let cron = Cron::new(input).unwrap();
+
+    let syntax: Syntax =
+        serde_json::from_str(&serde_json::to_string(&cron.syntax).unwrap()).unwrap();
+    let restored: Cron = serde_json::from_str(&serde_json::to_string(&cron).unwrap()).unwrap();
+
+    let from_syntax = Cron {
+        syntax,
+        ..Cron::default()
+    };
+    assert_eq!(
+        from_syntax.to_string(),
+        cron.to_string(),
+        "Failed for input: '{input}'"
+    );
+    assert_eq!(
+        restored.to_string(),
+        cron.to_string(),
+        "Failed for input: '{input}'"
+    );
+}
+
+#[test]
+fn serializes_errors() {
+    let error = Cron::new("at noon on the 1st and 32nd of the month").unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(&error).unwrap(),
+        json!({
+            "IncorrectValue": {
+                "state": "day",
+                "error": "day of month 32 should be between 1 and 31",
+            }
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<Error>(serde_json::to_value(&error).unwrap()).unwrap(),
+        error
+    );
+}
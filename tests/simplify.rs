@@ -0,0 +1,109 @@
+use english_to_cron::{Cron, FieldPosition};
+use rstest::rstest;
+
+#[rstest]
+#[case("0 0/60 * * * ? *", "0 0 * * * ? *")]
+#[case("0 0 */24 * * ? *", "0 0 0 * * ? *")]
+#[case("0 0/1 * * * ? *", "0 * * * * ? *")]
+#[case("0 0 0 */1 * ? *", "0 0 0 * * ? *")]
+#[case("0/1 * * * * ? *", "* * * * * ? *")]
+#[case("0 5/1 * * * ? *", "0 5-59 * * * ? *")]
+#[case("0 0 9-17/10 * * ? *", "0 0 9 * * ? *")]
+#[case("0 0 9-9 * * ? *", "0 0 9 * * ? *")]
+#[case("0 0 9 ? * MON,TUE,WED,THU,FRI *", "0 0 9 ? * MON-FRI *")]
+#[case("0 0 9 ? * SUN,MON,TUE,WED,THU,FRI,SAT *", "0 0 9 ? * * *")]
+#[case("0 0 1,2,3,5 * * ? *", "0 0 1-3,5 * * ? *")]
+#[case("0 0 0 1,2,3 JAN,FEB,MAR,APR ? *", "0 0 0 1-3 JAN-APR ? *")]
+#[case("0 0 0 ? * 2,3,4,5,6 *", "0 0 0 ? * 2-6 *")]
+#[case("0 0 0 1 1,2,3,12 ? *", "0 0 0 1 1-3,12 ? *")]
+#[case("0 0 0 ? * MON,3,4 *", "0 0 0 ? * MON-WED *")]
+#[test]
+fn simplifies_fields(#[case] input: &str, #[case] expected: &str) {
+    let cron = Cron::parse_cron(input).unwrap();
+
+    assert_eq!(cron.simplify().to_string(), expected);
+}
+
+#[rstest]
+#[case("0 0/15 * * * ? *")]
+#[case("0 0 9 ? * MON,TUE *")]
+#[case("0 0 9 ? * 2,3 *")]
+#[case("0 0 22-2/2 * * ? *")]
+#[case("0 0 12 L * ? *")]
+#[case("0 0 10 ? * MON#2 *")]
+#[case("0 0 9-17 ? * MON-FRI 2024-2030")]
+#[test]
+fn keeps_simple_fields(#[case] input: &str) {
+    let cron = Cron::parse_cron(input).unwrap();
+
+    assert_eq!(cron.simplify().to_string(), input);
+}
+
+#[rstest]
+#[case("every 60 minutes", "0 0 * * * ? *")]
+#[case("every day at noon", "0 0 12 * * ? *")]
+#[case("every 1 minutes", "0 * * * * ? *")]
+#[case("at 9am on Monday, Tuesday and Wednesday", "0 0 9 ? * MON-WED *")]
+#[test]
+fn simplifies_english(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(Cron::new(input).unwrap().simplify().to_string(), expected);
+}
+
+#[test]
+fn keeps_explicit_fields() {
+    let cron = Cron::new("every day at noon").unwrap();
+    let simplified = cron.simplify();
+
+    for field in FieldPosition::ALL {
+        assert_eq!(
+            simplified.syntax.is_explicit(field),
+            cron.syntax.is_explicit(field)
+        );
+    }
+}
+
+#[rstest]
+#[case("0 0 * * * ? *", "0 0 */1 * * ? *")]
+#[case("0 0 9 ? * MON-FRI *", "0 0 9 ? * 2-6 *")]
+#[case("0 0 9 ? * MON,TUE,WED,THU,FRI *", "0 0 9 ? * mon-fri *")]
+#[case("0 0/60 * * * ? *", "0 0 * * * ? *")]
+#[case("0 0 0 1 JAN,FEB,MAR ? *", "0 0 0 1 1-3 ? *")]
+#[case("0 0 0 * * ? *", "0 0 0 ? * * *")]
+#[case("0 0 9 ? * MON,WED,FRI *", "0 0 9 ? * FRI,MON,WED *")]
+#[case("0 0 1,2 * * ? *", "0 0 1-2 * * ? *")]
+#[case("0 0 0/12 * * ? *", "0 0 0,12 * * ? *")]
+#[case("0 0 9-10 * * ? *", "0 0 9,10 * * ? *")]
+#[case("0 0 22-2/2 * * ? *", "0 0 0,2,22 * * ? *")]
+#[test]
+fn compares_semantically(#[case] left: &str, #[case] right: &str) {
+    let left = Cron::parse_cron(left).unwrap();
+    let right = Cron::parse_cron(right).unwrap();
+
+    assert!(left.eq_semantically(&right));
+    assert_eq!(left, right);
+    assert!(!left.eq_literal(&right));
+}
+
+#[rstest]
+#[case("0 0 9 ? * MON-FRI *", "0 0 9 ? * MON-SAT *")]
+#[case("0 0/15 * * * ? *", "0 0/30 * * * ? *")]
+#[case("0 0 12 L * ? *", "0 0 12 LW * ? *")]
+#[case("0 0 12 L * ? *", "0 0 12 28-31 * ? *")]
+#[case("0 0 12 ? * MON#2 *", "0 0 12 ? * MON *")]
+#[case("0 0 22-2/2 * * ? *", "0 0 22-2/3 * * ? *")]
+#[test]
+fn tells_different_schedules_apart(#[case] left: &str, #[case] right: &str) {
+    let left = Cron::parse_cron(left).unwrap();
+    let right = Cron::parse_cron(right).unwrap();
+
+    assert_ne!(left, right);
+    assert!(!left.eq_literal(&right));
+}
+
+#[test]
+fn compares_english_with_expressions() {
+    let cron = Cron::new("every 60 minutes").unwrap();
+
+    assert_eq!(cron, Cron::parse_cron("0 0 * * * ? *").unwrap());
+    assert!(cron.eq_literal(&Cron::parse_cron("0 0 0/1 * * ? *").unwrap()));
+}
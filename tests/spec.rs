@@ -0,0 +1,198 @@
+use english_to_cron::{Cron, Error, FieldItem, FieldPosition, FieldSpec, ScheduleSpec};
+use rstest::rstest;
+
+#[rstest]
+#[case("every day at 4:00 pm", "0 0 16 */1 * ? *")]
+#[case("Run every 15 minutes", "0 0/15 * * * ? *")]
+#[case("Run at 6:00 pm every Monday through Friday", "0 0 18 ? * 2-6 *")]
+#[case("Run at midnight on the 1st and 15th of the month", "0 0 0 1,15 * ?")]
+#[case("Run every day from January to March", "0 0 0 */1 jan-mar ? *")]
+#[test]
+fn english_and_cron_produce_equal_specs(#[case] english: &str, #[case] cron: &str) {
+    assert_eq!(
+        ScheduleSpec::from_english(english),
+        ScheduleSpec::from_cron(cron),
+        "Failed for input: '{english}'"
+    );
+}
+
+#[test]
+fn parses_typed_fields() {
+    let spec = ScheduleSpec::from_cron("0 0/15 9-17 L * ? *").unwrap();
+
+    assert_eq!(spec.seconds, FieldSpec::List(vec![FieldItem::Value(0)]));
+    assert_eq!(
+        spec.minutes,
+        FieldSpec::List(vec![FieldItem::Step {
+            start: Some(0),
+            end: None,
+            step: 15,
+        }])
+    );
+    assert_eq!(
+        spec.hour,
+        FieldSpec::List(vec![FieldItem::Range { start: 9, end: 17 }])
+    );
+    assert_eq!(
+        spec.day_of_month,
+        FieldSpec::List(vec![FieldItem::Special("L".to_string())])
+    );
+    assert_eq!(spec.month, FieldSpec::Any);
+    assert_eq!(spec.day_of_week, FieldSpec::NoSpecificValue);
+
+    let spec = ScheduleSpec::from_cron("0 0 9 ? * MON#2 *").unwrap();
+    assert_eq!(
+        spec.day_of_week,
+        FieldSpec::List(vec![FieldItem::Special("MON#2".to_string())])
+    );
+}
+
+#[rstest]
+#[case("every day at 4:00 pm", "0 0 16 */1 * ? *")]
+#[case("Run at 6:00 pm every Monday through Friday", "0 0 18 ? * MON-FRI *")]
+#[case("Run every day from January to March", "0 0 0 */1 JAN-MAR ? *")]
+#[test]
+fn renders_back_to_cron(#[case] english: &str, #[case] expected: &str) {
+    let spec = ScheduleSpec::from_english(english).unwrap();
+
+    assert_eq!(spec.to_string(), expected);
+}
+
+#[rstest]
+#[case(
+    "0 0 25 * * ? *",
+    Error::IncorrectValue {
+        state: "hour".to_string(),
+        error: "hour 25 should be between 0 and 23".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 * * ? * *",
+    Error::IncorrectValue {
+        state: "cron".to_string(),
+        error: "expected 6 or 7 fields, found 8".to_string(),
+    }
+)]
+#[case(
+    "0 x * * * ? *",
+    Error::ParseToNumber {
+        state: "minutes".to_string(),
+        value: "X".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 ? * FOOL *",
+    Error::ParseToNumber {
+        state: "day_of_week".to_string(),
+        value: "FOO".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 ? * XYZ#2 *",
+    Error::ParseToNumber {
+        state: "day_of_week".to_string(),
+        value: "XYZ".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 ? * MON#9 *",
+    Error::IncorrectValue {
+        state: "day_of_week".to_string(),
+        error: "MON#9 is not a valid day_of_week".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 ? * L#2 *",
+    Error::ParseToNumber {
+        state: "day_of_week".to_string(),
+        value: "L".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 45W * ? *",
+    Error::IncorrectValue {
+        state: "day_of_month".to_string(),
+        error: "45W is not a valid day_of_month".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 L-40 * ? *",
+    Error::IncorrectValue {
+        state: "day_of_month".to_string(),
+        error: "L-40 is not a valid day_of_month".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 LX * ? *",
+    Error::IncorrectValue {
+        state: "day_of_month".to_string(),
+        error: "LX is not a valid day_of_month".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 W * ? *",
+    Error::IncorrectValue {
+        state: "day_of_month".to_string(),
+        error: "W is not a valid day_of_month".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 L#2 * ? *",
+    Error::IncorrectValue {
+        state: "day_of_month".to_string(),
+        error: "L#2 is not a valid day_of_month".to_string(),
+    }
+)]
+#[case(
+    "0 0/90 * * * ? *",
+    Error::IncorrectValue {
+        state: "minutes".to_string(),
+        error: "step in 0/90 should be at most 60".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 ? * ? *",
+    Error::IncorrectValue {
+        state: "day_of_week".to_string(),
+        error: "? cannot be used in both day fields".to_string(),
+    }
+)]
+#[case(
+    "0 0 0 1 * MON *",
+    Error::IncorrectValue {
+        state: "day_of_week".to_string(),
+        error: "one of the day fields should be ?".to_string(),
+    }
+)]
+#[test]
+fn rejects_invalid_cron(#[case] input: &str, #[case] expected: Error) {
+    assert_eq!(ScheduleSpec::from_cron(input), Err(expected.clone()));
+    assert_eq!(
+        Cron::parse_cron(input).map(|cron| cron.to_string()),
+        Err(expected)
+    );
+}
+
+#[rstest]
+#[case("0 0 16 */1 * ? *", "0 0 16 */1 * ? *")]
+#[case("0 0/15 9-17 ? * mon-fri *", "0 0/15 9-17 ? * MON-FRI *")]
+#[case("0 0 0 L * ? 2030", "0 0 0 L * ? 2030")]
+#[case("0 30 8 ? jan,jul MON#2", "0 30 8 ? JAN,JUL MON#2 *")]
+#[test]
+fn parses_cron_into_cron(#[case] input: &str, #[case] expected: &str) {
+    let cron = Cron::parse_cron(input).unwrap();
+
+    assert_eq!(cron.to_string(), expected);
+}
+
+#[test]
+fn parsed_cron_fields_are_explicit() {
+    let cron = Cron::parse_cron("0 0 16 */1 * ? *").unwrap();
+
+    assert_eq!(cron.explicit_fields(), FieldPosition::ALL);
+}
+
+#[test]
+fn parse_cron_does_not_read_english() {
+    assert!(Cron::parse_cron("every day at 4:00 pm").is_err());
+}
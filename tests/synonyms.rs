@@ -0,0 +1,87 @@
+use english_to_cron::{Cron, Error, Options, Tokenizer};
+use rstest::rstest;
+use std::collections::HashMap;
+
+fn tokenizer() -> Tokenizer {
+    Tokenizer::with_synonyms(HashMap::from([
+        ("every sprint".to_string(), "every 2 weeks".to_string()),
+        (
+            "every standup".to_string(),
+            "every weekday at 9:30am".to_string(),
+        ),
+        ("standup".to_string(), "9:30am".to_string()),
+        (
+            "payday".to_string(),
+            "the last day of the month".to_string(),
+        ),
+    ]))
+    .unwrap()
+}
+
+#[rstest]
+#[case("every standup", "0 30 9 ? * MON-FRI *")]
+#[case("Every Standup", "0 30 9 ? * MON-FRI *")]
+#[case("at standup on Friday", "0 30 9 ? * FRI *")]
+#[case("every sprint", "0 0 0 */14 * ? *")]
+#[case("on payday at 5pm", "0 0 17 L * ? *")]
+#[case("every day at 4:00 pm", "0 0 16 */1 * ? *")]
+#[test]
+fn rewrites_synonyms(#[case] input: &str, #[case] expected: &str) {
+    let cron = Cron::with_tokenizer(input, &tokenizer(), &Options::default()).unwrap();
+
+    assert_eq!(cron.to_string(), expected, "Failed for input: '{input}'");
+}
+
+#[test]
+fn matches_whole_words_only() {
+    let tokenizer =
+        Tokenizer::with_synonyms(HashMap::from([("day".to_string(), "Monday".to_string())]))
+            .unwrap();
+
+    assert_eq!(tokenizer.run("every day"), vec!["Monday"]);
+    assert_eq!(tokenizer.run("on Sunday"), vec!["Sunday"]);
+}
+
+#[test]
+fn does_not_rescan_replacements() {
+    let tokenizer = Tokenizer::with_synonyms(HashMap::from([
+        ("standup".to_string(), "retro".to_string()),
+        ("retro".to_string(), "Friday".to_string()),
+    ]))
+    .unwrap();
+
+    assert_eq!(tokenizer.run("on standup"), Vec::<String>::new());
+}
+
+#[test]
+fn empty_map_matches_the_default_tokenizer() {
+    let input = "Run at 6:00 pm every Monday through Friday";
+
+    assert_eq!(
+        Tokenizer::with_synonyms(HashMap::new()).unwrap().run(input),
+        Tokenizer::new().run(input)
+    );
+}
+
+#[test]
+fn clones_keep_synonyms() {
+    let original = tokenizer();
+    let clone = original.clone();
+    drop(original);
+
+    let cron = Cron::with_tokenizer("every standup", &clone, &Options::default()).unwrap();
+    assert_eq!(cron.to_string(), "0 30 9 ? * MON-FRI *");
+}
+
+#[test]
+fn rejects_phrases_too_large_to_match() {
+    let tokenizer = Tokenizer::with_synonyms(HashMap::from([(
+        "a".repeat(1_000_000),
+        "daily".to_string(),
+    )]));
+
+    assert!(matches!(
+        tokenizer,
+        Err(Error::IncorrectValue { state, .. }) if state == "synonyms"
+    ));
+}
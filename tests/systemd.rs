@@ -0,0 +1,92 @@
+use english_to_cron::{Cron, CronDialect, Error};
+use rstest::rstest;
+
+#[rstest]
+#[case("every day at 4:00 pm", "*-*-01/1 16:00:00")]
+#[case("at 10:00 am", "*-*-* 10:00:00")]
+#[case("Run every 15 minutes", "*-*-* *:00/15:00")]
+#[case("every 15 seconds", "*-*-* *:*:00/15")]
+#[case(
+    "Run at 6:00 pm every Monday through Friday",
+    "Mon..Fri *-*-* 18:00:00"
+)]
+#[case("2pm on Tuesday, Wednesday and Thursday", "Tue,Wed,Thu *-*-* 14:00:00")]
+#[case(
+    "Run at midnight on the 1st and 15th of the month",
+    "*-*-01,15 00:00:00"
+)]
+#[case("Run every day from January to March", "*-01..03-01/1 00:00:00")]
+#[case(
+    "every 2 day from January to August in 2020 and 2024",
+    "2020,2024-01..08-01/2 00:00:00"
+)]
+#[case("on even days", "*-*-02..30/2 00:00:00")]
+#[case("every 2 hours from 9am to 5pm", "*-*-* 09..17/2:00:00")]
+#[case("from Saturday to Sunday", "Sat..Sun *-*-* *:*:00")]
+#[test]
+fn can_convert_to_systemd_timer(#[case] input: &str, #[case] expected: &str) {
+    let cron = Cron::new(input).unwrap();
+    assert_eq!(
+        cron.to_systemd_timer(),
+        Ok(expected.to_string()),
+        "Failed for input: '{input}'"
+    );
+}
+
+#[rstest]
+#[case("L", "L in day_of_month")]
+#[case("15W", "15W in day_of_month")]
+#[test]
+fn rejects_quartz_only_features(#[case] day_of_month: &str, #[case] feature: &str) {
+    let mut cron = Cron::new("at 10:00 am").unwrap();
+    cron.syntax.day_of_month = day_of_month.to_string();
+
+    assert_eq!(
+        cron.to_systemd_timer(),
+        Err(Error::DialectUnsupported {
+            dialect: CronDialect::Systemd,
+            feature: feature.to_string(),
+        })
+    );
+}
+
+#[rstest]
+#[case("from noon to midnight", "12-0 in hour")]
+#[case("from 10pm to 2am", "22-2 in hour")]
+#[case("from Friday to Monday", "FRI-MON in day_of_week")]
+#[test]
+fn rejects_wrapping_ranges(#[case] input: &str, #[case] feature: &str) {
+    let cron = Cron::new(input).unwrap();
+
+    assert_eq!(
+        cron.to_systemd_timer(),
+        Err(Error::DialectUnsupported {
+            dialect: CronDialect::Systemd,
+            feature: feature.to_string(),
+        })
+    );
+}
+
+#[test]
+fn rejects_last_weekday() {
+    let cron = Cron::new("at 10:00 am on the last Friday of the month").unwrap();
+
+    assert_eq!(
+        cron.to_systemd_timer(),
+        Err(Error::DialectUnsupported {
+            dialect: CronDialect::Systemd,
+            feature: "FRIL in day_of_week".to_string(),
+        })
+    );
+}
+
+#[test]
+fn rejects_nth_weekday() {
+    let mut cron = Cron::new("at 10:00 am").unwrap();
+    cron.syntax.day_of_week = "MON#1".to_string();
+
+    assert!(matches!(
+        cron.to_systemd_timer(),
+        Err(Error::DialectUnsupported { .. })
+    ));
+}
@@ -1,10 +1,22 @@
-use english_to_cron::str_cron_syntax;
+use english_to_cron::{
+    str_cron_syntax, str_cron_syntax_lenient, str_cron_syntax_verbose, Clock, Cron, CronDialect,
+    DayOfWeekFormat, Error, FieldPosition, Meridiem, MonthFormat, Options, ScheduleSpec, Syntax,
+    Warning, WeekStart,
+};
 use rstest::rstest;
 
 #[rstest]
 // Seconds
 #[case("Run second", Ok("* * * * * ? *"))]
 #[case("every 5 second", Ok("0/5 * * * * ? *"))]
+#[case("every 59 seconds", Ok("0/59 * * * * ? *"))]
+#[case(
+    "every 75 seconds",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 75 seconds".to_string(),
+        suggestion: "every 60 or 120 seconds".to_string(),
+    })
+)]
 #[case("every 5 second on september", Ok("0/5 * * * SEP ? *"))]
 #[case("every 5 second on 9 month", Ok("0/5 * * * 9 ? *"))]
 #[case("Every 2 seconds, only on thursday", Ok("0/2 * * ? * THU *"))]
@@ -32,12 +44,114 @@ use rstest::rstest;
     Ok("0 0/5 6-9 ? * MON-THU *")
 )]
 #[case("Every 5 minutes, only on Friday", Ok("0 0/5 * ? * FRI *"))]
+// Windows within the hour
+#[case("every minute in the first half of the hour", Ok("0 0-29 * * * ? *"))]
+#[case("every minute in the second half of the hour", Ok("0 30-59 * * * ? *"))]
+#[case("every minute in the first 30 minutes", Ok("0 0-29 * * * ? *"))]
+#[case(
+    "every minute in the last 15 minutes of the hour",
+    Ok("0 45-59 * * * ? *")
+)]
+#[case(
+    "every 5 minutes in the first half of the hour",
+    Ok("0 0-29/5 * * * ? *")
+)]
+#[case("every second in the first half of the minute", Ok("0-29 * * * * ? *"))]
+#[case("every second in the last 10 seconds", Ok("50-59 * * * * ? *"))]
+// Ordinal minutes: "every 5th minute" repeats, "the 5th minute" is a single minute
+#[case("every 5th minute", Ok("0 0/5 * * * ? *"))]
+#[case("every 5th minute of every hour", Ok("0 0/5 * * * ? *"))]
+#[case("each 5th minute of the hour", Ok("0 0/5 * * * ? *"))]
+#[case("every 15th minute", Ok("0 0/15 * * * ? *"))]
+#[case("every 3rd minute on weekdays", Ok("0 0/3 * ? * MON-FRI *"))]
+#[case("every 20th minute between 9am and 5pm", Ok("0 0/20 9-17 * * ? *"))]
+#[case("every 10th second", Ok("0/10 * * * * ? *"))]
+#[case("every 2nd hour", Ok("0 0 0/2 * * ? *"))]
+#[case("on the 5th minute of every hour", Ok("0 5 * * * ? *"))]
+#[case("at the 5th minute of every hour", Ok("0 5 * * * ? *"))]
+#[case("the 30th minute of each hour", Ok("0 30 * * * ? *"))]
+#[case("on the 1st minute of the hour", Ok("0 1 * * * ? *"))]
+#[case("on the 10th second of every minute", Ok("10 * * * * ? *"))]
+#[case("at minute 30 of every hour", Ok("0 30 * * * ? *"))]
+#[case("on the 45th minute of each hour", Ok("0 45 * * * ? *"))]
+#[case("at minute 0 of every hour", Ok("0 0 * * * ? *"))]
+#[case("at minute 15 on weekdays", Ok("0 15 * ? * MON-FRI *"))]
+#[case(
+    "at minute 75 of every hour",
+    Err(Error::IncorrectValue {
+        state: "minute".to_string(),
+        error: "minute 75 should be between 0 and 59".to_string(),
+    })
+)]
+#[case("at second 15 of every minute", Ok("15 * * * * ? *"))]
+#[case("at the 30th second of each minute", Ok("30 * * * * ? *"))]
+#[case("at second 0 of every minute", Ok("0 * * * * ? *"))]
+#[case(
+    "at second 15 of every minute between 9am and 5pm",
+    Ok("15 * 9-17 * * ? *")
+)]
+#[case(
+    "at second 60 of every minute",
+    Err(Error::IncorrectValue {
+        state: "seconds".to_string(),
+        error: "second 60 should be between 0 and 59".to_string(),
+    })
+)]
+#[case(
+    "on the 60th minute",
+    Err(Error::IncorrectValue {
+        state: "minute".to_string(),
+        error: "minute 60 should be between 0 and 59".to_string(),
+    })
+)]
 // Hours
 #[case("Run every 3 hours", Ok("0 0 0/3 * * ? *"))]
+#[case("at 6, 12 and 18 hours", Ok("0 0 6,12,18 * * ? *"))]
+#[case("at 0, 6, 12 and 18 hours", Ok("0 0 0,6,12,18 * * ? *"))]
+#[case("every day at 0, 6, 12 and 18 hours", Ok("0 0 0,6,12,18 */1 * ? *"))]
+#[case("at 6 and 18 hours on weekdays", Ok("0 0 6,18 ? * MON-FRI *"))]
+#[case("from 9 to 17 hours", Ok("0 0 9-17 * * ? *"))]
+#[case(
+    "at 6, 12 and 30 hours",
+    Err(Error::IncorrectValue {
+        state: "hour".to_string(),
+        error: "hour 30 should be between 0 and 23".to_string(),
+    })
+)]
 #[case(
     "Run every 6 hours, starting at 1:00 pm on day Monday",
-    Ok("0 0 0/6 ? * MON *")
+    Ok("0 0 13/6 ? * MON *")
 )]
+#[case("every 2 hours starting at 9am", Ok("0 0 9/2 * * ? *"))]
+#[case("every 6 hours starting at 1pm", Ok("0 0 13/6 * * ? *"))]
+#[case("Run every 3 hours starting at midnight", Ok("0 0 0/3 * * ? *"))]
+#[case("every 3 hours starting at noon", Ok("0 0 12/3 * * ? *"))]
+#[case("every hour from noon to midnight", Ok("0 0 12-0 * * ? *"))]
+#[case("every 2 hours from midnight to noon", Ok("0 0 0-12/2 * * ? *"))]
+#[case("every 30 minutes from noon to 5pm", Ok("0 0/30 12-17 * * ? *"))]
+#[case("every 15 minutes starting at 9am", Ok("0 0/15 9-23 * * ? *"))]
+#[case("every 4 hours starting at 6:30", Ok("0 30 6/4 * * ? *"))]
+#[case("every 15 minutes starting at 5", Ok("0 5/15 * * * ? *"))]
+#[case("every 2 hours starting at 5", Ok("0 0 5/2 * * ? *"))]
+#[case("every 5 minutes starting at minute 2", Ok("0 2/5 * * * ? *"))]
+#[case("every 10 minutes starting at :07", Ok("0 7/10 * * * ? *"))]
+#[case(
+    "every 10 minutes starting at minute 7 on weekdays",
+    Ok("0 7/10 * ? * MON-FRI *")
+)]
+#[case("every 10 seconds starting at second 3", Ok("3/10 * * * * ? *"))]
+#[case("every 10 seconds starting at 3", Ok("3/10 * * * * ? *"))]
+#[case("every 10 seconds starting at 9am", Ok("0/10 * 9-23 * * ? *"))]
+#[case("every 5 minutes starting at 8:00 am", Ok("0 0/5 8-23 * * ? *"))]
+#[case("every 10 minutes starting at 8:05 am", Ok("0 5/10 8-23 * * ? *"))]
+#[case("every hour starting at 8:30am", Ok("0 30 8-23 * * ? *"))]
+#[case("every 5 minutes starting at 9am to 5pm", Ok("0 0/5 9-17 * * ? *"))]
+#[case(
+    "every 5 minutes starting at 9am ending at 5pm",
+    Ok("0 0/5 9-17 * * ? *")
+)]
+#[case("every 10 minutes ending at 5:00 pm", Ok("0 0/10 0-17 * * ? *"))]
+#[case("every 2 hours ending at 5pm", Ok("0 0 0-17/2 * * ? *"))]
 #[case("Run every 1 hour only on weekends", Ok("0 0 0/1 ? * SAT,SUN *"))]
 #[case("Run every hour only on weekends", Ok("0 0 * ? * SAT,SUN *"))]
 #[case(
@@ -57,19 +171,494 @@ use rstest::rstest;
 )]
 #[case("Run every day from January to March", Ok("0 0 0 */1 JAN-MAR ? *"))]
 #[case("Run every 3 days at noon", Ok("0 0 12 */3 * ? *"))]
+#[case("from Monday and Wednesday", Ok("0 * * ? * MON,WED *"))]
+#[case("between Monday and Wednesday", Ok("0 * * ? * MON-WED *"))]
+#[case("between Monday and Wednesday at 9am", Ok("0 0 9 ? * MON-WED *"))]
+#[case("monday wednesday friday", Ok("0 * * ? * MON,WED,FRI *"))]
+#[case("monday  wednesday   friday", Ok("0 * * ? * MON,WED,FRI *"))]
+#[case("monday, wednesday; friday", Ok("0 * * ? * MON,WED,FRI *"))]
+#[case("mon tue wed thu fri", Ok("0 * * ? * MON,TUE,WED,THU,FRI *"))]
+#[case("mon  tue  wed  thu  fri", Ok("0 * * ? * MON,TUE,WED,THU,FRI *"))]
+#[case("mon/tue/wed/thu/fri at 9am", Ok("0 0 9 ? * MON,TUE,WED,THU,FRI *"))]
+#[case("weekdays & saturday", Ok("0 * * ? * MON,TUE,WED,THU,FRI,SAT *"))]
 #[case("Run every 2nd day of the month", Ok("0 0 0 2 * ? *"))]
+#[case("on the 3rd of each month", Ok("0 0 0 3 * ? *"))]
+#[case("on the 31st of the month", Ok("0 0 0 31 * ? *"))]
+#[case("at noon from the 1st to the 15th", Ok("0 0 12 1-15 * ? *"))]
+#[case("from the 1st through the 15th at noon", Ok("0 0 12 1-15 * ? *"))]
+#[case(
+    "at noon from the 1st to the 15th of the month",
+    Ok("0 0 12 1-15 * ? *")
+)]
+#[case("at 6am between the 5th and the 10th", Ok("0 0 6 5-10 * ? *"))]
+#[case("at noon on the 1st and 15th", Ok("0 0 12 1,15 * ? *"))]
+#[case(
+    "on the 1st, 10th and 20th of the month at 7am",
+    Ok("0 0 7 1,10,20 * ? *")
+)]
+#[case("on the 1st, 10th, and 20th", Ok("0 * * 1,10,20 * ? *"))]
+#[case("on the 20th, 1st, 10th and 1st", Ok("0 * * 20,1,10 * ? *"))]
+#[case("on the 2nd, 10th, and 20th", Ok("0 * * 2,10,20 * ? *"))]
+#[case("on the 2nd and the 10th and the 20th", Ok("0 * * 2,10,20 * ? *"))]
+#[case(
+    "on the 2nd, the 10th, and the 20th of the month",
+    Ok("0 * * 2,10,20 * ? *")
+)]
+#[case("on the 1st and on the 15th", Ok("0 * * 1,15 * ? *"))]
+#[case("on the 1st day and 15th day", Ok("0 * * 1,15 * ? *"))]
+#[case(
+    "on the 1st, 8th, 15th, 22nd and 29th at 9am",
+    Ok("0 0 9 1,8,15,22,29 * ? *")
+)]
+#[case("at 6am between the 5th and 10th", Ok("0 0 6 5-10 * ? *"))]
+#[case(
+    "every day from the 5th to the 10th of each month at noon",
+    Ok("0 0 12 5-10 * ? *")
+)]
+#[case("every day between the 5th and 10th at noon", Ok("0 0 12 5-10 * ? *"))]
+#[case(
+    "from the 5th through the 10th of every month at 9am",
+    Ok("0 0 9 5-10 * ? *")
+)]
+#[case(
+    "between the 5th and the 10th of the month at 9am",
+    Ok("0 0 9 5-10 * ? *")
+)]
+#[case(
+    "from the 10th to the 5th",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month range 10-5 ends before it starts".to_string(),
+    })
+)]
+#[case(
+    "between the 10th and 5th",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month range 10-5 ends before it starts".to_string(),
+    })
+)]
+#[case("at midnight on the 1st and 15th day", Ok("0 0 0 1,15 * ? *"))]
+#[case(
+    "at 9am on the 1st, 2nd, 3rd, 4th, 5th, 6th, 7th, 8th, 9th and 10th",
+    Ok("0 0 9 1,2,3,4,5,6,7,8,9,10 * ? *")
+)]
+#[case(
+    "on the 1st, 10th and 40th",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month 40 should be between 1 and 31".to_string(),
+    })
+)]
+#[case(
+    "on the 45th day",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month 45 should be between 1 and 31".to_string(),
+    })
+)]
+#[case(
+    "at noon on the 1st and 32nd of the month",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month 32 should be between 1 and 31".to_string(),
+    })
+)]
+#[case("at noon on the 3rd of every month", Ok("0 0 12 3 * ? *"))]
+#[case("the 15th of every month at 3pm", Ok("0 0 15 15 * ? *"))]
+#[case("on the 15th of the month", Ok("0 0 0 15 * ? *"))]
+#[case("every month on the 15th", Ok("0 0 0 15 * ? *"))]
+#[case("every month on the 15th at 3pm", Ok("0 0 15 15 * ? *"))]
+#[case("the 15th at 3pm", Ok("0 0 15 15 * ? *"))]
+#[case("on the 15th", Ok("0 0 0 15 * ? *"))]
+#[case("on the 15th every 10 minutes", Ok("0 0/10 * 15 * ? *"))]
+// Days of the month and of the week together
+#[case(
+    "at noon on the 15th and on Monday",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month 15 and day of week MON cannot both be set".to_string(),
+    })
+)]
+#[case(
+    "on the 1st and 15th on weekdays",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month 1,15 and day of week MON-FRI cannot both be set".to_string(),
+    })
+)]
+#[case(
+    "on the last day of the month on Friday",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month L and day of week FRI cannot both be set".to_string(),
+    })
+)]
+#[case("every 2 days on Monday", Ok("0 0 0 ? * MON *"))]
+#[case("every day on Monday at noon", Ok("0 0 12 ? * MON *"))]
+// Too many clauses
+#[case(
+    "every 5 minutes on Monday and Tuesday at 9am in March in Q1 in 2030 every day every hour every week",
+    Err(Error::TooComplex { clauses: 9 })
+)]
+// Days from the end of the month
+#[case("on the last day of the month", Ok("0 0 0 L * ? *"))]
+#[case("3 days before the end of the month", Ok("0 0 0 L-3 * ? *"))]
+#[case("the next to last day of the month", Ok("0 0 0 L-1 * ? *"))]
+#[case("the second to last day of the month", Ok("0 0 0 L-1 * ? *"))]
+#[case("the penultimate day of the month", Ok("0 0 0 L-1 * ? *"))]
+#[case("the third to last day of the month", Ok("0 0 0 L-2 * ? *"))]
+#[case("third to last day of every month at 9am", Ok("0 0 9 L-2 * ? *"))]
+#[case(
+    "31 days before the end of the month",
+    Err(Error::IncorrectValue {
+        state: "last_day".to_string(),
+        error: "offset from the end of the month should be between 0 and 30, found 31".to_string(),
+    })
+)]
+#[case(
+    "the umpteenth to last day of the month",
+    Err(Error::IncorrectValue {
+        state: "last_day".to_string(),
+        error: "umpteenth is not a supported ordinal".to_string(),
+    })
+)]
+#[case("on the last Friday of the month", Ok("0 * * ? * FRIL *"))]
+#[case("on the last Monday", Ok("0 * * ? * MONL *"))]
+#[case("on the last Sunday of every month", Ok("0 * * ? * SUNL *"))]
+#[case("last sunday of every month at 9am", Ok("0 0 9 ? * SUNL *"))]
+#[case("at 5pm on the last fri of each month", Ok("0 0 17 ? * FRIL *"))]
+#[case("the last Saturday of the month in March", Ok("0 * * ? MAR SATL *"))]
+// Repeated units
+#[case("every 5 minutes every day", Ok("0 0/5 * * * ? *"))]
+#[case("every hour every day", Ok("0 0 * * * ? *"))]
+#[case("every 30 seconds every day", Ok("0/30 * * * * ? *"))]
+#[case("every day every 5 minutes", Ok("0 0/5 * */1 * ? *"))]
+#[case("every day every hour", Ok("0 0 * */1 * ? *"))]
+// Implicit frequency of one
+#[case("once a day", Ok("0 0 0 */1 * ? *"))]
+#[case("once an hour", Ok("0 0 0/1 * * ? *"))]
+#[case("an hour", Ok("0 0 0/1 * * ? *"))]
+#[case("every a minute", Ok("0 0/1 * * * ? *"))]
+#[case("once a month", Ok("0 0 0 1 */1 ? *"))]
+#[case("a day at 9am", Ok("0 0 9 */1 * ? *"))]
+#[case("at 9am on a monday", Ok("0 0 9 ? * MON *"))]
+#[case("in a data center every hour", Ok("0 0 * * * ? *"))]
+// Compact weekday ranges
+#[case("every 30 minutes Mon-Fri", Ok("0 0/30 * ? * MON-FRI *"))]
+#[case("every 30 minutes Mon–Fri", Ok("0 0/30 * ? * MON-FRI *"))]
+#[case("at 9am sat - SUN", Ok("0 0 9 ? * SAT-SUN *"))]
+#[case("every day Monday-Thursday at 5pm", Ok("0 0 17 ? * MON-THU *"))]
+#[case("every 30 minutes on tue-thu in March", Ok("0 0/30 * ? MAR TUE-THU *"))]
+// Even and odd days
+#[case("run on even days at 01:00", Ok("0 0 1 2-30/2 * ? *"))]
+#[case("on odd days", Ok("0 0 0 1-31/2 * ? *"))]
+#[case("on even days in March", Ok("0 0 0 2-30/2 MAR ? *"))]
+#[case("every 5 minutes on odd-numbered days", Ok("0 0/5 * 1-31/2 * ? *"))]
+#[case("every day on even days", Ok("0 0 0 2-30/2 * ? *"))]
+#[case(
+    "on even days on the 5th",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "5th contradicts the day of month already given".to_string(),
+    })
+)]
+#[case(
+    "on the 5th of the month on odd days",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "odd days contradicts the day of month already given".to_string(),
+    })
+)]
+// Each as every
+#[case("each day", Ok("0 0 0 */1 * ? *"))]
+#[case("each Monday at 9am", Ok("0 0 9 ? * MON *"))]
+#[case("each 5 minutes", Ok("0 0/5 * * * ? *"))]
+#[case("each hour", Ok("0 0 * * * ? *"))]
+#[case("each other day", Ok("0 0 0 */2 * ? *"))]
+#[case("at 10:00 am on the 1st of each month", Ok("0 0 10 1 * ? *"))]
+#[case("the last day of each month", Ok("0 0 0 L * ? *"))]
+// Every other
+#[case("every other day", Ok("0 0 0 */2 * ? *"))]
+#[case("every other minute", Ok("0 0/2 * * * ? *"))]
+#[case("every other hour", Ok("0 0 0/2 * * ? *"))]
+#[case("every other week", Ok("0 0 0 */14 * ? *"))]
+#[case("every other month", Ok("0 0 0 1 */2 ? *"))]
+#[case(
+    "every other Monday",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every other Monday".to_string(),
+        suggestion: "every Monday".to_string(),
+    })
+)]
+#[case(
+    "every second Tuesday at 9am",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every other Tuesday".to_string(),
+        suggestion: "every Tuesday".to_string(),
+    })
+)]
+#[case(
+    "on alternating Mondays",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every other Monday".to_string(),
+        suggestion: "every Monday".to_string(),
+    })
+)]
+#[case(
+    "alternate fridays at 5pm",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every other friday".to_string(),
+        suggestion: "every friday".to_string(),
+    })
+)]
+// Numbered weekdays of the month
+#[case(
+    "on the 1st and 3rd Monday",
+    Err(Error::UnsupportedSchedule {
+        schedule: "the 1st and 3rd Monday".to_string(),
+        suggestion: "every Monday".to_string(),
+    })
+)]
+#[case("the first Friday of the month at 9am", Ok("0 0 9 ? * FRI#1 *"))]
+#[case("at 9am on the 2nd tue", Ok("0 0 9 ? * TUE#2 *"))]
+#[case(
+    "at 9am on the 2nd, 4th tue",
+    Err(Error::UnsupportedSchedule {
+        schedule: "the 2nd and 4th tue".to_string(),
+        suggestion: "every tue".to_string(),
+    })
+)]
+#[case(
+    "on the second and fourth Wednesday of each month",
+    Err(Error::UnsupportedSchedule {
+        schedule: "the second and fourth Wednesday of each month".to_string(),
+        suggestion: "every Wednesday".to_string(),
+    })
+)]
+#[case(
+    "on the 6th Monday",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "occurrence 6 of MON should be between 1 and 5".to_string(),
+    })
+)]
+#[case("every other day at 9am", Ok("0 0 9 */2 * ? *"))]
+#[case("every 3 weeks", Ok("0 0 0 */21 * ? *"))]
+#[case(
+    "every 6 weeks",
+    Err(Error::IncorrectValue {
+        state: "day_of_month".to_string(),
+        error: "step in */42 should be at most 31".to_string(),
+    })
+)]
+#[case("every week on monday", Ok("0 0 0 ? * MON *"))]
+#[case("every week on Tuesday at 10am", Ok("0 0 10 ? * TUE *"))]
+#[case("weekly on Tuesday", Ok("0 0 0 ? * TUE *"))]
+#[case("weekly on Friday", Ok("0 0 0 ? * FRI *"))]
+#[case("every 2 weeks on Monday", Ok("0 0 0 ? * MON *"))]
+#[case("every 3 weeks on Friday at 9am", Ok("0 0 9 ? * FRI *"))]
+#[case("every 1 week on Friday", Ok("0 0 0 ? * FRI *"))]
+#[case("every week on monday and friday", Ok("0 0 0 ? * MON,FRI *"))]
+#[case("every week", Ok("0 0 0 ? * SUN *"))]
+#[case("weekly at 9am", Ok("0 0 9 ? * SUN *"))]
+#[case("each week at 9am", Ok("0 0 9 ? * SUN *"))]
+#[case("on Monday every week", Ok("0 * * ? * MON *"))]
+#[case("every day of every week", Ok("0 0 0 */1 * ? *"))]
+#[case("every day this week at 9am", Ok("0 0 9 */1 * ? *"))]
+#[case("every weeknight at 9pm", Ok("0 0 21 * * ? *"))]
+#[case("every hour of the workweek", Ok("0 0 * * * ? *"))]
+#[case("every midweek at 9am", Ok("0 0 9 * * ? *"))]
+#[case("fortnightly", Ok("0 0 0 */14 * ? *"))]
+#[case("every fortnight", Ok("0 0 0 */14 * ? *"))]
+#[case("every two weeks", Ok("0 0 0 */14 * ? *"))]
+#[case("biweekly", Ok("0 0 0 */14 * ? *"))]
+#[case("bi-weekly at 9am", Ok("0 0 9 */14 * ? *"))]
+#[case(
+    "bimonthly",
+    Err(Error::AmbiguousInput {
+        input: "bimonthly".to_string(),
+        alternatives: vec!["0 0 0 1 */2 ? *".to_string(), "0 0 0 1,15 * ? *".to_string()],
+    })
+)]
+#[case(
+    "bi-monthly at 9am",
+    Err(Error::AmbiguousInput {
+        input: "bi-monthly at 9am".to_string(),
+        alternatives: vec!["0 0 9 1 */2 ? *".to_string(), "0 0 9 1,15 * ? *".to_string()],
+    })
+)]
+#[case(
+    "biannually",
+    Err(Error::AmbiguousInput {
+        input: "biannually".to_string(),
+        alternatives: vec!["0 0 0 1 1 ? */2".to_string(), "0 0 0 1 */6 ? *".to_string()],
+    })
+)]
 // Month
 #[case("Run every sec from January to March", Ok("* * * * JAN-MAR ? *"))]
 #[case("Run every minute from January to March", Ok("0 * * * JAN-MAR ? *"))]
 #[case("Run every hours from January to March", Ok("0 0 * * JAN-MAR ? *"))]
+#[case("from January and March", Ok("0 * * * JAN,MAR ? *"))]
+#[case("between January and March", Ok("0 * * * JAN-MAR ? *"))]
+#[case("in January, March, and May", Ok("0 * * * JAN,MAR,MAY ? *"))]
+#[case(
+    "in April, June, September, and November at 9am",
+    Ok("0 0 9 * APR,JUN,SEP,NOV ? *")
+)]
+#[case("every day between January and March", Ok("0 0 0 */1 JAN-MAR ? *"))]
+#[case("in December and January", Ok("0 * * * JAN,DEC ? *"))]
+// Dates
+#[case("on June 15", Ok("0 0 0 15 JUN ? *"))]
+#[case("on June 15th", Ok("0 0 0 15 JUN ? *"))]
+#[case("on 15 June", Ok("0 0 0 15 JUN ? *"))]
+#[case("on the 15th of June", Ok("0 0 0 15 JUN ? *"))]
+#[case("on December 25 at 3pm", Ok("0 0 15 25 DEC ? *"))]
+#[case("every 10 minutes on June 15", Ok("0 0/10 * 15 JUN ? *"))]
+#[case("in June every 2 hours", Ok("0 0 0/2 * JUN ? *"))]
+#[case(
+    "on June 0",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month 0 should be between 1 and 31".to_string(),
+    })
+)]
+#[case(
+    "on June 32",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "day of month 32 should be between 1 and 31".to_string(),
+    })
+)]
+// Seasons (northern hemisphere)
+#[case("every day during summer", Ok("0 0 0 */1 JUN,JUL,AUG ? *"))]
+#[case("during winter", Ok("0 * * * JAN,FEB,DEC ? *"))]
+#[case("during spring months", Ok("0 * * * MAR,APR,MAY ? *"))]
+#[case("during fall", Ok("0 * * * SEP,OCT,NOV ? *"))]
+#[case("during autumn", Ok("0 * * * SEP,OCT,NOV ? *"))]
+#[case(
+    "at 9am on weekdays during summer",
+    Ok("0 0 9 ? JUN,JUL,AUG MON-FRI *")
+)]
+#[case(
+    "every 15 minutes in the summer months",
+    Ok("0 0/15 * * JUN,JUL,AUG ? *")
+)]
+// Season and month names inside other words
+#[case("every day in springfield", Ok("0 0 0 */1 * ? *"))]
+#[case("every rainfall day", Ok("0 0 0 */1 * ? *"))]
+#[case("every day in marseille", Ok("0 0 0 */1 * ? *"))]
+#[case("in July, March and January", Ok("0 * * * JAN,MAR,JUL ? *"))]
+#[case("in March and May and March", Ok("0 * * * MAR,MAY ? *"))]
+#[case("in March, May, and March", Ok("0 * * * MAR,MAY ? *"))]
+#[case("in December, and January", Ok("0 * * * JAN,DEC ? *"))]
+#[case("in March, and May and June", Ok("0 * * * MAR,MAY,JUN ? *"))]
+#[case("from January to March and May", Ok("0 * * * JAN-MAR,MAY ? *"))]
+#[case("in Sept and Jan", Ok("0 * * * JAN,SEP ? *"))]
+#[case("at noon in December, June and June", Ok("0 0 12 * JUN,DEC ? *"))]
+// Quarter
+#[case("every day in Q1", Ok("0 0 0 */1 JAN-MAR ? *"))]
+#[case("every day in Q2", Ok("0 0 0 */1 APR-JUN ? *"))]
+#[case("every day in Q3", Ok("0 0 0 */1 JUL-SEP ? *"))]
+#[case("every day in Q4", Ok("0 0 0 */1 OCT-DEC ? *"))]
+#[case("every 2 days in q4", Ok("0 0 0 */2 OCT-DEC ? *"))]
+#[case("every Monday in Q1", Ok("0 * * ? JAN-MAR MON *"))]
+#[case("every Friday at 5pm in Q2", Ok("0 0 17 ? APR-JUN FRI *"))]
+#[case("every 15 minutes in Q3", Ok("0 0/15 * * JUL-SEP ? *"))]
+#[case("every 3 hours in Q4", Ok("0 0 0/3 * OCT-DEC ? *"))]
+#[case("every 30 seconds in Q1", Ok("0/30 * * * JAN-MAR ? *"))]
+#[case("at noon on the 15th in Q2", Ok("0 0 12 15 APR-JUN ? *"))]
+#[case("at 9am every weekend in Q3", Ok("0 0 9 ? JUL-SEP SAT,SUN *"))]
 // Year
+#[case("every day in 2019", Ok("0 0 0 */1 * ? 2019"))]
+#[case("every day from 2018 to 2021", Ok("0 0 0 */1 * ? 2018-2021"))]
 #[case(
     "every 2 day from January to August in 2020 and 2024",
     Ok("0 0 0 */2 JAN-AUG ? 2020,2024")
 )]
+#[case("from 2020 to 2025", Ok("0 * * * * ? 2020-2025"))]
+#[case("every day between 2020 and 2030", Ok("0 0 0 */1 * ? 2020-2030"))]
+#[case("every year", Ok("0 0 0 1 1 ? *"))]
+#[case("once a year", Ok("0 0 0 1 1 ? *"))]
+#[case("every 2 years", Ok("0 0 0 1 1 ? */2"))]
+#[case("every 2 years starting 2024", Ok("0 0 0 1 1 ? 2024/2"))]
+#[case("every 2 years starting in 2024", Ok("0 0 0 1 1 ? 2024/2"))]
+#[case("every 3 years from 2021", Ok("0 0 0 1 1 ? 2021/3"))]
+#[case("every 2 years from 2020 to 2030", Ok("0 0 0 1 1 ? 2020-2030/2"))]
+#[case("every 2 years between 2020 and 2030", Ok("0 0 0 1 1 ? 2020-2030/2"))]
+#[case("every year in March", Ok("0 0 0 1 MAR ? *"))]
 // Specific Times (AM/PM)
 #[case("Run at 10:00 am", Ok("0 0 10 * * ? *"))]
 #[case("Run at 12:15 pm", Ok("0 15 12 * * ? *"))]
+#[case("at 13:45:30", Ok("30 45 13 * * ? *"))]
+#[case("at 13:45", Ok("0 45 13 * * ? *"))]
+#[case("at 12:00:00 am", Ok("0 0 0 * * ? *"))]
+#[case("at 1:15:05 pm", Ok("5 15 13 * * ? *"))]
+#[case("every day at 08:30:15", Ok("15 30 8 */1 * ? *"))]
+// Bare Hours
+#[case("at 7", Ok("0 0 7 * * ? *"))]
+#[case("at 19", Ok("0 0 19 * * ? *"))]
+#[case("at 7 every day", Ok("0 0 7 */1 * ? *"))]
+#[case("every day at 7", Ok("0 0 7 */1 * ? *"))]
+#[case("at 7 and 19", Ok("0 0 7,19 * * ? *"))]
+#[case("at 7 on weekdays", Ok("0 0 7 ? * MON-FRI *"))]
+#[case("at 7 every Friday evening", Ok("0 0 19 ? * FRI *"))]
+#[case("every evening at 8 and at 7", Ok("0 0 20,7 */1 * ? *"))]
+#[case(
+    "at 7 and 9:30",
+    Err(Error::UnsupportedSchedule {
+        schedule: "at 7 and 9:30".to_string(),
+        suggestion: "the separate schedules 0 0 7 * * ? * and 0 30 9 * * ? *".to_string(),
+    })
+)]
+#[case(
+    "every day at 7:30 and 9",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every day at 7:30 and 9".to_string(),
+        suggestion: "the separate schedules 0 30 7 */1 * ? * and 0 0 9 */1 * ? *".to_string(),
+    })
+)]
+// Military Times
+#[case("at 1430", Ok("0 30 14 * * ? *"))]
+#[case("at 0900", Ok("0 0 9 * * ? *"))]
+#[case("at 0000", Ok("0 0 0 * * ? *"))]
+#[case("at 2359", Ok("0 59 23 * * ? *"))]
+#[case("at 0900 and 1700", Ok("0 0 9,17 * * ? *"))]
+#[case("at 1430 on weekdays", Ok("0 30 14 ? * MON-FRI *"))]
+#[case("at 1430 in 2025", Ok("0 30 14 * * ? 2025"))]
+#[case("in 2030", Ok("0 * * * * ? 2030"))]
+#[case("at 0630", Ok("0 30 6 * * ? *"))]
+#[case("at 630", Ok("0 30 6 * * ? *"))]
+#[case("at 1800 hours", Ok("0 0 18 * * ? *"))]
+#[case("at 1800 hrs", Ok("0 0 18 * * ? *"))]
+#[case("1800 hours on weekdays", Ok("0 0 18 ? * MON-FRI *"))]
+#[case("every day at 0630 hours", Ok("0 30 6 */1 * ? *"))]
+#[case("between 0900 and 1700", Ok("0 * 9-17 * * ? *"))]
+#[case("from 0900 to 1700", Ok("0 0 9-17 * * ? *"))]
+#[case("every 15 minutes from 0900 to 1700", Ok("0 0/15 9-17 * * ? *"))]
+#[case("every hour between 1800 and 2200", Ok("0 0 18-22 * * ? *"))]
+#[case("every day from 2025 to 2030", Ok("0 0 0 */1 * ? 2025-2030"))]
+#[case(
+    "at 2430",
+    Err(Error::IncorrectValue {
+        state: "clock_time".to_string(),
+        error: "hour 24 should be between 0 and 23".to_string(),
+    })
+)]
+#[case(
+    "at 0999",
+    Err(Error::IncorrectValue {
+        state: "clock_time".to_string(),
+        error: "minute 99 should be lower or equal to 60".to_string(),
+    })
+)]
+#[case(
+    "at 13:45:60",
+    Err(Error::IncorrectValue {
+        state: "clock_time".to_string(),
+        error: "second 60 should be between 0 and 59".to_string(),
+    })
+)]
 #[case(
     "Run at 6:00 pm every Monday through Friday",
     Ok("0 0 18 ? * MON-FRI *")
@@ -82,6 +671,12 @@ use rstest::rstest;
 #[case("midnight on Tuesdays", Ok("0 0 0 ? * TUE *"))]
 #[case("Run at 5:15am every Tuesday", Ok("0 15 5 ? * TUE *"))]
 #[case("7pm every Thursday", Ok("0 0 19 ? * THU *"))]
+#[case("at 7 p.m. every Thursday", Ok("0 0 19 ? * THU *"))]
+#[case("at 7 P.M. every Thursday", Ok("0 0 19 ? * THU *"))]
+#[case("at 9:30 a.m. on Monday", Ok("0 30 9 ? * MON *"))]
+#[case("at 7 p. m.", Ok("0 0 19 * * ? *"))]
+#[case("at 12 a.m.", Ok("0 0 0 * * ? *"))]
+#[case("at 12 p.m.", Ok("0 0 12 * * ? *"))]
 #[case("2pm and 6pm", Ok("0 0 14,18 * * ? *"))]
 #[case("5am, 10am and 3pm", Ok("0 0 5,10,15 * * ? *"))]
 #[case("Run every hour only on Monday", Ok("0 0 * ? * MON *"))]
@@ -90,6 +685,536 @@ use rstest::rstest;
 #[case("4pm, 5pm, and 7pm", Ok("0 0 16,17,19 * * ? *"))]
 #[case("4pm, 5pm, 7pm", Ok("0 0 16,17,19 * * ? *"))]
 #[case("4pm and 5pm and 7pm", Ok("0 0 16,17,19 * * ? *"))]
+#[case("at 9am, noon, and 5pm", Ok("0 0 9,12,17 * * ? *"))]
+#[case("at 9:30am and 5:30pm", Ok("0 30 9,17 * * ? *"))]
+#[case("at 8:15, 12:15 and 16:15", Ok("0 15 8,12,16 * * ? *"))]
+#[case("at midnight and noon", Ok("0 0 0,12 * * ? *"))]
+#[case(
+    "at 9:30am and 5pm",
+    Err(Error::UnsupportedSchedule {
+        schedule: "at 9:30am and 5pm".to_string(),
+        suggestion: "the separate schedules 0 30 9 * * ? * and 0 0 17 * * ? *".to_string(),
+    })
+)]
+// Time-of-day qualifiers
+#[case("at 8 in the morning", Ok("0 0 8 * * ? *"))]
+#[case("at 8 in the evening", Ok("0 0 20 * * ? *"))]
+#[case("at 3 in the afternoon", Ok("0 0 15 * * ? *"))]
+#[case("at 11 at night", Ok("0 0 23 * * ? *"))]
+#[case("at 2 at night", Ok("0 0 2 * * ? *"))]
+#[case("at 8:30 in the evening", Ok("0 30 20 * * ? *"))]
+#[case("at 8 pm in the evening", Ok("0 0 20 * * ? *"))]
+#[case("every day at 8 in the evening", Ok("0 0 20 */1 * ? *"))]
+#[case("on Monday at 7:15 in the morning", Ok("0 15 7 ? * MON *"))]
+#[case(
+    "at 8 pm in the morning",
+    Err(Error::IncorrectValue {
+        state: "clock_time".to_string(),
+        error: "PM contradicts \"in the morning\"".to_string(),
+    })
+)]
+// Periods of the day
+#[case("every morning at 8", Ok("0 0 8 */1 * ? *"))]
+#[case("every morning", Ok("0 0 9 */1 * ? *"))]
+#[case("every afternoon", Ok("0 0 15 */1 * ? *"))]
+#[case("every evening", Ok("0 0 18 */1 * ? *"))]
+#[case("every evening at 7", Ok("0 0 19 */1 * ? *"))]
+#[case("every evening at 7:30", Ok("0 30 19 */1 * ? *"))]
+#[case("every afternoon at 2:15 pm", Ok("0 15 14 */1 * ? *"))]
+#[case("at 7:30 every evening", Ok("0 30 19 */1 * ? *"))]
+#[case("every morning at 8 on weekdays", Ok("0 0 8 ? * MON-FRI *"))]
+#[case("on Monday morning", Ok("0 0 9 ? * MON *"))]
+#[case("every weekday morning at 9", Ok("0 0 9 ? * MON-FRI *"))]
+#[case("at 9 every weekday morning", Ok("0 0 9 ? * MON-FRI *"))]
+#[case("at 8 every weekday morning", Ok("0 0 8 ? * MON-FRI *"))]
+#[case("every weekday at 9 in the morning", Ok("0 0 9 ? * MON-FRI *"))]
+#[case("at 9 in the morning every weekday", Ok("0 0 9 ? * MON-FRI *"))]
+#[case("every weekday morning", Ok("0 0 9 ? * MON-FRI *"))]
+#[case("at 7 every Friday evening", Ok("0 0 19 ? * FRI *"))]
+#[case("every night at 11pm", Ok("0 0 23 */1 * ? *"))]
+#[case("nightly at 2am", Ok("0 0 2 */1 * ? *"))]
+#[case("every night at 11", Ok("0 0 23 */1 * ? *"))]
+#[case("every night at 2", Ok("0 0 2 */1 * ? *"))]
+#[case("nightly", Ok("0 0 0 */1 * ? *"))]
+#[case("knights", Err(Error::InvalidInput))]
+#[case("midnightly", Err(Error::InvalidInput))]
+#[case("at 3 knights", Ok("0 0 3 * * ? *"))]
+#[case("every night at 11:30", Ok("0 30 23 */1 * ? *"))]
+#[case("every Friday night at 10", Ok("0 0 22 ? * FRI *"))]
+#[case(
+    "on weekdays and Saturday at noon",
+    Ok("0 0 12 ? * MON,TUE,WED,THU,FRI,SAT *")
+)]
+// O'clock
+#[case("at 9 o'clock", Ok("0 0 9 * * ? *"))]
+#[case("at 9 o’clock", Ok("0 0 9 * * ? *"))]
+#[case("at 21 o'clock", Ok("0 0 21 * * ? *"))]
+#[case("at 9 o'clock pm", Ok("0 0 21 * * ? *"))]
+#[case("at 8 o'clock in the evening", Ok("0 0 20 * * ? *"))]
+#[case("at 8 o'clock in the morning", Ok("0 0 8 * * ? *"))]
+#[case("at 12 o'clock noon", Ok("0 0 12 * * ? *"))]
+#[case("at 12 o'clock midnight", Ok("0 0 0 * * ? *"))]
+#[case("every Monday at 7 o'clock", Ok("0 0 7 ? * MON *"))]
+// Numeric hour ranges
+#[case("every 15 minutes 9-17", Ok("0 0/15 9-17 * * ? *"))]
+#[case("every 15 minutes 9 - 17", Ok("0 0/15 9-17 * * ? *"))]
+#[case("every 15 minutes between 9 and 17", Ok("0 0/15 9-17 * * ? *"))]
+#[case("every 15 minutes from 9 to 17", Ok("0 0/15 9-17 * * ? *"))]
+#[case("9-17 every 5 minutes", Ok("0 0/5 9-17 * * ? *"))]
+#[case("every 2 hours 9-17", Ok("0 0 9-17/2 * * ? *"))]
+#[case("every 30 minutes 9-17 on weekdays", Ok("0 0/30 9-17 ? * MON-FRI *"))]
+#[case("every 10 minutes between 22 and 2", Ok("0 0/10 22-2 * * ? *"))]
+#[case("every day from 1 to 15 of the month", Ok("0 0 0 1-15 * ? *"))]
+#[case(
+    "every 15 minutes between 9 and 25",
+    Err(Error::IncorrectValue {
+        state: "clock_time".to_string(),
+        error: "hour 25 should be between 0 and 23".to_string(),
+    })
+)]
+#[case(
+    "at 24:00",
+    Err(Error::IncorrectValue {
+        state: "clock_time".to_string(),
+        error: "hour 24 should be between 0 and 23".to_string(),
+    })
+)]
+// Business hours
+#[case(
+    "every 10 minutes during business hours",
+    Ok("0 0/10 9-17 ? * MON-FRI *")
+)]
+#[case(
+    "during office hours every 10 minutes",
+    Ok("0 0/10 9-17 ? * MON-FRI *")
+)]
+#[case("every 2 hours during working hours", Ok("0 0 9-17/2 ? * MON-FRI *"))]
+#[case(
+    "every 10 minutes during business hours on Saturday",
+    Ok("0 0/10 9-17 ? * SAT *")
+)]
+#[case(
+    "on Saturday every hour during business hours",
+    Ok("0 0 9-17 ? * SAT *")
+)]
+#[case("every day during business hours", Ok("0 0 9-17 ? * MON-FRI *"))]
+#[case(
+    "every hour during business hours every day",
+    Ok("0 0 9-17 ? * MON-FRI *")
+)]
+#[case(
+    "every hour during business hours and office hours",
+    Ok("0 0 9-17 ? * MON-FRI *")
+)]
+#[case(
+    "every 10 minutes during business hours on the 15th of the month",
+    Ok("0 0/10 9-17 15 * ? *")
+)]
+#[case(
+    "every 10 minutes between 9:00 and 17:00 on the 15th",
+    Ok("0 0/10 9-17 15 * ? *")
+)]
+// Intervals longer than their unit
+#[case("every 120 seconds", Ok("0 0/2 * * * ? *"))]
+#[case("every 60 seconds", Ok("0 0/1 * * * ? *"))]
+#[case("every 120 minutes", Ok("0 0 0/2 * * ? *"))]
+#[case("every 180 minutes", Ok("0 0 0/3 * * ? *"))]
+#[case("every 48 hours", Ok("0 0 0 */2 * ? *"))]
+#[case("every 1440 minutes", Ok("0 0 0 */1 * ? *"))]
+#[case("every 3600 seconds", Ok("0 0 0/1 * * ? *"))]
+#[case("every 7200 seconds", Ok("0 0 0/2 * * ? *"))]
+#[case(
+    "every 1000 seconds",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 1000 seconds".to_string(),
+        suggestion: "every 960 or 1020 seconds".to_string(),
+    })
+)]
+#[case(
+    "every 90 minutes",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 90 minutes".to_string(),
+        suggestion: "every 60 or 120 minutes".to_string(),
+    })
+)]
+#[case(
+    "every 36 hours",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 36 hours".to_string(),
+        suggestion: "every 24 or 48 hours".to_string(),
+    })
+)]
+#[case(
+    "every 48 hours on Monday",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 48 hours".to_string(),
+        suggestion: "every 2 days".to_string(),
+    })
+)]
+// Noon, midday and midnight
+#[case("midday", Ok("0 0 12 * * ? *"))]
+#[case("every day at midday", Ok("0 0 12 */1 * ? *"))]
+#[case("every Monday at midday", Ok("0 0 12 ? * MON *"))]
+#[case("12 o'clock midday", Ok("0 0 12 * * ? *"))]
+#[case("every 3 days at midnight", Ok("0 0 0 */3 * ? *"))]
+#[case("every 3 days at noon", Ok("0 0 12 */3 * ? *"))]
+#[case("every 3 days at midday", Ok("0 0 12 */3 * ? *"))]
+#[case("every week at midnight", Ok("0 0 0 ? * SUN *"))]
+#[case("every other day at MIDDAY", Ok("0 0 12 */2 * ? *"))]
+// Several times on weekdays
+#[case("every weekday at 9am and 5pm", Ok("0 0 9,17 ? * MON-FRI *"))]
+#[case("at 9am and 5pm every weekday", Ok("0 0 9,17 ? * MON-FRI *"))]
+#[case("on weekdays at 9am, 1pm and 5pm", Ok("0 0 9,13,17 ? * MON-FRI *"))]
+#[case("every weekday at 9:00 and 17:00", Ok("0 0 9,17 ? * MON-FRI *"))]
+#[case("every Monday and Friday at 9am and 5pm", Ok("0 0 9,17 ? * MON,FRI *"))]
+#[case("every weekday from 9am to 5pm", Ok("0 0 9-17 ? * MON-FRI *"))]
+// Minutes past the hour
+#[case("at 15 past the hour", Ok("0 15 * * * ? *"))]
+#[case("at 15 minutes past every hour", Ok("0 15 * * * ? *"))]
+#[case("at 1 minute past each hour", Ok("0 1 * * * ? *"))]
+#[case("at half past every hour", Ok("0 30 * * * ? *"))]
+#[case("at quarter past the hour", Ok("0 15 * * * ? *"))]
+#[case("at a quarter past each hour", Ok("0 15 * * * ? *"))]
+#[case("at 15 minutes past the hour on Monday", Ok("0 15 * ? * MON *"))]
+#[case("every 2 hours at 10 past the hour", Ok("0 10 0/2 * * ? *"))]
+#[case("at 15 past the hour between 9am and 5pm", Ok("0 15 9-17 * * ? *"))]
+#[case(
+    "at 75 past the hour",
+    Err(Error::IncorrectValue {
+        state: "past_hour".to_string(),
+        error: "minute in 75 past the hour should be between 0 and 59".to_string(),
+    })
+)]
+// Top of the hour
+#[case("every weekday on the hour", Ok("0 0 * ? * MON-FRI *"))]
+#[case("at the top of the hour", Ok("0 0 * * * ? *"))]
+#[case("top of every hour on Mondays", Ok("0 0 * ? * MON *"))]
+#[case("every 2 hours on the hour", Ok("0 0 0/2 * * ? *"))]
+#[case("on the hour between 9am and 5pm", Ok("0 0 9-17 * * ? *"))]
+#[case("every 10 seconds on the hour", Ok("0/10 0 * * * ? *"))]
+// Exclusions
+#[case(
+    "every day except Saturday and Sunday",
+    Ok("0 0 0 ? * MON,TUE,WED,THU,FRI *")
+)]
+#[case(
+    "every day except on Monday",
+    Ok("0 0 0 ? * TUE,WED,THU,FRI,SAT,SUN *")
+)]
+#[case(
+    "at 9am every day except for weekends",
+    Ok("0 0 9 ? * MON,TUE,WED,THU,FRI *")
+)]
+#[case(
+    "every day except Monday at 9am",
+    Ok("0 0 9 ? * TUE,WED,THU,FRI,SAT,SUN *")
+)]
+#[case(
+    "at noon except Monday through Wednesday",
+    Ok("0 0 12 ? * THU,FRI,SAT,SUN *")
+)]
+#[case("every day except Monday-Wednesday", Ok("0 0 0 ? * THU,FRI,SAT,SUN *"))]
+#[case(
+    "every 10 minutes except on Sunday",
+    Ok("0 0/10 * ? * MON,TUE,WED,THU,FRI,SAT *")
+)]
+#[case(
+    "in every month except July and August",
+    Ok("0 * * * JAN,FEB,MAR,APR,MAY,JUN,SEP,OCT,NOV,DEC ? *")
+)]
+#[case(
+    "at 9am on the 1st except June through August",
+    Ok("0 0 9 1 JAN,FEB,MAR,APR,MAY,SEP,OCT,NOV,DEC ? *")
+)]
+#[case(
+    "every day except weekdays and weekends",
+    Err(Error::IncorrectValue {
+        state: "except".to_string(),
+        error: "excluding MON,TUE,WED,THU,FRI,SAT,SUN leaves nothing to run on".to_string(),
+    })
+)]
+#[case(
+    "every day excluding Sunday",
+    Ok("0 0 0 ? * MON,TUE,WED,THU,FRI,SAT *")
+)]
+#[case("weekdays except Friday", Ok("0 * * ? * MON,TUE,WED,THU *"))]
+#[case("every weekday except Friday", Ok("0 * * ? * MON,TUE,WED,THU *"))]
+#[case(
+    "every weekday excluding Monday and Friday",
+    Ok("0 * * ? * TUE,WED,THU *")
+)]
+#[case(
+    "every weekday except Monday through Wednesday",
+    Ok("0 * * ? * THU,FRI *")
+)]
+#[case("on weekends except Sunday", Ok("0 * * ? * SAT *"))]
+#[case("during summer except July", Ok("0 * * * JUN,AUG ? *"))]
+#[case(
+    "every Monday except the 1st",
+    Err(Error::IncorrectValue {
+        state: "except".to_string(),
+        error: "cannot exclude 1st".to_string(),
+    })
+)]
+#[case(
+    "weekdays except Monday, Tuesday, Wednesday, Thursday and Friday",
+    Err(Error::IncorrectValue {
+        state: "except".to_string(),
+        error: "excluding MON,TUE,WED,THU,FRI leaves nothing to run on".to_string(),
+    })
+)]
+// Compact durations
+#[case("every 5m", Ok("0 0/5 * * * ? *"))]
+#[case("every 30s", Ok("0/30 * * * * ? *"))]
+#[case("every 2h", Ok("0 0 0/2 * * ? *"))]
+#[case("every 2H", Ok("0 0 0/2 * * ? *"))]
+#[case("every 1d", Ok("0 0 0 */1 * ? *"))]
+#[case("every 120m", Ok("0 0 0/2 * * ? *"))]
+#[case("every 2h0m", Ok("0 0 0/2 * * ? *"))]
+#[case("every 2d at 5pm", Ok("0 0 17 */2 * ? *"))]
+#[case("every 1h0s", Ok("0 0 0/1 * * ? *"))]
+#[case("every 1d0m", Ok("0 0 0 */1 * ? *"))]
+#[case("every 59m60s", Ok("0 0 0/1 * * ? *"))]
+#[case("every 2h0m0s on Monday", Ok("0 0 0/2 ? * MON *"))]
+#[case(
+    "every 16h40m",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 1000 minutes".to_string(),
+        suggestion: "every 960 or 1020 minutes".to_string(),
+    })
+)]
+#[case(
+    "every 1h30m",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 90 minutes".to_string(),
+        suggestion: "every 60 or 120 minutes".to_string(),
+    })
+)]
+#[case(
+    "every 90s",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 90 seconds".to_string(),
+        suggestion: "every 60 or 120 seconds".to_string(),
+    })
+)]
+// Durations with an extra half unit
+#[case(
+    "every hour and a half",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 90 minutes".to_string(),
+        suggestion: "every 60 or 120 minutes".to_string(),
+    })
+)]
+#[case(
+    "every two and a half hours",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 150 minutes".to_string(),
+        suggestion: "every 120 or 180 minutes".to_string(),
+    })
+)]
+#[case(
+    "every 2 and a half hours",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 150 minutes".to_string(),
+        suggestion: "every 120 or 180 minutes".to_string(),
+    })
+)]
+#[case(
+    "every 2 hours and a half",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 150 minutes".to_string(),
+        suggestion: "every 120 or 180 minutes".to_string(),
+    })
+)]
+#[case(
+    "every three minutes and a half",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 210 seconds".to_string(),
+        suggestion: "every 180 or 240 seconds".to_string(),
+    })
+)]
+#[case(
+    "every minute and a half",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 90 seconds".to_string(),
+        suggestion: "every 60 or 120 seconds".to_string(),
+    })
+)]
+#[case(
+    "every day and a half",
+    Err(Error::UnsupportedSchedule {
+        schedule: "every 36 hours".to_string(),
+        suggestion: "every 24 or 48 hours".to_string(),
+    })
+)]
+// Number of runs per unit
+#[case("twice a day", Ok("0 0 0/12 * * ? *"))]
+#[case("three times a day", Ok("0 0 0/8 * * ? *"))]
+#[case("four times a day", Ok("0 0 0/6 * * ? *"))]
+#[case("4 times per hour", Ok("0 0/15 * * * ? *"))]
+#[case("twice a minute", Ok("0/30 * * * * ? *"))]
+#[case("twice a week", Ok("0 0 0 ? * MON,THU *"))]
+#[case("three times a week at 9am", Ok("0 0 9 ? * MON,WED,FRI *"))]
+#[case("7 times a week", Ok("0 0 0 */1 * ? *"))]
+#[case("twice a month", Ok("0 0 0 1,15 * ? *"))]
+#[case("4 times a month at 3pm", Ok("0 0 15 1,8,15,22 * ? *"))]
+#[case("twice a year", Ok("0 0 0 1 */6 ? *"))]
+#[case("one time a week", Ok("0 0 0 ? * SUN *"))]
+#[case("twice a day on weekdays", Ok("0 0 0/12 ? * MON-FRI *"))]
+#[case(
+    "five times a day",
+    Err(Error::UnsupportedSchedule {
+        schedule: "5 times a day".to_string(),
+        suggestion: "4 or 6 times a day".to_string(),
+    })
+)]
+#[case(
+    "4 times a week",
+    Err(Error::UnsupportedSchedule {
+        schedule: "4 times a week".to_string(),
+        suggestion: "3 or 7 times a week".to_string(),
+    })
+)]
+#[case(
+    "50 times a day",
+    Err(Error::UnsupportedSchedule {
+        schedule: "50 times a day".to_string(),
+        suggestion: "24 times a day".to_string(),
+    })
+)]
+#[case(
+    "0 times a day",
+    Err(Error::IncorrectValue {
+        state: "frequency_times".to_string(),
+        error: "0 times a day should be at least once".to_string(),
+    })
+)]
+#[case(
+    "twice a day at 9am",
+    Err(Error::IncorrectValue {
+        state: "frequency_times".to_string(),
+        error: "2 times a day cannot be combined with the time also given".to_string(),
+    })
+)]
+#[case(
+    "at 9am twice a day",
+    Err(Error::IncorrectValue {
+        state: "frequency_times".to_string(),
+        error: "2 times a day cannot be combined with the time also given".to_string(),
+    })
+)]
+#[case(
+    "twice a day between 9am and 5pm",
+    Err(Error::IncorrectValue {
+        state: "frequency_times".to_string(),
+        error: "2 times a day cannot be combined with the time also given".to_string(),
+    })
+)]
+#[case(
+    "twice a week on Tuesday",
+    Err(Error::IncorrectValue {
+        state: "frequency_times".to_string(),
+        error: "2 times a week cannot be combined with the days also given".to_string(),
+    })
+)]
+#[case(
+    "on Tuesday twice a week",
+    Err(Error::IncorrectValue {
+        state: "frequency_times".to_string(),
+        error: "2 times a week cannot be combined with the days also given".to_string(),
+    })
+)]
+#[case(
+    "twice a year in March",
+    Err(Error::IncorrectValue {
+        state: "frequency_times".to_string(),
+        error: "2 times a year cannot be combined with the months also given".to_string(),
+    })
+)]
+#[case("once a day at 9am", Ok("0 0 9 */1 * ? *"))]
+// Yearly dates
+#[case("every January 1st at midnight", Ok("0 0 0 1 JAN ? *"))]
+#[case("every December 25th at 6am", Ok("0 0 6 25 DEC ? *"))]
+#[case("every 1st of January at midnight", Ok("0 0 0 1 JAN ? *"))]
+#[case("every 25th December at 6am", Ok("0 0 6 25 DEC ? *"))]
+#[case("at midnight every January 1st", Ok("0 0 0 1 JAN ? *"))]
+#[case("every January 1st, at midnight", Ok("0 0 0 1 JAN ? *"))]
+#[case("December 25th, 2026 at 6am", Ok("0 0 6 25 DEC ? 2026"))]
+#[case(
+    "every January 1st at midnight in 2026 and 2027",
+    Ok("0 0 0 1 JAN ? 2026,2027")
+)]
+#[case("June 1st to 15th", Ok("0 0 0 1-15 JUN ? *"))]
+#[case("June 1st to 15th at 9am", Ok("0 0 9 1-15 JUN ? *"))]
+// Inputs found by fuzzing
+#[case(
+    "every 0 minutes",
+    Err(Error::IncorrectValue {
+        state: "minutes".to_string(),
+        error: "step in 0/0 should not be 0".to_string(),
+    })
+)]
+#[case(
+    "every 0 days",
+    Err(Error::IncorrectValue {
+        state: "day_of_month".to_string(),
+        error: "step in */0 should not be 0".to_string(),
+    })
+)]
+#[case(
+    "every 5 seconds to 9 month",
+    Err(Error::IncorrectValue {
+        state: "month".to_string(),
+        error: "a range of months ending in month 9 has no start".to_string(),
+    })
+)]
+#[case("every day through 2026", Ok("0 0 0 */1 * ? 2026"))]
+#[case(
+    "in 2100",
+    Err(Error::IncorrectValue {
+        state: "year".to_string(),
+        error: "year 2100 should be between 1970 and 2099".to_string(),
+    })
+)]
+#[case("every 3rd year", Ok("0 0 0 1 1 ? */3"))]
+#[case(
+    "every 60 month",
+    Err(Error::IncorrectValue {
+        state: "month".to_string(),
+        error: "month 60 should be between 1 and 12".to_string(),
+    })
+)]
+#[case(
+    "at 30 every evening",
+    Err(Error::IncorrectValue {
+        state: "day_period".to_string(),
+        error: "hour 30 should be between 0 and 23".to_string(),
+    })
+)]
+#[case("every day through Friday", Ok("0 0 0 ? * FRI *"))]
+#[case(
+    "from 9 to 71 hours",
+    Err(Error::IncorrectValue {
+        state: "hour".to_string(),
+        error: "hour 71 should be between 0 and 23".to_string(),
+    })
+)]
+#[case(
+    "the 25th hour",
+    Err(Error::IncorrectValue {
+        state: "hour".to_string(),
+        error: "hour 25 should be between 0 and 23".to_string(),
+    })
+)]
+#[case("at noon every month and June", Ok("0 0 12 * JUN ? *"))]
+#[case(
+    "Monday through weekends",
+    Err(Error::IncorrectValue {
+        state: "day".to_string(),
+        error: "a range of days cannot start or end with WEEKEND".to_string(),
+    })
+)]
+#[case("every 2 hours ending 3pm ending 5pm", Ok("0 0 0-17/2 * * ? *"))]
 #[test]
 fn can_parse_string(
     #[case] cron_str: &str,
@@ -105,3 +1230,843 @@ fn can_parse_string(
         "Failed for input: '{cron_str}'. Expected: {expected_result:?}, Got: {result:?}"
     );
 }
+
+#[rstest]
+#[case("every 5 minutes of each hour", "every 5 minutes")]
+#[case("at 9am of each day", "at 9am")]
+#[case("on Monday of each week", "on Monday")]
+#[case("every day at 4:00 pm of every month", "every day at 4:00 pm")]
+#[case(
+    "at midnight on the 1st and 15th of every month",
+    "at midnight on the 1st and 15th of the month"
+)]
+#[test]
+fn scope_suffix_is_noop(#[case] with_suffix: &str, #[case] without_suffix: &str) {
+    assert_eq!(
+        str_cron_syntax(with_suffix),
+        str_cron_syntax(without_suffix),
+        "Failed for input: '{with_suffix}'"
+    );
+}
+
+#[rstest]
+#[case(
+    "every day at 4pm",
+    &[FieldPosition::Minutes, FieldPosition::Hour, FieldPosition::DayOfMonth]
+)]
+#[case("every day", &[FieldPosition::DayOfMonth])]
+#[case("every 15 minutes", &[FieldPosition::Minutes])]
+#[case("every 3 hours", &[FieldPosition::Hour])]
+#[case(
+    "Run at 6:00 pm every Monday through Friday",
+    &[FieldPosition::Minutes, FieldPosition::Hour, FieldPosition::DayOfWeek]
+)]
+#[case(
+    "every 2 day from January to August in 2020 and 2024",
+    &[FieldPosition::DayOfMonth, FieldPosition::Month, FieldPosition::Year]
+)]
+#[test]
+fn tracks_explicit_fields(#[case] input: &str, #[case] expected: &[FieldPosition]) {
+    let cron = Cron::new(input).unwrap();
+    assert_eq!(
+        cron.explicit_fields(),
+        expected,
+        "Failed for input: '{input}'"
+    );
+}
+
+#[rstest]
+#[case("fortnightly", "fortnightly")]
+#[case("every two weeks", "two weeks")]
+#[case("every 3 weeks", "every 3 weeks")]
+#[case("biweekly", "biweekly (read as every two weeks, not twice a week)")]
+#[test]
+fn warns_about_approximated_weeks(#[case] input: &str, #[case] phrase: &str) {
+    let cron = Cron::new(input).unwrap();
+    assert!(
+        matches!(
+            cron.warnings.as_slice(),
+            [Warning::ApproximatedPeriod { phrase: actual, .. }] if actual == phrase
+        ),
+        "Failed for input: '{input}', got {:?}",
+        cron.warnings
+    );
+    assert!(cron.is_approximate(), "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case("every 2 weeks on Monday", "every 2 weeks", "MON", 2)]
+#[case("on Monday every 2 weeks", "every 2 weeks", "MON", 2)]
+#[case("fortnightly on Tuesday", "fortnightly", "TUE", 2)]
+#[case("every 3 weeks on Friday at 9am", "every 3 weeks", "FRI", 3)]
+#[test]
+fn warns_about_unenforced_weeks(
+    #[case] input: &str,
+    #[case] phrase: &str,
+    #[case] day_of_week: &str,
+    #[case] weeks: u32,
+) {
+    let cron = Cron::new(input).unwrap();
+    assert_eq!(
+        cron.warnings,
+        [Warning::ApproximatedPeriod {
+            phrase: phrase.to_string(),
+            approximation: format!(
+                "every week on {day_of_week}, since the {weeks}-week cadence cannot be enforced"
+            ),
+        }],
+        "Failed for input: '{input}'"
+    );
+    assert!(cron.is_approximate(), "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case("every week")]
+#[case("every day")]
+#[case("Run at 6:00 pm every Monday through Friday")]
+#[case("every 10 minutes during business hours")]
+#[case("every day on Monday")]
+#[test]
+fn exact_periods_have_no_warnings(#[case] input: &str) {
+    let cron = Cron::new(input).unwrap();
+    assert!(cron.warnings.is_empty(), "Failed for input: '{input}'");
+    assert!(!cron.is_approximate(), "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case("every hour please", &["please"])]
+#[case("every 2 hours until noon", &["until"])]
+#[case("in a data center every hour", &["data", "center"])]
+#[case("every 5 minutes starting on Monday", &["starting", "Monday"])]
+#[case("every 2 hours starting at 30", &["starting", "30"])]
+#[test]
+fn warns_about_ignored_words(#[case] input: &str, #[case] words: &[&str]) {
+    let (cron, warnings) = str_cron_syntax_verbose(input);
+    let expected: Vec<_> = words
+        .iter()
+        .map(|word| Warning::IgnoredToken {
+            token: (*word).to_string(),
+        })
+        .collect();
+
+    assert!(cron.is_ok(), "Failed for input: '{input}'");
+    assert_eq!(warnings, expected, "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case("every 2 days on Monday")]
+#[case("on Monday every 2 days")]
+#[test]
+fn warns_about_dropped_day_repetition(#[case] input: &str) {
+    let (cron, warnings) = str_cron_syntax_verbose(input);
+
+    assert_eq!(cron.as_deref(), Ok("0 0 0 ? * MON *"));
+    assert_eq!(
+        warnings,
+        vec![Warning::AmbiguousDayFields {
+            day_of_month: "*/2".to_string(),
+            day_of_week: "MON".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn verbose_errors_have_no_warnings() {
+    assert_eq!(
+        str_cron_syntax_verbose("please"),
+        (Err(Error::InvalidInput), Vec::new())
+    );
+}
+
+#[rstest]
+#[case("every 15 minutes please", "0 0/15 * * * ? *", &["please"])]
+#[case("at 25:00 every monday", "0 * * ? * MON *", &["25:00"])]
+#[case("every 15 minutes at 99:00 on friday", "0 0/15 * ? * FRI *", &["99:00"])]
+#[case("every day at 4:00 pm", "0 0 16 */1 * ? *", &[])]
+#[case("whenever you like", "* * * * * ? *", &["whenever", "you", "like"])]
+#[case("", "* * * * * ? *", &[])]
+#[test]
+fn parses_leniently(#[case] input: &str, #[case] expected: &str, #[case] ignored: &[&str]) {
+    let (cron, unparsed) = str_cron_syntax_lenient(input);
+
+    assert_eq!(cron.as_deref(), Ok(expected), "Failed for input: '{input}'");
+    assert_eq!(unparsed, ignored, "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case(
+    "every other monday at 9am",
+    Error::UnsupportedSchedule {
+        schedule: "every other monday".to_string(),
+        suggestion: "every monday".to_string(),
+    }
+)]
+#[case(
+    "at 25:00 every other monday",
+    Error::UnsupportedSchedule {
+        schedule: "every other monday".to_string(),
+        suggestion: "every monday".to_string(),
+    }
+)]
+#[case(
+    "bimonthly at 9am",
+    Error::AmbiguousInput {
+        input: "bimonthly at 9am".to_string(),
+        alternatives: vec!["0 0 9 1 */2 ? *".to_string(), "0 0 9 1,15 * ? *".to_string()],
+    }
+)]
+#[test]
+fn lenient_parsing_keeps_the_meaning(#[case] input: &str, #[case] expected: Error) {
+    let (cron, _) = str_cron_syntax_lenient(input);
+
+    assert_eq!(cron, Err(expected), "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case(8, Ok("0 0 9 */1 * ? *"))]
+#[case(2, Ok("0 0 9 */1 * ? *"))]
+#[case(1, Err(Error::TooComplex { clauses: 2 }))]
+#[test]
+fn caps_schedule_clauses(#[case] max_clauses: usize, #[case] expected: Result<&str, Error>) {
+    let mut options = Options::default();
+    options.max_clauses = max_clauses;
+    let result = Cron::with_options("every day at 9am", &options).map(|cron| cron.to_string());
+    assert_eq!(result, expected.map(ToString::to_string));
+}
+
+#[rstest]
+#[case("every 10 minutes during business hours", "0 0/10 8-18 ? * MON-FRI *")]
+#[case("every hour during office hours on Monday", "0 0 8-18 ? * MON *")]
+#[test]
+fn business_hours_follow_options(#[case] input: &str, #[case] expected: &str) {
+    let mut options = Options::default();
+    options.business_hours = (8, 18);
+    let cron = Cron::with_options(input, &options).unwrap();
+
+    assert_eq!(cron.to_string(), expected, "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case((30, 5))]
+#[case((17, 9))]
+#[case((9, 24))]
+#[test]
+fn rejects_business_hours_out_of_range(#[case] business_hours: (u8, u8)) {
+    let mut options = Options::default();
+    options.business_hours = business_hours;
+    let (start, end) = business_hours;
+
+    assert_eq!(
+        Cron::with_options("every hour during business hours", &options).unwrap_err(),
+        Error::IncorrectValue {
+            state: "business_hours".to_string(),
+            error: format!(
+                "business hours {start} to {end} should be hours from 0 to 23, the first no later than the last"
+            ),
+        }
+    );
+    assert!(Cron::with_options("every hour", &options).is_ok());
+}
+
+#[rstest]
+#[case("on Friday, Monday and Wednesday", WeekStart::Monday, "MON,WED,FRI")]
+#[case("on Sunday and Monday", WeekStart::Monday, "MON,SUN")]
+#[case("on Sunday and Monday", WeekStart::Sunday, "SUN,MON")]
+#[case("on Wednesday, Sunday and Monday", WeekStart::Sunday, "SUN,MON,WED")]
+#[case("on weekends and Monday", WeekStart::Monday, "MON,SAT,SUN")]
+#[case("on weekends and Monday", WeekStart::Sunday, "SUN,MON,SAT")]
+#[case("on Friday and Friday", WeekStart::Monday, "FRI")]
+#[case("on Monday, Wednesday and Monday", WeekStart::Sunday, "MON,WED")]
+#[case(
+    "on weekdays and Saturday",
+    WeekStart::Monday,
+    "MON,TUE,WED,THU,FRI,SAT"
+)]
+#[case("on Sunday", WeekStart::Sunday, "SUN")]
+#[case("Monday through Friday", WeekStart::Sunday, "MON-FRI")]
+#[test]
+fn lists_days_of_the_week_in_order(
+    #[case] input: &str,
+    #[case] week_start: WeekStart,
+    #[case] expected: &str,
+) {
+    let mut options = Options::default();
+    options.week_start = week_start;
+    let cron = Cron::with_options(input, &options).unwrap();
+
+    assert_eq!(cron.day_of_week(), expected, "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case("on Sunday", DayOfWeekFormat::Names, "SUN")]
+#[case("on Sunday", DayOfWeekFormat::ZeroBasedSunday0, "0")]
+#[case("on Sunday", DayOfWeekFormat::ZeroBasedSunday7, "7")]
+#[case("on Sunday", DayOfWeekFormat::SixBasedMon0, "6")]
+#[case("on Monday", DayOfWeekFormat::ZeroBasedSunday0, "1")]
+#[case("on Monday", DayOfWeekFormat::SixBasedMon0, "0")]
+#[case("on weekdays", DayOfWeekFormat::ZeroBasedSunday0, "1-5")]
+#[case("on weekdays", DayOfWeekFormat::SixBasedMon0, "0-4")]
+#[case("on weekends", DayOfWeekFormat::ZeroBasedSunday0, "6,0")]
+#[case("on weekends", DayOfWeekFormat::ZeroBasedSunday7, "6,7")]
+#[case(
+    "on the last Friday of the month",
+    DayOfWeekFormat::ZeroBasedSunday0,
+    "5L"
+)]
+#[case(
+    "on the 2nd Tuesday of the month",
+    DayOfWeekFormat::ZeroBasedSunday7,
+    "2#2"
+)]
+#[case("every day", DayOfWeekFormat::ZeroBasedSunday0, "?")]
+#[case("Friday through Sunday", DayOfWeekFormat::ZeroBasedSunday0, "5,6,0")]
+#[case("Friday through Sunday", DayOfWeekFormat::ZeroBasedSunday7, "5-7")]
+#[case("Sunday through Tuesday", DayOfWeekFormat::ZeroBasedSunday0, "0-2")]
+#[case("Sunday through Tuesday", DayOfWeekFormat::ZeroBasedSunday7, "7,1,2")]
+#[case("Sunday through Tuesday", DayOfWeekFormat::SixBasedMon0, "6,0,1")]
+#[test]
+fn writes_days_of_the_week_in_format(
+    #[case] input: &str,
+    #[case] day_of_week_format: DayOfWeekFormat,
+    #[case] expected: &str,
+) {
+    let mut options = Options::default();
+    options.day_of_week_format = day_of_week_format;
+    let cron = Cron::with_options(input, &options).unwrap();
+
+    assert_eq!(cron.day_of_week(), expected, "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case("on Sunday at 9am")]
+#[case("on weekdays at 9am")]
+#[case("on weekends at 9am")]
+#[case("every Monday and Thursday at 9am")]
+#[test]
+fn numbered_days_read_as_named(
+    #[case] input: &str,
+    #[values(
+        DayOfWeekFormat::ZeroBasedSunday0,
+        DayOfWeekFormat::ZeroBasedSunday7,
+        DayOfWeekFormat::SixBasedMon0
+    )]
+    day_of_week_format: DayOfWeekFormat,
+) {
+    let mut options = Options::default();
+    options.day_of_week_format = day_of_week_format;
+    let numbered = Cron::with_options(input, &options).unwrap();
+    let named = Cron::new(input).unwrap();
+
+    assert_eq!(
+        numbered.describe(),
+        named.describe(),
+        "Failed for input: '{input}'"
+    );
+    assert_eq!(
+        numbered.to_dialect(CronDialect::Unix),
+        named.to_dialect(CronDialect::Unix),
+        "Failed for input: '{input}'"
+    );
+    assert_eq!(numbered, named, "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case(
+    "Friday through Sunday at 9am",
+    DayOfWeekFormat::ZeroBasedSunday0,
+    "0 0 9 ? * FRI,SAT,SUN *"
+)]
+#[case(
+    "Friday through Sunday at 9am",
+    DayOfWeekFormat::ZeroBasedSunday7,
+    "0 0 9 ? * FRI-SUN *"
+)]
+#[case(
+    "Sunday through Tuesday at 9am",
+    DayOfWeekFormat::ZeroBasedSunday0,
+    "0 0 9 ? * SUN-TUE *"
+)]
+#[case(
+    "Sunday through Tuesday at 9am",
+    DayOfWeekFormat::ZeroBasedSunday7,
+    "0 0 9 ? * SUN,MON,TUE *"
+)]
+#[case(
+    "Sunday through Tuesday at 9am",
+    DayOfWeekFormat::SixBasedMon0,
+    "0 0 9 ? * SUN,MON,TUE *"
+)]
+#[case(
+    "on the last Friday of the month",
+    DayOfWeekFormat::ZeroBasedSunday0,
+    "0 * * ? * FRIL *"
+)]
+#[case(
+    "on the 2nd Tuesday of the month",
+    DayOfWeekFormat::SixBasedMon0,
+    "0 * * ? * TUE#2 *"
+)]
+#[test]
+fn numbered_days_read_as_quartz_days(
+    #[case] input: &str,
+    #[case] day_of_week_format: DayOfWeekFormat,
+    #[case] named: &str,
+) {
+    let mut options = Options::default();
+    options.day_of_week_format = day_of_week_format;
+    let numbered = Cron::with_options(input, &options).unwrap();
+    let named = Cron::parse_cron(named).unwrap();
+
+    assert_eq!(
+        ScheduleSpec::try_from(&numbered.syntax),
+        ScheduleSpec::try_from(&named.syntax),
+        "Failed for input: '{input}'"
+    );
+    assert_eq!(
+        numbered.describe(),
+        named.describe(),
+        "Failed for input: '{input}'"
+    );
+}
+
+#[rstest]
+#[case("in September", MonthFormat::Names, "SEP")]
+#[case("in September", MonthFormat::Numeric, "9")]
+#[case("in January, July and December", MonthFormat::Numeric, "1,7,12")]
+#[case("from January to March", MonthFormat::Numeric, "1-3")]
+#[case("every 5 second on 9 month", MonthFormat::Numeric, "9")]
+#[case("every day during summer", MonthFormat::Numeric, "6,7,8")]
+#[case("every 3 months", MonthFormat::Numeric, "*/3")]
+#[case("from October to February", MonthFormat::Numeric, "10,11,12,1,2")]
+#[case("from October to February", MonthFormat::Names, "OCT-FEB")]
+#[case("every day in winter", MonthFormat::Numeric, "1,2,12")]
+#[test]
+fn writes_months_in_format(
+    #[case] input: &str,
+    #[case] month_format: MonthFormat,
+    #[case] expected: &str,
+) {
+    let mut options = Options::default();
+    options.month_format = month_format;
+    let cron = Cron::with_options(input, &options).unwrap();
+
+    assert_eq!(cron.month(), expected, "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case("at 7", Meridiem::TwentyFourHour, "0 0 7 * * ? *")]
+#[case("at 7", Meridiem::Am, "0 0 7 * * ? *")]
+#[case("at 7", Meridiem::Pm, "0 0 19 * * ? *")]
+#[case("at 19", Meridiem::Pm, "0 0 19 * * ? *")]
+#[case("at 12", Meridiem::Am, "0 0 0 * * ? *")]
+#[case("at 7 and 8", Meridiem::Pm, "0 0 19,20 * * ? *")]
+#[case("at 7 o'clock", Meridiem::Pm, "0 0 19 * * ? *")]
+#[case("at 7am", Meridiem::Pm, "0 0 7 * * ? *")]
+#[case("at 7 in the morning", Meridiem::Pm, "0 0 7 * * ? *")]
+#[test]
+fn reads_bare_hours_with_default_meridiem(
+    #[case] input: &str,
+    #[case] default_meridiem: Meridiem,
+    #[case] expected: &str,
+) {
+    let mut options = Options::default();
+    options.default_meridiem = default_meridiem;
+    let cron = Cron::with_options(input, &options).unwrap();
+
+    assert_eq!(cron.to_string(), expected, "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case("every 15 seconds")]
+#[case("Run at 6:00 pm every Monday through Friday")]
+#[case("Run every day from January to March")]
+#[test]
+fn maps_fields_by_name(#[case] input: &str) {
+    let cron = Cron::new(input).unwrap();
+    let fields = cron.syntax.to_map();
+
+    let ordered: Vec<&str> = Syntax::field_names()
+        .iter()
+        .map(|name| fields[name])
+        .collect();
+    assert_eq!(
+        ordered.join(" "),
+        cron.to_string(),
+        "Failed for input: '{input}'"
+    );
+}
+
+#[rstest]
+#[case("every 15 seconds")]
+#[case("Run at 6:00 pm every Monday through Friday")]
+#[case("on the last Friday of the month at 5pm in 2030")]
+#[test]
+fn accessors_match_displayed_fields(#[case] input: &str) {
+    let cron = Cron::new(input).unwrap();
+    let accessors = [
+        cron.seconds(),
+        cron.minutes(),
+        cron.hours(),
+        cron.day_of_month(),
+        cron.month(),
+        cron.day_of_week(),
+        cron.year(),
+    ];
+
+    assert_eq!(accessors, cron.fields(), "Failed for input: '{input}'");
+    assert_eq!(
+        cron.fields().join(" "),
+        cron.to_string(),
+        "Failed for input: '{input}'"
+    );
+}
+
+#[rstest]
+#[case("every 15 seconds")]
+#[case("Run at 6:00 pm every Monday through Friday")]
+#[case("on the last Friday of the month at 5pm in 2030")]
+#[test]
+fn positional_access_matches_fields(#[case] input: &str) {
+    let mut options = Options::default();
+    options.clock = Clock::Fixed(2025);
+    let cron = Cron::with_options(input, &options).unwrap();
+
+    for (index, field) in FieldPosition::ALL.into_iter().enumerate() {
+        let position = u8::try_from(index).unwrap();
+        assert_eq!(FieldPosition::try_from(position), Ok(field));
+        assert_eq!(cron.field_at(field), cron.fields()[index]);
+    }
+}
+
+#[rstest]
+#[case("the first Friday of the month at 9am")]
+#[case("on the 5th Sunday")]
+#[case("every other Monday")]
+#[case("on alternating Mondays")]
+#[case("on the 1st and 3rd Monday")]
+#[case("at 9am on the 2nd, 4th tue")]
+#[test]
+fn numbered_weekdays_are_valid_quartz(#[case] input: &str) {
+    let cron = match Cron::new(input) {
+        Ok(cron) => cron,
+        Err(Error::UnsupportedSchedule { suggestion, .. }) => Cron::new(&suggestion).unwrap(),
+        Err(error) => panic!("Failed for input: '{input}': {error}"),
+    };
+
+    let parsed = Cron::parse_cron(&cron.to_string()).unwrap();
+    assert!(parsed.validate().is_ok(), "Failed for input: '{input}'");
+}
+
+#[rstest]
+#[case("0 0 9 ? * MON#1,MON#3 *")]
+#[case("0 0 9 ? * MON#2,FRI *")]
+#[test]
+fn numbered_weekday_must_stand_alone(#[case] expression: &str) {
+    assert!(
+        Cron::parse_cron(expression).is_err(),
+        "Failed for expression: '{expression}'"
+    );
+}
+
+#[test]
+fn verbose_labels_each_field() {
+    let cron = Cron::new("every 15 minutes on weekdays").unwrap();
+
+    assert_eq!(
+        cron.verbose().to_string(),
+        "Seconds:      0\n\
+         Minutes:      0/15\n\
+         Hour:         *\n\
+         Day-of-month: ?\n\
+         Month:        *\n\
+         Day-of-week:  MON-FRI\n\
+         Year:         *"
+    );
+}
+
+#[rstest]
+#[case("every 15 seconds")]
+#[case("on the last Friday of the month at 5pm in 2030")]
+#[test]
+fn debug_matches_verbose(#[case] input: &str) {
+    let mut options = Options::default();
+    options.clock = Clock::Fixed(2025);
+    let cron = Cron::with_options(input, &options).unwrap();
+    let debug = format!("{cron:?}");
+
+    assert_eq!(debug.lines().count(), 7, "Failed for input: '{input}'");
+    assert_eq!(
+        debug,
+        cron.verbose().to_string(),
+        "Failed for input: '{input}'"
+    );
+}
+
+#[test]
+fn debug_lists_warnings() {
+    let input = "every 5 minutes starting on Monday";
+    let debug = format!("{:?}", Cron::new(input).unwrap());
+    let verbose = Cron::new(input).unwrap().verbose().to_string();
+
+    assert_eq!(
+        debug,
+        format!(
+            "{verbose}\n\
+             Warning:      starting was ignored\n\
+             Warning:      Monday was ignored"
+        )
+    );
+}
+
+#[test]
+fn set_field_marks_field_explicit() {
+    let mut cron = Cron::new("every day at 4:00 pm").unwrap();
+    assert!(!cron.syntax.is_explicit(FieldPosition::Year));
+
+    cron.set_field(FieldPosition::Year, "2030");
+    cron.set_field(FieldPosition::try_from(1).unwrap(), String::from("15"));
+
+    assert_eq!(cron.field_at(FieldPosition::Year), "2030");
+    assert!(cron.syntax.is_explicit(FieldPosition::Year));
+    assert_eq!(cron.to_string(), "0 15 16 */1 * ? 2030");
+}
+
+#[test]
+fn field_position_rejects_positions_past_year() {
+    assert_eq!(
+        FieldPosition::try_from(7),
+        Err(Error::IncorrectValue {
+            state: "field_position".to_string(),
+            error: "position 7 should be between 0 and 6".to_string(),
+        })
+    );
+}
+
+#[rstest]
+#[case("on business days", "on weekdays", "0 * * ? * MON-FRI *")]
+#[case("on businessday", "on weekdays", "0 * * ? * MON-FRI *")]
+#[case(
+    "every business day at 7am",
+    "every weekday at 7am",
+    "0 0 7 ? * MON-FRI *"
+)]
+#[case("at 7am every workday", "at 7am every weekday", "0 0 7 ? * MON-FRI *")]
+#[case(
+    "every 15 minutes on workdays",
+    "every 15 minutes on weekdays",
+    "0 0/15 * ? * MON-FRI *"
+)]
+#[case(
+    "every work day at 7am in March",
+    "every weekday at 7am in March",
+    "0 0 7 ? MAR MON-FRI *"
+)]
+#[case(
+    "every Work Days from January to March",
+    "every weekdays from January to March",
+    "0 * * ? JAN-MAR MON-FRI *"
+)]
+#[case(
+    "on business days and Saturday",
+    "on weekdays and Saturday",
+    "0 * * ? * MON,TUE,WED,THU,FRI,SAT *"
+)]
+#[case(
+    "on workdays and Saturday",
+    "on weekdays and Saturday",
+    "0 * * ? * MON,TUE,WED,THU,FRI,SAT *"
+)]
+#[test]
+fn business_days_are_weekdays(#[case] input: &str, #[case] weekdays: &str, #[case] expected: &str) {
+    assert_eq!(
+        str_cron_syntax(input).as_deref(),
+        Ok(expected),
+        "Failed for input: '{input}'"
+    );
+    assert_eq!(
+        str_cron_syntax(input),
+        str_cron_syntax(weekdays),
+        "Failed for input: '{input}'"
+    );
+}
+
+#[rstest]
+#[test]
+fn several_times_with_day_interval(
+    #[values(
+        ("", "*"),
+        ("every 2 days", "*/2"),
+        ("every other day", "*/2"),
+        ("every 5 days", "*/5")
+    )]
+    days: (&str, &str),
+    #[values(
+        ("6:30", "30 6"),
+        ("6:30 and 18:30", "30 6,18"),
+        ("6:30, 12:30 and 18:30", "30 6,12,18"),
+        ("9am and 5pm", "0 9,17")
+    )]
+    times: (&str, &str),
+) {
+    let ((days, day_of_month), (times, minute_hour)) = (days, times);
+    let expected = format!("0 {minute_hour} {day_of_month} * ? *");
+    for input in [format!("{days} at {times}"), format!("at {times} {days}")] {
+        assert_eq!(
+            str_cron_syntax(input.trim()).as_deref(),
+            Ok(expected.as_str()),
+            "Failed for input: '{input}'"
+        );
+    }
+}
+
+#[rstest]
+#[test]
+fn every_n_days_at_time(
+    #[values(1, 2, 3, 7, 15, 31)] days: u32,
+    #[values(
+        ("9:00 am", "0 9"),
+        ("9am", "0 9"),
+        ("9 AM", "0 9"),
+        ("9:45 am", "45 9"),
+        ("12:00 am", "0 0"),
+        ("12 pm", "0 12"),
+        ("1:30 pm", "30 13"),
+        ("11:59 pm", "59 23"),
+        ("noon", "0 12"),
+        ("midnight", "0 0"),
+        ("21:15", "15 21")
+    )]
+    time: (&str, &str),
+) {
+    let (time, minute_hour) = time;
+    let expected = format!("0 {minute_hour} */{days} * ? *");
+    for input in [
+        format!("every {days} days at {time}"),
+        format!("at {time} every {days} days"),
+        format!("every {days} day at {time}"),
+    ] {
+        assert_eq!(
+            str_cron_syntax(&input).as_deref(),
+            Ok(expected.as_str()),
+            "Failed for input: '{input}'"
+        );
+    }
+}
+
+#[rstest]
+#[case("every 5 minutes", "0 0/5 9-17 * * ? *")]
+#[case("every minute", "0 * 9-17 * * ? *")]
+#[case("every 10 seconds", "0/10 * 9-17 * * ? *")]
+#[case("every second", "* * 9-17 * * ? *")]
+#[case("every 2 hours", "0 0 9-17/2 * * ? *")]
+#[case("every hour", "0 0 9-17 * * ? *")]
+#[test]
+fn repetition_and_time_range_in_either_order(#[case] repetition: &str, #[case] expected: &str) {
+    for range in ["from 9am to 5pm", "between 9am and 5pm"] {
+        for input in [
+            format!("{repetition} {range}"),
+            format!("{range} {repetition}"),
+        ] {
+            assert_eq!(
+                str_cron_syntax(&input).as_deref(),
+                Ok(expected),
+                "Failed for input: '{input}'"
+            );
+        }
+    }
+}
+
+#[rstest]
+#[case("on weekdays", "0 0/10 9-17 ? * MON-FRI *")]
+#[case("on Monday and Friday", "0 0/10 9-17 ? * MON,FRI *")]
+#[test]
+fn repetition_time_range_and_days_in_any_order(#[case] days: &str, #[case] expected: &str) {
+    for range in ["from 9am to 5pm", "between 9am and 5pm"] {
+        let clauses = ["every 10 minutes", range, days];
+        for order in [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ] {
+            let input = order.map(|index| clauses[index]).join(" ");
+            assert_eq!(
+                str_cron_syntax(&input).as_deref(),
+                Ok(expected),
+                "Failed for input: '{input}'"
+            );
+        }
+    }
+}
+
+#[rstest]
+#[test]
+fn time_and_days_in_either_order(
+    #[values(
+        ("9am", "0 9"),
+        ("9:30am", "30 9"),
+        ("9am and 5pm", "0 9,17"),
+        ("noon", "0 12"),
+        ("17:45", "45 17"),
+        ("7 in the evening", "0 19")
+    )]
+    time: (&str, &str),
+    #[values(
+        ("on Monday", "? * MON"),
+        ("on Monday and Friday", "? * MON,FRI"),
+        ("on weekdays", "? * MON-FRI"),
+        ("on weekends", "? * SAT,SUN"),
+        ("on Monday through Friday", "? * MON-FRI"),
+        ("on Tuesdays", "? * TUE"),
+        ("every Monday", "? * MON"),
+        ("every day", "*/1 * ?"),
+        ("on the 1st and 15th", "1,15 * ?"),
+        ("on the last day of the month", "L * ?"),
+        ("on the second Monday of the month", "? * MON#2")
+    )]
+    days: (&str, &str),
+) {
+    let ((time, time_fields), (days, day_fields)) = (time, days);
+    let expected = format!("0 {time_fields} {day_fields} *");
+
+    for input in [format!("at {time} {days}"), format!("{days} at {time}")] {
+        assert_eq!(
+            str_cron_syntax(&input),
+            Ok(expected.clone()),
+            "Failed for input: '{input}'"
+        );
+    }
+}
+
+#[test]
+fn malformed_number_sequences_do_not_panic() {
+    let words = [
+        "0",
+        "999",
+        "99999999999",
+        "-1",
+        "32nd",
+        "other",
+        "and",
+        "between",
+        "starting",
+        "ending",
+        "25:61",
+        "13pm",
+        "minutes",
+        "day",
+        "month",
+        "Monday",
+    ];
+
+    for first in words {
+        for second in words {
+            for third in words {
+                // Errors are fine, only a panic fails the test
+                let _ = Cron::new(&format!("{first} {second} {third}"));
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use english_to_cron::{Clock, Cron, Error, Options, Warning};
+use rstest::rstest;
+
+/// The default options with the clock fixed in 2025.
+fn options() -> Options {
+    let mut options = Options::default();
+    options.clock = Clock::Fixed(2025);
+    options
+}
+
+#[rstest]
+#[case("every day in 2019", 2019)]
+#[case("every day in 2019 and 2020", 2020)]
+#[case("every day from 2018 to 2021", 2021)]
+#[test]
+fn rejects_years_in_the_past(#[case] input: &str, #[case] year: i32) {
+    let cron = Cron::new(input).unwrap();
+
+    assert_eq!(
+        cron.validate_with(&options()),
+        Err(Error::ScheduleInPast { year }),
+        "Failed for input: '{input}'"
+    );
+}
+
+#[rstest]
+#[case("every day", vec![])]
+#[case("every day in 2025", vec![])]
+#[case("every day in 2030", vec![])]
+#[case("every day from 2020 to 2026", vec![])]
+#[case("every 2 years", vec![])]
+#[case("every day in 2019 and 2027", vec![Warning::PastYear { year: 2019 }])]
+#[case(
+    "every day in 2018, 2019 and 2030",
+    vec![Warning::PastYear { year: 2018 }, Warning::PastYear { year: 2019 }]
+)]
+#[test]
+fn accepts_years_with_a_future(#[case] input: &str, #[case] warnings: Vec<Warning>) {
+    let cron = Cron::new(input).unwrap();
+
+    assert_eq!(
+        cron.validate_with(&options()),
+        Ok(warnings),
+        "Failed for input: '{input}'"
+    );
+}
+
+#[test]
+fn past_years_can_be_allowed() {
+    let cron = Cron::new("every day in 2019").unwrap();
+    let mut options = options();
+    options.reject_past_years = false;
+
+    assert_eq!(cron.validate_with(&options), Ok(vec![]));
+}
+
+#[test]
+fn system_clock_is_not_before_2025() {
+    assert!(Clock::System.current_year() >= 2025);
+}